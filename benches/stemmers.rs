@@ -0,0 +1,301 @@
+// Copyright 2019 The Polystem authors.
+//
+// For the full copyright and license information, please view the LICENSE file
+// that was distributed with this source code.
+
+// Throughput and allocations of each stemmer and of the cached, batch and
+// parallel ways of stemming, over two corpora built from the words of the
+// Porter vocabulary:
+//
+// * `vocabulary`: each distinct word once, the worst case for a cache.
+// * `text`: a stream of words drawn with Zipf's law, as in running text.
+//
+// Run with `cargo bench`. The allocations per word are printed before the
+// timings.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use criterion::{
+    black_box, criterion_group, criterion_main, BenchmarkId, Criterion,
+    Throughput,
+};
+use polystem::{
+    Cached, DynStemmer, Interner, Porter, SharedCached, Stemmer, Stems, S,
+};
+
+#[allow(dead_code)]
+#[path = "../src/fixture_test.rs"]
+mod fixture_test;
+
+use fixture_test::Vocabulary;
+
+// An allocator that counts the allocations made through it.
+struct Counting;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(
+        &self,
+        ptr: *mut u8,
+        layout: Layout,
+        new_size: usize,
+    ) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: Counting = Counting;
+
+// The number of words of the `text` corpus.
+const TEXT_LEN: usize = 100_000;
+
+// The capacity of the caches, about a tenth of the vocabulary.
+const CACHE_CAPACITY: usize = 2048;
+
+// Return the corpora, by name.
+fn corpora() -> Vec<(&'static str, Vec<&'static str>)> {
+    // The vocabulary is leaked, as its words are borrowed for the run.
+    let porter: &'static Vocabulary =
+        Box::leak(Box::new(Vocabulary::load("porter")));
+    let words = porter.words();
+    let text = text(&words);
+    vec![("vocabulary", words), ("text", text)]
+}
+
+// Draw `TEXT_LEN` words of `words` in a fixed order, the word of rank `r`
+// with a probability proportional to `1 / r`.
+fn text(words: &[&'static str]) -> Vec<&'static str> {
+    let mut cumulative = Vec::with_capacity(words.len());
+    let mut total = 0.0;
+    for rank in 1..=words.len() {
+        total += 1.0 / rank as f64;
+        cumulative.push(total);
+    }
+
+    // The ranks are a fixed shuffle of the vocabulary, so that frequent
+    // words are not all at the start of the alphabet.
+    let mut state: u64 = 0x2545_f491_4f6c_dd1d;
+    let mut next = move || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state
+    };
+    let mut ranked = words.to_vec();
+    for i in (1..ranked.len()).rev() {
+        ranked.swap(i, next() as usize % (i + 1));
+    }
+
+    (0..TEXT_LEN)
+        .map(|_| {
+            let x = (next() >> 11) as f64 / (1u64 << 53) as f64 * total;
+            let rank = cumulative.partition_point(|&c| c < x);
+            ranked[rank.min(ranked.len() - 1)]
+        })
+        .collect()
+}
+
+// Return the number of allocations made by `f`.
+fn allocations<F: FnOnce()>(f: F) -> usize {
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    f();
+    ALLOCATIONS.load(Ordering::Relaxed) - before
+}
+
+fn stemmers(c: &mut Criterion) {
+    for (name, words) in corpora() {
+        let mut group = c.benchmark_group(format!("stem/{}", name));
+        group.throughput(Throughput::Elements(words.len() as u64));
+
+        group.bench_function("s", |b| {
+            b.iter(|| {
+                for word in &words {
+                    black_box(S::stem(word));
+                }
+            })
+        });
+        group.bench_function("porter", |b| {
+            b.iter(|| {
+                for word in &words {
+                    black_box(Porter::stem(word));
+                }
+            })
+        });
+        group.bench_function("porter_mut", |b| {
+            let mut porter = Porter::default();
+            b.iter(|| {
+                for word in &words {
+                    black_box(porter.stem_reuse(word));
+                }
+            })
+        });
+        group.bench_function("porter_to_buf", |b| {
+            let mut buf = [0; 64];
+            b.iter(|| {
+                for word in &words {
+                    let _ = black_box(Porter::stem_to_buf(word, &mut buf));
+                }
+            })
+        });
+        group.bench_function("porter_with_stem", |b| {
+            b.iter(|| {
+                for word in &words {
+                    Porter::with_stem(word, |stem| black_box(stem.len()));
+                }
+            })
+        });
+        group.finish();
+    }
+}
+
+fn paths(c: &mut Criterion) {
+    for (name, words) in corpora() {
+        let mut group = c.benchmark_group(format!("paths/{}", name));
+        group.throughput(Throughput::Elements(words.len() as u64));
+
+        let capacity = BenchmarkId::new("cached", CACHE_CAPACITY);
+        group.bench_function(capacity, |b| {
+            let cached = Cached::new(Porter::default(), CACHE_CAPACITY);
+            b.iter(|| {
+                for word in &words {
+                    black_box(cached.stem_word(word));
+                }
+            })
+        });
+        let capacity = BenchmarkId::new("shared_cached", CACHE_CAPACITY);
+        group.bench_function(capacity, |b| {
+            let cached = SharedCached::new(Porter::default(), CACHE_CAPACITY);
+            b.iter(|| {
+                for word in &words {
+                    black_box(cached.stem_word(word));
+                }
+            })
+        });
+        group.bench_function("stem_all", |b| {
+            b.iter(|| black_box(Porter::stem_all(&words)))
+        });
+        group.bench_function("stem_all_dedup", |b| {
+            b.iter(|| black_box(Porter::stem_all_dedup(&words)))
+        });
+        group.bench_function("stem_all_interned", |b| {
+            b.iter(|| {
+                let mut interner = Interner::new();
+                black_box(Porter::stem_all_interned(&words, &mut interner))
+            })
+        });
+        group.bench_function("stem_all_into", |b| {
+            let mut stems = Stems::new();
+            b.iter(|| {
+                stems.clear();
+                Porter::stem_all_into(&words, &mut stems);
+                black_box(&stems);
+            })
+        });
+        #[cfg(feature = "rayon")]
+        group.bench_function("stem_all_parallel", |b| {
+            b.iter(|| black_box(Porter::stem_all_parallel(&words)))
+        });
+        group.finish();
+    }
+}
+
+// Print the allocations per word of each stemmer and path.
+fn report_allocations(_: &mut Criterion) {
+    for (name, words) in corpora() {
+        let per_word = |n: usize| n as f64 / words.len() as f64;
+        let cached = Cached::new(Porter::default(), CACHE_CAPACITY);
+        let mut porter = Porter::default();
+        let mut buf = [0; 64];
+
+        let counts = [
+            (
+                "s",
+                allocations(|| words.iter().for_each(|w| drop(S::stem(w)))),
+            ),
+            (
+                "porter",
+                allocations(|| {
+                    for word in &words {
+                        drop(Porter::stem(word));
+                    }
+                }),
+            ),
+            (
+                "porter_mut",
+                allocations(|| {
+                    for word in &words {
+                        porter.stem_reuse(word);
+                    }
+                }),
+            ),
+            (
+                "porter_to_buf",
+                allocations(|| {
+                    for word in &words {
+                        let _ = Porter::stem_to_buf(word, &mut buf);
+                    }
+                }),
+            ),
+            (
+                "porter_with_stem",
+                allocations(|| {
+                    for word in &words {
+                        Porter::with_stem(word, |stem| stem.len());
+                    }
+                }),
+            ),
+            (
+                "cached",
+                allocations(|| {
+                    for word in &words {
+                        drop(cached.stem_word(word));
+                    }
+                }),
+            ),
+            ("stem_all", allocations(|| drop(Porter::stem_all(&words)))),
+            (
+                "stem_all_dedup",
+                allocations(|| drop(Porter::stem_all_dedup(&words))),
+            ),
+            (
+                "stem_all_interned",
+                allocations(|| {
+                    let mut interner = Interner::new();
+                    Porter::stem_all_interned(&words, &mut interner);
+                }),
+            ),
+            (
+                "stem_all_into",
+                allocations(|| {
+                    let mut stems = Stems::new();
+                    Porter::stem_all_into(&words, &mut stems);
+                }),
+            ),
+        ];
+
+        for (path, count) in counts.iter() {
+            println!(
+                "allocations/{}/{}: {:.2} per word",
+                name,
+                path,
+                per_word(*count)
+            );
+        }
+    }
+}
+
+criterion_group!(benches, report_allocations, stemmers, paths);
+criterion_main!(benches);
@@ -0,0 +1,106 @@
+// Copyright 2019 The Polystem authors.
+//
+// For the full copyright and license information, please view the LICENSE file
+// that was distributed with this source code.
+
+//! The native methods of `net.hypergeometric.polystem.Stemmer`.
+
+use polystem::Algorithm;
+use jni::objects::{JClass, JObject, JString};
+use jni::sys::{jobjectArray, jstring};
+use jni::JNIEnv;
+
+// The exception thrown for an unknown algorithm.
+const ILLEGAL_ARGUMENT: &str = "java/lang/IllegalArgumentException";
+
+/// `static native String stem(String algorithm, String word)`
+#[no_mangle]
+pub extern "system" fn Java_net_hypergeometric_polystem_Stemmer_stem<'l>(
+    mut env: JNIEnv<'l>,
+    _class: JClass<'l>,
+    algorithm: JString<'l>,
+    word: JString<'l>,
+) -> jstring {
+    let result = stem(&mut env, &algorithm, &word);
+    throw_on_error(&mut env, result)
+}
+
+/// `static native String[] algorithms()`
+#[no_mangle]
+pub extern "system" fn Java_net_hypergeometric_polystem_Stemmer_algorithms<
+    'l,
+>(
+    mut env: JNIEnv<'l>,
+    _class: JClass<'l>,
+) -> jobjectArray {
+    let result = algorithms(&mut env);
+    throw_on_error(&mut env, result)
+}
+
+// The error of a native method: a failed JNI call, which may have left an
+// exception pending, or an exception to throw.
+enum Error {
+    Jni(jni::errors::Error),
+    IllegalArgument(String),
+}
+
+impl From<jni::errors::Error> for Error {
+    fn from(e: jni::errors::Error) -> Error {
+        Error::Jni(e)
+    }
+}
+
+// Return the stem of `word` with the algorithm named `algorithm`.
+fn stem(
+    env: &mut JNIEnv,
+    algorithm: &JString,
+    word: &JString,
+) -> Result<jstring, Error> {
+    let name: String = env.get_string(algorithm)?.into();
+    let algorithm: Algorithm =
+        name.parse().map_err(|e: polystem::ParseAlgorithmError| {
+            Error::IllegalArgument(e.to_string())
+        })?;
+    let word: String = env.get_string(word)?.into();
+
+    let stem = algorithm.with_stem(&word, |stem| env.new_string(stem))?;
+    Ok(stem.into_raw())
+}
+
+// Return the names of the algorithms as a `String[]`.
+fn algorithms(env: &mut JNIEnv) -> Result<jobjectArray, Error> {
+    let names = env.new_object_array(
+        Algorithm::ALL.len() as i32,
+        "java/lang/String",
+        JObject::null(),
+    )?;
+    for (i, algorithm) in Algorithm::ALL.iter().enumerate() {
+        let name = env.new_string(algorithm.name())?;
+        env.set_object_array_element(&names, i as i32, name)?;
+    }
+
+    Ok(names.into_raw())
+}
+
+// Return the value of `result`, or throw its error as a Java exception and
+// return null.
+fn throw_on_error<T>(
+    env: &mut JNIEnv,
+    result: Result<*mut T, Error>,
+) -> *mut T {
+    match result {
+        Ok(value) => value,
+        // An exception is already pending.
+        Err(Error::Jni(jni::errors::Error::JavaException)) => {
+            std::ptr::null_mut()
+        }
+        Err(Error::Jni(e)) => {
+            let _ = env.throw_new("java/lang/RuntimeException", e.to_string());
+            std::ptr::null_mut()
+        }
+        Err(Error::IllegalArgument(message)) => {
+            let _ = env.throw_new(ILLEGAL_ARGUMENT, message);
+            std::ptr::null_mut()
+        }
+    }
+}
@@ -0,0 +1,54 @@
+// Copyright 2019 The Polystem authors.
+//
+// For the full copyright and license information, please view the LICENSE file
+// that was distributed with this source code.
+
+package net.hypergeometric.polystem;
+
+import java.util.Arrays;
+
+/**
+ * A stemmer for one of the algorithms of polystem, backed by the native
+ * {@code polystem_java} library.
+ *
+ * <pre>{@code
+ * Stemmer porter = new Stemmer("porter");
+ * porter.stem("connections"); // "connect"
+ * }</pre>
+ */
+public final class Stemmer {
+    static {
+        System.loadLibrary("polystem_java");
+    }
+
+    private final String algorithm;
+
+    /**
+     * Construct a stemmer for the algorithm named {@code algorithm}, one of
+     * {@link #algorithms()}.
+     *
+     * @throws IllegalArgumentException if there is no such algorithm
+     */
+    public Stemmer(String algorithm) {
+        if (!Arrays.asList(algorithms()).contains(algorithm)) {
+            throw new IllegalArgumentException(
+                "unknown stemming algorithm `" + algorithm + "`");
+        }
+        this.algorithm = algorithm;
+    }
+
+    /** Return the name of the algorithm of the stemmer. */
+    public String algorithm() {
+        return algorithm;
+    }
+
+    /** Return the stem of {@code word}. */
+    public String stem(String word) {
+        return stem(algorithm, word);
+    }
+
+    /** Return the names of the algorithms compiled into the library. */
+    public static native String[] algorithms();
+
+    private static native String stem(String algorithm, String word);
+}
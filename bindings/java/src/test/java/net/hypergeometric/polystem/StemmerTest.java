@@ -0,0 +1,49 @@
+// Copyright 2019 The Polystem authors.
+//
+// For the full copyright and license information, please view the LICENSE file
+// that was distributed with this source code.
+
+package net.hypergeometric.polystem;
+
+import java.util.Arrays;
+
+/**
+ * The tests of {@link Stemmer}, run with {@code java -ea}.
+ */
+public final class StemmerTest {
+    public static void main(String[] args) {
+        testStem();
+        testAlgorithms();
+        testUnknownAlgorithm();
+        System.out.println("ok");
+    }
+
+    static void testStem() {
+        Stemmer porter = new Stemmer("porter");
+        check(porter.stem("connections"), "connect");
+        check(porter.stem("generalizations"), "gener");
+        check(new Stemmer("s").stem("flies"), "fly");
+        check(new Stemmer("s").stem("cafés"), "café");
+    }
+
+    static void testAlgorithms() {
+        check(Arrays.asList(Stemmer.algorithms()).toString(), "[s, porter]");
+        check(new Stemmer("porter").algorithm(), "porter");
+    }
+
+    static void testUnknownAlgorithm() {
+        try {
+            new Stemmer("porter2");
+        } catch (IllegalArgumentException e) {
+            return;
+        }
+        throw new AssertionError("porter2 is not an algorithm");
+    }
+
+    static void check(String actual, String expected) {
+        if (!actual.equals(expected)) {
+            throw new AssertionError(
+                "expected `" + expected + "`, got `" + actual + "`");
+        }
+    }
+}
@@ -0,0 +1,83 @@
+// Copyright 2019 The Polystem authors.
+//
+// For the full copyright and license information, please view the LICENSE file
+// that was distributed with this source code.
+
+//! Polars expressions that stem the strings of a column, so that text is
+//! normalized within a lazy query.
+//!
+//! # Examples
+//!
+//! ```
+//! use polars::prelude::*;
+//! use polystem::Algorithm;
+//! use polystem_polars::StemExpr;
+//!
+//! let df = df!("text" => ["ponies", "rusted"]).unwrap();
+//! let stems = df
+//!     .lazy()
+//!     .select([col("text").stem(Algorithm::Porter)])
+//!     .collect()
+//!     .unwrap();
+//!
+//! let expected = df!("text" => ["poni", "rust"]).unwrap();
+//! assert!(stems.equals(&expected));
+//! ```
+
+use polars::prelude::*;
+use polystem::Algorithm;
+
+/// Stemming of string expressions.
+pub trait StemExpr {
+    /// Stem every string of the expression with `algorithm`. Nulls stay
+    /// null.
+    fn stem(self, algorithm: Algorithm) -> Expr;
+}
+
+impl StemExpr for Expr {
+    fn stem(self, algorithm: Algorithm) -> Expr {
+        self.map(
+            move |column| stem_column(&column, algorithm),
+            |_, field| Ok(Field::new(field.name().clone(), DataType::String)),
+        )
+    }
+}
+
+// Return the stems of the strings of `column`.
+fn stem_column(column: &Column, algorithm: Algorithm) -> PolarsResult<Column> {
+    let stems = column.str()?.apply_into_string_amortized(|word, buf| {
+        algorithm.with_stem(word, |stem| buf.push_str(stem))
+    });
+    Ok(stems.into_column())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stem() {
+        let df = df!("word" => [Some("Flies"), None, Some("cats")]).unwrap();
+        let stems = df
+            .lazy()
+            .select([col("word").stem(Algorithm::S)])
+            .collect()
+            .unwrap();
+
+        let stems: Vec<_> = stems
+            .column("word")
+            .unwrap()
+            .str()
+            .unwrap()
+            .iter()
+            .collect();
+        assert_eq!(stems, [Some("fly"), None, Some("cat")]);
+    }
+
+    #[test]
+    fn test_stem_not_a_string() {
+        let df = df!("n" => [1, 2]).unwrap();
+        let result = df.lazy().select([col("n").stem(Algorithm::S)]).collect();
+        assert!(result.is_err());
+    }
+}
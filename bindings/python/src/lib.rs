@@ -0,0 +1,108 @@
+// Copyright 2019 The Polystem authors.
+//
+// For the full copyright and license information, please view the LICENSE file
+// that was distributed with this source code.
+
+//! The `polystem` Python module.
+//!
+//! The classes follow the interfaces of the two common Python stemming
+//! libraries, so that their users can compare the stems or switch over:
+//!
+//! * `Stemmer(algorithm)` with `stemWord` and `stemWords`, as in PyStemmer.
+//! * `PorterStemmer()` with `stem`, as in NLTK.
+
+use polystem::{Algorithm, DynStemmer, SharedCached};
+use pyo3::exceptions::PyKeyError;
+use pyo3::prelude::*;
+
+// The number of words cached by a `Stemmer` by default, as in PyStemmer.
+const CACHE_SIZE: usize = 10_000;
+
+/// A stemmer for one algorithm, with the interface of `Stemmer.Stemmer` from
+/// PyStemmer.
+///
+/// `algorithm` is the name of an algorithm, such as `"porter"`. The
+/// language names of PyStemmer, such as `"english"`, are rejected: its
+/// English stemmer is Porter2, whose stems differ from Porter's. The most
+/// recently stemmed words are cached, up to `maxCacheSize` of them.
+#[pyclass(module = "polystem", frozen)]
+struct Stemmer {
+    algorithm: Algorithm,
+    cache: SharedCached<Algorithm>,
+}
+
+#[pymethods]
+impl Stemmer {
+    #[new]
+    #[pyo3(signature = (algorithm, maxCacheSize = CACHE_SIZE))]
+    #[allow(non_snake_case)]
+    fn new(algorithm: &str, maxCacheSize: usize) -> PyResult<Stemmer> {
+        let algorithm = parse_algorithm(algorithm)?;
+        Ok(Stemmer {
+            algorithm,
+            cache: SharedCached::new(algorithm, maxCacheSize),
+        })
+    }
+
+    /// Return the stem of `word`.
+    #[pyo3(name = "stemWord")]
+    fn stem_word(&self, word: &str) -> String {
+        self.cache.stem_word(word)
+    }
+
+    /// Return the stem of every word of `words`, in order.
+    #[pyo3(name = "stemWords")]
+    fn stem_words(&self, words: Vec<String>) -> Vec<String> {
+        words
+            .iter()
+            .map(|word| self.cache.stem_word(word))
+            .collect()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Stemmer('{}')", self.algorithm.name())
+    }
+}
+
+/// The Porter stemmer, with the interface of `nltk.stem.PorterStemmer`.
+#[pyclass(module = "polystem", frozen)]
+struct PorterStemmer;
+
+#[pymethods]
+impl PorterStemmer {
+    #[new]
+    fn new() -> PorterStemmer {
+        PorterStemmer
+    }
+
+    /// Return the stem of `word`.
+    fn stem(&self, word: &str) -> String {
+        Algorithm::Porter.stem(word)
+    }
+
+    fn __repr__(&self) -> &'static str {
+        "<PorterStemmer>"
+    }
+}
+
+/// Return the names of the algorithms, as accepted by `Stemmer`.
+#[pyfunction]
+fn algorithms() -> Vec<&'static str> {
+    Algorithm::ALL.iter().map(|a| a.name()).collect()
+}
+
+// Return the algorithm named `name`.
+fn parse_algorithm(name: &str) -> PyResult<Algorithm> {
+    name.parse().map_err(|e: polystem::ParseAlgorithmError| {
+        PyKeyError::new_err(e.to_string())
+    })
+}
+
+#[pymodule]
+#[pyo3(name = "polystem")]
+fn polystem_module(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<Stemmer>()?;
+    m.add_class::<PorterStemmer>()?;
+    m.add_function(wrap_pyfunction!(algorithms, m)?)?;
+    Ok(())
+}
@@ -0,0 +1,42 @@
+# Copyright 2019 The Polystem authors.
+#
+# For the full copyright and license information, please view the LICENSE file
+# that was distributed with this source code.
+
+import unittest
+
+import polystem
+
+
+class TestStemmer(unittest.TestCase):
+    def test_stem_word(self):
+        stemmer = polystem.Stemmer("porter")
+        self.assertEqual(stemmer.stemWord("rusted"), "rust")
+        self.assertEqual(stemmer.stemWord("rusted"), "rust")
+        self.assertEqual(polystem.Stemmer("s").stemWord("flies"), "fly")
+
+    def test_stem_words(self):
+        stemmer = polystem.Stemmer("porter", maxCacheSize=0)
+        words = ["ponies", "rusted", "connections"]
+        self.assertEqual(stemmer.stemWords(words), ["poni", "rust", "connect"])
+
+    def test_unknown_algorithm(self):
+        with self.assertRaises(KeyError):
+            polystem.Stemmer("porter2")
+        with self.assertRaises(KeyError):
+            polystem.Stemmer("english")
+
+    def test_algorithms(self):
+        self.assertEqual(polystem.algorithms(), ["s", "porter"])
+        self.assertEqual(repr(polystem.Stemmer("Porter")), "Stemmer('porter')")
+
+
+class TestPorterStemmer(unittest.TestCase):
+    def test_stem(self):
+        stemmer = polystem.PorterStemmer()
+        self.assertEqual(stemmer.stem("generalizations"), "gener")
+        self.assertEqual(stemmer.stem("is"), "is")
+
+
+if __name__ == "__main__":
+    unittest.main()
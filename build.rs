@@ -0,0 +1,171 @@
+// Copyright 2019 The Polystem authors.
+//
+// For the full copyright and license information, please view the LICENSE file
+// that was distributed with this source code.
+
+// Compile the suffix rules of each `rules/<name>.rules` file to static Rust
+// tables in `$OUT_DIR/<name>_rules.rs`, included by the stemmer that uses
+// them. See `rules/porter.rules` for the format.
+//
+// With the `snowball` feature, translate each Snowball program
+// `snowball/<name>.sbl` to a module of `$OUT_DIR/snowball.rs`, included by
+// `src/snowball.rs`. Without it the file only has an empty `ALGORITHMS`.
+
+use std::env;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+#[path = "build/snowball.rs"]
+mod snowball;
+
+const RULES_DIR: &str = "rules";
+const SNOWBALL_DIR: &str = "snowball";
+
+fn main() {
+    println!("cargo:rerun-if-changed={}", RULES_DIR);
+
+    println!("cargo:rerun-if-changed={}", SNOWBALL_DIR);
+    println!("cargo:rerun-if-changed=build/snowball.rs");
+
+    let out_dir = env::var("OUT_DIR").expect("OUT_DIR is set by cargo");
+    for path in sources(RULES_DIR, "rules") {
+        println!("cargo:rerun-if-changed={}", path.display());
+        let text = fs::read_to_string(&path).expect("read a rules file");
+        let code = match compile(&text) {
+            Ok(code) => code,
+            Err((line, message)) => {
+                panic!("{}:{}: {}", path.display(), line, message)
+            }
+        };
+
+        let stem = path.file_stem().and_then(|s| s.to_str()).unwrap();
+        let out = Path::new(&out_dir).join(format!("{}_rules.rs", stem));
+        fs::write(out, code).expect("write the generated rules");
+    }
+
+    let mut code = String::new();
+    let mut algorithms = String::new();
+    let programs = match env::var_os("CARGO_FEATURE_SNOWBALL") {
+        Some(_) => sources(SNOWBALL_DIR, "sbl"),
+        None => Vec::new(),
+    };
+    for path in programs {
+        println!("cargo:rerun-if-changed={}", path.display());
+        let text = fs::read_to_string(&path).expect("read a Snowball program");
+        let module = match snowball::translate(&text) {
+            Ok(module) => module,
+            Err((line, message)) => {
+                panic!("{}:{}: {}", path.display(), line, message)
+            }
+        };
+
+        let name = path.file_stem().and_then(|s| s.to_str()).unwrap();
+        if !is_identifier(name) {
+            panic!("{}: invalid program name", path.display());
+        }
+        writeln!(
+            code,
+            "#[allow(non_snake_case, non_upper_case_globals, unused, clippy::all)]\nmod {} {{\n{}}}\n",
+            name, module
+        )
+        .unwrap();
+        writeln!(algorithms, "    (\"{}\", {}::stem as Entry),", name, name)
+            .unwrap();
+    }
+    writeln!(
+        code,
+        "const ALGORITHMS: &[(&str, Entry)] = &[\n{}];",
+        algorithms
+    )
+    .unwrap();
+    let out = Path::new(&out_dir).join("snowball.rs");
+    fs::write(out, code).expect("write the generated Snowball programs");
+}
+
+// Return the sorted paths of the files of `dir` with extension `ext`.
+fn sources(dir: &str, ext: &str) -> Vec<PathBuf> {
+    let mut paths: Vec<_> = fs::read_dir(dir)
+        .expect("read a source directory")
+        .map(|entry| entry.expect("read a source file").path())
+        .filter(|path| path.extension().is_some_and(|e| e == ext))
+        .collect();
+    paths.sort();
+    paths
+}
+
+// Return the Rust tables of the rules in `text`, or the line number and
+// description of the first error.
+fn compile(text: &str) -> Result<String, (usize, String)> {
+    let mut code = String::new();
+    let mut comments = Vec::new();
+    let mut open = false;
+
+    for (i, line) in text.lines().enumerate() {
+        let line = line.trim();
+        let error = |message: &str| (i + 1, message.to_owned());
+
+        if let Some(comment) = line.strip_prefix('#') {
+            comments.push(comment.trim_end());
+            continue;
+        }
+        if line.is_empty() {
+            comments.clear();
+            continue;
+        }
+
+        if let Some(name) = line.strip_prefix('[') {
+            let name = name
+                .strip_suffix(']')
+                .filter(|name| is_identifier(name))
+                .ok_or_else(|| error("invalid section header"))?;
+            if open {
+                code.push_str("];\n\n");
+            }
+            for comment in comments.drain(..) {
+                writeln!(code, "//{}", comment).unwrap();
+            }
+            writeln!(
+                code,
+                "const {}: &[(&str, &str)] = &[",
+                name.to_ascii_uppercase()
+            )
+            .unwrap();
+            open = true;
+            continue;
+        }
+
+        if !open {
+            return Err(error("rule outside of a section"));
+        }
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        let (suffix, replacement) = match fields[..] {
+            [suffix] => (suffix, ""),
+            [suffix, replacement] => (suffix, replacement),
+            _ => return Err(error("expected a suffix and a replacement")),
+        };
+        if !is_letters(suffix)
+            || !(replacement.is_empty() || is_letters(replacement))
+        {
+            return Err(error("rules must be lowercase ASCII letters"));
+        }
+        writeln!(code, "    ({:?}, {:?}),", suffix, replacement).unwrap();
+    }
+
+    if open {
+        code.push_str("];\n");
+    }
+    Ok(code)
+}
+
+// Return `true` if `s` is a non-empty run of lowercase ASCII letters.
+fn is_letters(s: &str) -> bool {
+    !s.is_empty() && s.bytes().all(|b| b.is_ascii_lowercase())
+}
+
+// Return `true` if `s` can name a table.
+fn is_identifier(s: &str) -> bool {
+    s.bytes().next().is_some_and(|b| b.is_ascii_lowercase())
+        && s.bytes()
+            .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'_')
+}
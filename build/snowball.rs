@@ -0,0 +1,1281 @@
+// Copyright 2019 The Polystem authors.
+//
+// For the full copyright and license information, please view the LICENSE file
+// that was distributed with this source code.
+
+// Translate a Snowball program (https://snowballstem.org) to a Rust module
+// run by `src/snowball.rs`. Each routine becomes a function over the `Env`
+// of the runtime, following the C code generated by the Snowball compiler,
+// and each grouping and `among` becomes a static table.
+//
+// The `get` and `reverse` commands and string commands (`$s C`) are not
+// supported, and are reported as errors.
+
+use std::collections::{BTreeSet, HashMap};
+use std::fmt::Write as _;
+
+// The line number and description of an error.
+pub type Error = (usize, String);
+
+// Return the Rust module for the Snowball program `text`. The module
+// defines `pub(super) fn stem(env: &mut Env) -> bool` for the external
+// `stem` routine.
+pub fn translate(text: &str) -> Result<String, Error> {
+    let mut parser = Parser::new(text);
+    parser.program()?;
+    parser.check()?;
+    Ok(Generator::new(&parser).module())
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Token {
+    Name(String),
+    Literal(String),
+    Number(i32),
+    Symbol(&'static str),
+    End,
+}
+
+// The symbols, longest first so that `<-` is not read as `<`.
+const SYMBOLS: &[&str] = &[
+    "<-", "<+", "<=", "->", "=>", "==", "!=", ">=", "+=", "-=", "*=", "/=",
+    "(", ")", "[", "]", "$", "=", "<", ">", "+", "-", "*", "/", "?",
+];
+
+const ASSIGNMENTS: &[&str] = &["=", "+=", "-=", "*=", "/="];
+const COMPARISONS: &[&str] = &["==", "!=", ">", ">=", "<", "<="];
+
+struct Lexer {
+    chars: Vec<char>,
+    pos: usize,
+    line: usize,
+    // The characters around a `stringdef` name in a literal.
+    escapes: Option<(char, char)>,
+    defs: HashMap<String, String>,
+}
+
+impl Lexer {
+    fn error<T>(&self, message: &str) -> Result<T, Error> {
+        Err((self.line, message.to_owned()))
+    }
+
+    fn peek(&self, i: usize) -> Option<char> {
+        self.chars.get(self.pos + i).copied()
+    }
+
+    fn skip_space(&mut self) -> Result<(), Error> {
+        loop {
+            match (self.peek(0), self.peek(1)) {
+                (Some('\n'), _) => {
+                    self.line += 1;
+                    self.pos += 1;
+                }
+                (Some(c), _) if c.is_whitespace() => self.pos += 1,
+                (Some('/'), Some('/')) => {
+                    while self.peek(0).is_some_and(|c| c != '\n') {
+                        self.pos += 1;
+                    }
+                }
+                (Some('/'), Some('*')) => {
+                    self.pos += 2;
+                    loop {
+                        match (self.peek(0), self.peek(1)) {
+                            (None, _) => {
+                                return self.error("unterminated comment")
+                            }
+                            (Some('*'), Some('/')) => {
+                                self.pos += 2;
+                                break;
+                            }
+                            (Some(c), _) => {
+                                if c == '\n' {
+                                    self.line += 1;
+                                }
+                                self.pos += 1;
+                            }
+                        }
+                    }
+                }
+                _ => return Ok(()),
+            }
+        }
+    }
+
+    fn token(&mut self) -> Result<Token, Error> {
+        self.skip_space()?;
+        let c = match self.peek(0) {
+            Some(c) => c,
+            None => return Ok(Token::End),
+        };
+
+        let start = self.pos;
+        if c.is_ascii_alphabetic() {
+            while self
+                .peek(0)
+                .is_some_and(|c| c.is_ascii_alphanumeric() || c == '_')
+            {
+                self.pos += 1;
+            }
+            return Ok(Token::Name(
+                self.chars[start..self.pos].iter().collect(),
+            ));
+        }
+        if c.is_ascii_digit() {
+            while self.peek(0).is_some_and(|c| c.is_ascii_digit()) {
+                self.pos += 1;
+            }
+            let digits: String = self.chars[start..self.pos].iter().collect();
+            return match digits.parse() {
+                Ok(n) => Ok(Token::Number(n)),
+                Err(_) => self.error("number out of range"),
+            };
+        }
+        if c == '\'' {
+            self.pos += 1;
+            return self.literal().map(Token::Literal);
+        }
+
+        for symbol in SYMBOLS {
+            if symbol
+                .chars()
+                .enumerate()
+                .all(|(i, s)| self.peek(i) == Some(s))
+            {
+                self.pos += symbol.len();
+                return Ok(Token::Symbol(symbol));
+            }
+        }
+        self.error(&format!("unexpected character `{}`", c))
+    }
+
+    // Read a literal after its opening quote, replacing escapes.
+    fn literal(&mut self) -> Result<String, Error> {
+        let mut s = String::new();
+        loop {
+            let c = match self.peek(0) {
+                Some('\n') | None => return self.error("unterminated string"),
+                Some(c) => c,
+            };
+            self.pos += 1;
+            match self.escapes {
+                _ if c == '\'' => return Ok(s),
+                Some((open, close)) if c == open => {
+                    let start = self.pos;
+                    while self.peek(0).is_some_and(|c| c != close && c != '\n')
+                    {
+                        self.pos += 1;
+                    }
+                    if self.peek(0) != Some(close) {
+                        return self.error("unterminated escape");
+                    }
+                    let name: String =
+                        self.chars[start..self.pos].iter().collect();
+                    self.pos += 1;
+                    s.push_str(&self.escape(&name)?);
+                }
+                _ => s.push(c),
+            }
+        }
+    }
+
+    fn escape(&self, name: &str) -> Result<String, Error> {
+        if name == "'"
+            || self
+                .escapes
+                .is_some_and(|(open, _)| name == open.to_string())
+        {
+            return Ok(name.to_owned());
+        }
+        if let Some(hex) = name.strip_prefix("U+") {
+            return match code_point(hex, 16) {
+                Some(c) => Ok(c.to_string()),
+                None => self.error(&format!("invalid code point `{}`", name)),
+            };
+        }
+        match self.defs.get(name) {
+            Some(s) => Ok(s.clone()),
+            None => self.error(&format!("unknown stringdef `{}`", name)),
+        }
+    }
+
+    // Read the whitespace delimited name of a `stringdef`.
+    fn word(&mut self) -> Result<String, Error> {
+        self.skip_space()?;
+        let start = self.pos;
+        while self.peek(0).is_some_and(|c| !c.is_whitespace()) {
+            self.pos += 1;
+        }
+        if start == self.pos {
+            return self.error("expected a name");
+        }
+        Ok(self.chars[start..self.pos].iter().collect())
+    }
+
+    // Read the two characters of `stringescapes`.
+    fn escape_chars(&mut self) -> Result<(char, char), Error> {
+        self.skip_space()?;
+        match (self.peek(0), self.peek(1)) {
+            (Some(open), Some(close)) if !close.is_whitespace() => {
+                self.pos += 2;
+                Ok((open, close))
+            }
+            _ => self.error("expected two escape characters"),
+        }
+    }
+}
+
+// Return the character with the code point written in `radix`.
+fn code_point(digits: &str, radix: u32) -> Option<char> {
+    u32::from_str_radix(digits, radix)
+        .ok()
+        .and_then(std::char::from_u32)
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Kind {
+    String,
+    Integer,
+    Boolean,
+    Routine,
+    External,
+    Grouping,
+}
+
+#[derive(Debug)]
+enum Node {
+    List(Vec<Node>),
+    Or(Vec<Node>),
+    And(Vec<Node>),
+    Not(Box<Node>),
+    Test(Box<Node>),
+    Try(Box<Node>),
+    Do(Box<Node>),
+    Fail(Box<Node>),
+    Goto(Box<Node>),
+    Gopast(Box<Node>),
+    Repeat(Box<Node>),
+    Backwards(Box<Node>),
+    Loop(Ae, Box<Node>),
+    Atleast(Ae, Box<Node>),
+    Setlimit(Box<Node>, Box<Node>),
+    Literal(String),
+    StringVar(String),
+    Grouping(String, bool),
+    Call(String),
+    Boolean(String),
+    SetBoolean(String, bool),
+    Bra,
+    Ket,
+    SliceFrom(Source),
+    Insert(Source),
+    Attach(Source),
+    SliceTo(String),
+    AssignTo(String),
+    Hop(Ae),
+    Setmark(String),
+    Tomark(Ae),
+    Atmark(Ae),
+    Tolimit,
+    Atlimit,
+    True,
+    False,
+    Assign(String, &'static str, Ae),
+    Compare(Ae, &'static str, Ae),
+    Substring(usize),
+    Among(usize),
+}
+
+#[derive(Debug)]
+enum Source {
+    Literal(String),
+    Var(String),
+}
+
+// An arithmetic expression.
+#[derive(Debug)]
+enum Ae {
+    Number(i32),
+    Integer(String),
+    Cursor,
+    Limit,
+    Size,
+    Len,
+    Sizeof(String),
+    Lenof(String),
+    Maxint,
+    Minint,
+    Neg(Box<Ae>),
+    Binary(Box<Ae>, &'static str, Box<Ae>),
+}
+
+// The strings of an `among`, with their optional routine and the number of
+// their command, and the commands.
+#[derive(Debug, Default)]
+struct Among {
+    strings: Vec<(String, Option<String>, usize)>,
+    commands: Vec<Node>,
+    // The search is done by an earlier `substring`.
+    substring: bool,
+}
+
+struct Routine {
+    name: String,
+    backward: bool,
+    body: Node,
+}
+
+struct Parser {
+    lexer: Lexer,
+    held: Option<Token>,
+    kinds: HashMap<String, Kind>,
+    // The names of each kind in order of declaration.
+    declared: Vec<(String, Kind)>,
+    groupings: Vec<(String, BTreeSet<char>)>,
+    routines: Vec<Routine>,
+    amongs: Vec<Among>,
+    // The `among` of a `substring` of the routine being defined that has
+    // not been seen yet.
+    substring: Option<usize>,
+    backward: bool,
+}
+
+impl Parser {
+    fn new(text: &str) -> Parser {
+        Parser {
+            lexer: Lexer {
+                chars: text.chars().collect(),
+                pos: 0,
+                line: 1,
+                escapes: None,
+                defs: HashMap::new(),
+            },
+            held: None,
+            kinds: HashMap::new(),
+            declared: Vec::new(),
+            groupings: Vec::new(),
+            routines: Vec::new(),
+            amongs: Vec::new(),
+            substring: None,
+            backward: false,
+        }
+    }
+
+    fn error<T>(&self, message: &str) -> Result<T, Error> {
+        self.lexer.error(message)
+    }
+
+    fn next(&mut self) -> Result<Token, Error> {
+        match self.held.take() {
+            Some(token) => Ok(token),
+            None => self.lexer.token(),
+        }
+    }
+
+    fn hold(&mut self, token: Token) {
+        self.held = Some(token);
+    }
+
+    fn expect(&mut self, symbol: &str) -> Result<(), Error> {
+        match self.next()? {
+            Token::Symbol(s) if s == symbol => Ok(()),
+            _ => self.error(&format!("expected `{}`", symbol)),
+        }
+    }
+
+    // Read a name declared as one of `kinds`.
+    fn var(&mut self, kinds: &[Kind]) -> Result<String, Error> {
+        match self.next()? {
+            Token::Name(name) => match self.kinds.get(&name) {
+                Some(kind) if kinds.contains(kind) => Ok(name),
+                Some(kind) => self.error(&format!(
+                    "`{}` is a {:?}, expected a {:?}",
+                    name, kind, kinds[0]
+                )),
+                None => self.error(&format!("`{}` is not declared", name)),
+            },
+            _ => self.error("expected a name"),
+        }
+    }
+
+    fn program(&mut self) -> Result<(), Error> {
+        loop {
+            let name = match self.next()? {
+                Token::End => return Ok(()),
+                Token::Name(name) => name,
+                _ => {
+                    return self.error("expected a declaration or definition")
+                }
+            };
+            match name.as_str() {
+                "strings" => self.declare(Kind::String)?,
+                "integers" => self.declare(Kind::Integer)?,
+                "booleans" => self.declare(Kind::Boolean)?,
+                "routines" => self.declare(Kind::Routine)?,
+                "externals" => self.declare(Kind::External)?,
+                "groupings" => self.declare(Kind::Grouping)?,
+                "define" => self.define()?,
+                "backwardmode" => {
+                    if self.backward {
+                        return self.error("nested backwardmode");
+                    }
+                    self.expect("(")?;
+                    self.backward = true;
+                    loop {
+                        match self.next()? {
+                            Token::Symbol(")") => break,
+                            Token::Name(name) if name == "define" => {
+                                self.define()?
+                            }
+                            _ => return self.error("expected a definition"),
+                        }
+                    }
+                    self.backward = false;
+                }
+                "stringescapes" => {
+                    self.lexer.escapes = Some(self.lexer.escape_chars()?)
+                }
+                "stringdef" => {
+                    let name = self.lexer.word()?;
+                    let value = match self.next()? {
+                        Token::Literal(s) => s,
+                        Token::Name(radix) if radix == "hex" => {
+                            self.codes(16)?
+                        }
+                        Token::Name(radix) if radix == "decimal" => {
+                            self.codes(10)?
+                        }
+                        _ => return self.error("expected a string"),
+                    };
+                    self.lexer.defs.insert(name, value);
+                }
+                "get" => return self.error("`get` is not supported"),
+                _ => {
+                    return self.error(&format!("unexpected `{}`", name));
+                }
+            }
+        }
+    }
+
+    // Read the literal of code points of `stringdef name hex '...'`.
+    fn codes(&mut self, radix: u32) -> Result<String, Error> {
+        let literal = match self.next()? {
+            Token::Literal(s) => s,
+            _ => return self.error("expected a string"),
+        };
+        literal
+            .split_whitespace()
+            .map(|digits| code_point(digits, radix))
+            .collect::<Option<String>>()
+            .map_or_else(|| self.error("invalid code point"), Ok)
+    }
+
+    fn declare(&mut self, kind: Kind) -> Result<(), Error> {
+        self.expect("(")?;
+        loop {
+            match self.next()? {
+                Token::Symbol(")") => return Ok(()),
+                Token::Name(name) => {
+                    if self.kinds.insert(name.clone(), kind).is_some() {
+                        return self
+                            .error(&format!("`{}` is declared twice", name));
+                    }
+                    self.declared.push((name, kind));
+                }
+                _ => return self.error("expected a name"),
+            }
+        }
+    }
+
+    fn define(&mut self) -> Result<(), Error> {
+        let name =
+            self.var(&[Kind::Routine, Kind::External, Kind::Grouping])?;
+        let defined = self.routines.iter().any(|r| r.name == name)
+            || self.groupings.iter().any(|(g, _)| *g == name);
+        if defined {
+            return self.error(&format!("`{}` is defined twice", name));
+        }
+
+        if self.kinds[&name] == Kind::Grouping {
+            let chars = self.grouping()?;
+            self.groupings.push((name, chars));
+            return Ok(());
+        }
+
+        match self.next()? {
+            Token::Name(as_) if as_ == "as" => {}
+            _ => return self.error("expected `as`"),
+        }
+        self.substring = None;
+        let body = self.command()?;
+        if self.substring.is_some() {
+            return self.error("`substring` without `among`");
+        }
+        self.routines.push(Routine {
+            name,
+            backward: self.backward,
+            body,
+        });
+        Ok(())
+    }
+
+    // Read the characters of a grouping: literals and groupings joined by
+    // `+` and `-`.
+    fn grouping(&mut self) -> Result<BTreeSet<char>, Error> {
+        let mut chars = BTreeSet::new();
+        let mut add = true;
+        loop {
+            let operand: BTreeSet<char> = match self.next()? {
+                Token::Literal(s) => s.chars().collect(),
+                Token::Name(name) => {
+                    match self.groupings.iter().find(|(g, _)| *g == name) {
+                        Some((_, chars)) => chars.clone(),
+                        None => {
+                            return self.error(&format!(
+                                "grouping `{}` is not defined",
+                                name
+                            ))
+                        }
+                    }
+                }
+                _ => return self.error("expected a string or grouping"),
+            };
+            if add {
+                chars.extend(operand);
+            } else {
+                chars.retain(|c| !operand.contains(c));
+            }
+
+            match self.next()? {
+                Token::Symbol("+") => add = true,
+                Token::Symbol("-") => add = false,
+                token => {
+                    self.hold(token);
+                    return Ok(chars);
+                }
+            }
+        }
+    }
+
+    // Read a command and any commands joined to it by `or` and `and`.
+    fn connected(&mut self) -> Result<Node, Error> {
+        let mut node = self.command()?;
+        loop {
+            let op = match self.next()? {
+                Token::Name(op) if op == "or" || op == "and" => op,
+                token => {
+                    self.hold(token);
+                    return Ok(node);
+                }
+            };
+            let mut nodes = vec![node, self.command()?];
+            loop {
+                match self.next()? {
+                    Token::Name(name) if name == op => {
+                        nodes.push(self.command()?)
+                    }
+                    token => {
+                        self.hold(token);
+                        break;
+                    }
+                }
+            }
+            node = if op == "or" {
+                Node::Or(nodes)
+            } else {
+                Node::And(nodes)
+            };
+        }
+    }
+
+    fn command(&mut self) -> Result<Node, Error> {
+        let node = match self.next()? {
+            Token::Symbol("(") => {
+                let mut nodes = Vec::new();
+                loop {
+                    match self.next()? {
+                        Token::Symbol(")") => break,
+                        token => {
+                            self.hold(token);
+                            nodes.push(self.connected()?);
+                        }
+                    }
+                }
+                Node::List(nodes)
+            }
+            Token::Literal(s) => Node::Literal(s),
+            Token::Symbol("[") => Node::Bra,
+            Token::Symbol("]") => Node::Ket,
+            Token::Symbol("<-") => Node::SliceFrom(self.source()?),
+            Token::Symbol("<+") => Node::Insert(self.source()?),
+            Token::Symbol("->") => Node::SliceTo(self.var(&[Kind::String])?),
+            Token::Symbol("=>") => Node::AssignTo(self.var(&[Kind::String])?),
+            Token::Symbol("$") => self.integer_command()?,
+            Token::Symbol("?") => Node::True,
+            Token::Name(name) => self.named_command(name)?,
+            Token::End => return self.error("unexpected end of program"),
+            token => return self.error(&format!("unexpected {:?}", token)),
+        };
+        Ok(node)
+    }
+
+    fn named_command(&mut self, name: String) -> Result<Node, Error> {
+        let unary = |parser: &mut Parser| parser.command().map(Box::new);
+        let node = match name.as_str() {
+            "not" => Node::Not(unary(self)?),
+            "test" => Node::Test(unary(self)?),
+            "try" => Node::Try(unary(self)?),
+            "do" => Node::Do(unary(self)?),
+            "fail" => Node::Fail(unary(self)?),
+            "goto" => Node::Goto(unary(self)?),
+            "gopast" => Node::Gopast(unary(self)?),
+            "repeat" => Node::Repeat(unary(self)?),
+            "backwards" => Node::Backwards(unary(self)?),
+            "loop" => Node::Loop(self.ae()?, unary(self)?),
+            "atleast" => Node::Atleast(self.ae()?, unary(self)?),
+            "setlimit" => {
+                let limit = unary(self)?;
+                match self.next()? {
+                    Token::Name(name) if name == "for" => {}
+                    _ => return self.error("expected `for`"),
+                }
+                Node::Setlimit(limit, unary(self)?)
+            }
+            "hop" => Node::Hop(self.ae()?),
+            "next" => Node::Hop(Ae::Number(1)),
+            "setmark" => Node::Setmark(self.var(&[Kind::Integer])?),
+            "tomark" => Node::Tomark(self.ae()?),
+            "atmark" => Node::Atmark(self.ae()?),
+            "tolimit" => Node::Tolimit,
+            "atlimit" => Node::Atlimit,
+            "true" => Node::True,
+            "false" => Node::False,
+            "delete" => Node::SliceFrom(Source::Literal(String::new())),
+            "insert" => Node::Insert(self.source()?),
+            "attach" => Node::Attach(self.source()?),
+            "set" => Node::SetBoolean(self.var(&[Kind::Boolean])?, true),
+            "unset" => Node::SetBoolean(self.var(&[Kind::Boolean])?, false),
+            "non" => {
+                match self.next()? {
+                    Token::Symbol("-") => {}
+                    token => self.hold(token),
+                }
+                Node::Grouping(self.var(&[Kind::Grouping])?, false)
+            }
+            "among" => self.among()?,
+            "substring" => {
+                if self.substring.is_some() {
+                    return self.error("`substring` without `among`");
+                }
+                self.amongs.push(Among::default());
+                self.substring = Some(self.amongs.len() - 1);
+                Node::Substring(self.amongs.len() - 1)
+            }
+            "reverse" => return self.error("`reverse` is not supported"),
+            _ => match self.kinds.get(&name) {
+                Some(Kind::Routine) | Some(Kind::External) => Node::Call(name),
+                Some(Kind::Boolean) => Node::Boolean(name),
+                Some(Kind::Grouping) => Node::Grouping(name, true),
+                Some(Kind::String) => Node::StringVar(name),
+                Some(Kind::Integer) => {
+                    return self.error(&format!(
+                        "integer `{}` is not a command",
+                        name
+                    ))
+                }
+                None => {
+                    return self.error(&format!("`{}` is not declared", name))
+                }
+            },
+        };
+        Ok(node)
+    }
+
+    fn source(&mut self) -> Result<Source, Error> {
+        match self.next()? {
+            Token::Literal(s) => Ok(Source::Literal(s)),
+            token => {
+                self.hold(token);
+                self.var(&[Kind::String]).map(Source::Var)
+            }
+        }
+    }
+
+    // Read an integer command after `$`.
+    fn integer_command(&mut self) -> Result<Node, Error> {
+        match self.next()? {
+            Token::Symbol("(") => {
+                let left = self.ae()?;
+                let op = self.operator(COMPARISONS)?;
+                let right = self.ae()?;
+                self.expect(")")?;
+                Ok(Node::Compare(left, op, right))
+            }
+            Token::Name(name) => {
+                match self.kinds.get(&name) {
+                    Some(Kind::Integer) => {}
+                    Some(Kind::String) => {
+                        return self.error("string commands are not supported")
+                    }
+                    _ => return self.error("expected an integer"),
+                }
+                let op =
+                    self.operator(&[ASSIGNMENTS, COMPARISONS].concat())?;
+                let ae = self.ae()?;
+                if ASSIGNMENTS.contains(&op) {
+                    Ok(Node::Assign(name, op, ae))
+                } else {
+                    Ok(Node::Compare(Ae::Integer(name), op, ae))
+                }
+            }
+            _ => self.error("expected an integer"),
+        }
+    }
+
+    fn operator(&mut self, ops: &[&str]) -> Result<&'static str, Error> {
+        match self.next()? {
+            Token::Symbol(op) if ops.contains(&op) => Ok(op),
+            _ => self.error("expected an operator"),
+        }
+    }
+
+    fn ae(&mut self) -> Result<Ae, Error> {
+        let mut ae = self.term()?;
+        loop {
+            match self.next()? {
+                Token::Symbol(op @ "+") | Token::Symbol(op @ "-") => {
+                    ae = Ae::Binary(Box::new(ae), op, Box::new(self.term()?))
+                }
+                token => {
+                    self.hold(token);
+                    return Ok(ae);
+                }
+            }
+        }
+    }
+
+    fn term(&mut self) -> Result<Ae, Error> {
+        let mut ae = self.factor()?;
+        loop {
+            match self.next()? {
+                Token::Symbol(op @ "*") | Token::Symbol(op @ "/") => {
+                    ae = Ae::Binary(Box::new(ae), op, Box::new(self.factor()?))
+                }
+                token => {
+                    self.hold(token);
+                    return Ok(ae);
+                }
+            }
+        }
+    }
+
+    fn factor(&mut self) -> Result<Ae, Error> {
+        let ae = match self.next()? {
+            Token::Number(n) => Ae::Number(n),
+            Token::Symbol("-") => Ae::Neg(Box::new(self.factor()?)),
+            Token::Symbol("(") => {
+                let ae = self.ae()?;
+                self.expect(")")?;
+                ae
+            }
+            Token::Name(name) => match name.as_str() {
+                "cursor" => Ae::Cursor,
+                "limit" => Ae::Limit,
+                "size" => Ae::Size,
+                "len" => Ae::Len,
+                "maxint" => Ae::Maxint,
+                "minint" => Ae::Minint,
+                "sizeof" => Ae::Sizeof(self.var(&[Kind::String])?),
+                "lenof" => Ae::Lenof(self.var(&[Kind::String])?),
+                _ => {
+                    self.hold(Token::Name(name));
+                    Ae::Integer(self.var(&[Kind::Integer])?)
+                }
+            },
+            _ => return self.error("expected an arithmetic expression"),
+        };
+        Ok(ae)
+    }
+
+    fn among(&mut self) -> Result<Node, Error> {
+        // Commands of the among may have a `substring` of their own.
+        let substring = self.substring.take();
+        self.expect("(")?;
+        let mut among = Among::default();
+        let mut pending = Vec::new();
+        loop {
+            match self.next()? {
+                Token::Literal(s) => {
+                    let routine = match self.next()? {
+                        Token::Name(name) => {
+                            self.hold(Token::Name(name));
+                            Some(self.var(&[Kind::Routine, Kind::External])?)
+                        }
+                        token => {
+                            self.hold(token);
+                            None
+                        }
+                    };
+                    let seen = among.strings.iter().map(|(s, _, _)| s);
+                    if seen
+                        .chain(pending.iter().map(|(s, _)| s))
+                        .any(|t| *t == s)
+                    {
+                        return self
+                            .error(&format!("`{}` is repeated in among", s));
+                    }
+                    pending.push((s, routine));
+                }
+                Token::Symbol("(") => {
+                    if pending.is_empty() {
+                        return self.error("among command without strings");
+                    }
+                    self.hold(Token::Symbol("("));
+                    among.commands.push(self.command()?);
+                    let result = among.commands.len();
+                    among.strings.extend(
+                        pending.drain(..).map(|(s, r)| (s, r, result)),
+                    );
+                }
+                Token::Symbol(")") => break,
+                _ => return self.error("expected a string or command"),
+            }
+        }
+        if !pending.is_empty() {
+            among.commands.push(Node::True);
+            let result = among.commands.len();
+            among
+                .strings
+                .extend(pending.drain(..).map(|(s, r)| (s, r, result)));
+        }
+
+        match substring {
+            Some(id) => {
+                among.substring = true;
+                self.amongs[id] = among;
+                Ok(Node::Among(id))
+            }
+            None => {
+                self.amongs.push(among);
+                Ok(Node::Among(self.amongs.len() - 1))
+            }
+        }
+    }
+
+    // Check that every routine and grouping is defined, and that there is
+    // a `stem` external.
+    fn check(&self) -> Result<(), Error> {
+        for (name, kind) in &self.declared {
+            let defined = match kind {
+                Kind::Routine | Kind::External => {
+                    self.routines.iter().any(|r| r.name == *name)
+                }
+                Kind::Grouping => {
+                    self.groupings.iter().any(|(g, _)| g == name)
+                }
+                _ => true,
+            };
+            if !defined {
+                return self.error(&format!("`{}` is not defined", name));
+            }
+        }
+        if self.kinds.get("stem") != Some(&Kind::External) {
+            return self.error("there is no `stem` external");
+        }
+        Ok(())
+    }
+}
+
+struct Generator<'p> {
+    parser: &'p Parser,
+    // The number of variables and labels generated so far.
+    vars: usize,
+}
+
+impl<'p> Generator<'p> {
+    fn new(parser: &'p Parser) -> Generator<'p> {
+        Generator { parser, vars: 0 }
+    }
+
+    fn module(&mut self) -> String {
+        let mut code = String::new();
+        code.push_str("use super::{Among, Env};\n\n");
+
+        code.push_str("#[derive(Default)]\nstruct Context {\n");
+        for (name, kind) in &self.parser.declared {
+            let field = match kind {
+                Kind::String => format!("s_{}: String", name),
+                Kind::Integer => format!("i_{}: i32", name),
+                Kind::Boolean => format!("b_{}: bool", name),
+                _ => continue,
+            };
+            writeln!(code, "    {},", field).unwrap();
+        }
+        code.push_str("}\n\n");
+
+        for (name, chars) in &self.parser.groupings {
+            let chars: Vec<String> =
+                chars.iter().map(|c| format!("{:?}", c)).collect();
+            writeln!(
+                code,
+                "static G_{}: &[char] = &[{}];",
+                name,
+                chars.join(", ")
+            )
+            .unwrap();
+        }
+
+        for (id, among) in self.parser.amongs.iter().enumerate() {
+            let mut strings: Vec<_> = among.strings.iter().collect();
+            strings.sort_by_key(|(s, _, _)| std::cmp::Reverse(s.len()));
+            writeln!(code, "static A_{}: &[Among<Context>] = &[", id).unwrap();
+            for (s, routine, result) in strings {
+                let cond = match routine {
+                    Some(name) => format!("Some(r_{})", name),
+                    None => String::from("None"),
+                };
+                writeln!(
+                    code,
+                    "    Among {{ s: {}, result: {}, cond: {} }},",
+                    bytes(s),
+                    result,
+                    cond
+                )
+                .unwrap();
+            }
+            code.push_str("];\n");
+        }
+
+        code.push_str(
+            "\npub(super) fn stem(env: &mut Env) -> bool {\n    \
+             r_stem(env, &mut Context::default())\n}\n",
+        );
+
+        for routine in &self.parser.routines {
+            let body = self.command(&routine.body, routine.backward);
+            write!(
+                code,
+                "\nfn r_{}(env: &mut Env, ctx: &mut Context) -> bool {{\n    \
+                 let mut among_var = 0;\n    {}\n}}\n",
+                routine.name, body
+            )
+            .unwrap();
+        }
+        code
+    }
+
+    fn var(&mut self) -> String {
+        self.vars += 1;
+        format!("v_{}", self.vars)
+    }
+
+    // Return the statements that save and restore the cursor. Backward
+    // mode keeps the distance from the limit, since slices change the
+    // text after the cursor.
+    fn save(&mut self, backward: bool) -> (String, String) {
+        let v = self.var();
+        if backward {
+            (
+                format!("let {} = env.l - env.c;", v),
+                format!("env.c = env.l - {};", v),
+            )
+        } else {
+            (format!("let {} = env.c;", v), format!("env.c = {};", v))
+        }
+    }
+
+    // Return a `bool` expression that performs `node`.
+    fn command(&mut self, node: &Node, b: bool) -> String {
+        let suffix = if b { "_b" } else { "" };
+        match node {
+            Node::List(nodes) if nodes.is_empty() => String::from("true"),
+            Node::List(nodes) if nodes.len() == 1 => self.command(&nodes[0], b),
+            Node::List(nodes) => {
+                let nodes: Vec<_> =
+                    nodes.iter().map(|node| self.command(node, b)).collect();
+                format!("({})", nodes.join(" && "))
+            }
+            Node::Or(nodes) => {
+                let label = format!("'{}", self.var());
+                let (save, restore) = self.save(b);
+                let mut code = format!("{}: {{ {} ", label, save);
+                for (i, node) in nodes.iter().enumerate() {
+                    let node = self.command(node, b);
+                    if i + 1 < nodes.len() {
+                        write!(
+                            code,
+                            "if {} {{ break {} true; }} {} ",
+                            node, label, restore
+                        )
+                        .unwrap();
+                    } else {
+                        write!(code, "{} }}", node).unwrap();
+                    }
+                }
+                code
+            }
+            Node::And(nodes) => {
+                let (save, restore) = self.save(b);
+                let nodes: Vec<_> =
+                    nodes.iter().map(|node| self.command(node, b)).collect();
+                let rest: Vec<_> = nodes[1..]
+                    .iter()
+                    .map(|node| format!("{{ {} {} }}", restore, node))
+                    .collect();
+                format!("{{ {} {} && {} }}", save, nodes[0], rest.join(" && "))
+            }
+            Node::Not(node) => {
+                let (save, restore) = self.save(b);
+                let node = self.command(node, b);
+                format!(
+                    "{{ {} if {} {{ false }} else {{ {} true }} }}",
+                    save, node, restore
+                )
+            }
+            Node::Test(node) => {
+                let (save, restore) = self.save(b);
+                let node = self.command(node, b);
+                format!(
+                    "{{ {} if {} {{ {} true }} else {{ false }} }}",
+                    save, node, restore
+                )
+            }
+            Node::Try(node) => {
+                let (save, restore) = self.save(b);
+                let node = self.command(node, b);
+                format!("{{ {} if !{} {{ {} }} true }}", save, node, restore)
+            }
+            Node::Do(node) => {
+                let (save, restore) = self.save(b);
+                let node = self.command(node, b);
+                format!("{{ {} let _ = {}; {} true }}", save, node, restore)
+            }
+            Node::Fail(node) => {
+                format!("{{ let _ = {}; false }}", self.command(node, b))
+            }
+            Node::Goto(inner) | Node::Gopast(inner) => {
+                let (save, restore) = self.save(b);
+                let inner = self.command(inner, b);
+                // `goto` leaves the cursor before the match, `gopast` after.
+                let found = match node {
+                    Node::Goto(_) => restore.as_str(),
+                    _ => "",
+                };
+                format!(
+                    "loop {{ {} if {} {{ {} break true; }} {} \
+                     if !env.next{}() {{ break false; }} }}",
+                    save, inner, found, restore, suffix
+                )
+            }
+            Node::Repeat(node) => {
+                let (save, restore) = self.save(b);
+                let node = self.command(node, b);
+                format!(
+                    "{{ loop {{ {} if !{} {{ {} break; }} }} true }}",
+                    save, node, restore
+                )
+            }
+            Node::Backwards(node) => {
+                let v = self.var();
+                let node = self.command(node, true);
+                format!(
+                    "{{ env.lb = env.c; env.c = env.l; let {} = {}; \
+                     env.c = env.lb; {} }}",
+                    v, node, v
+                )
+            }
+            Node::Loop(ae, node) => {
+                let n = self.var();
+                let ae = self.ae(ae, b);
+                let node = self.command(node, b);
+                format!(
+                    "{{ let mut {n} = {}; loop {{ if {n} <= 0 {{ break true; }} \
+                     if !{} {{ break false; }} {n} -= 1; }} }}",
+                    ae,
+                    node,
+                    n = n
+                )
+            }
+            Node::Atleast(ae, node) => {
+                let n = self.var();
+                let ae = self.ae(ae, b);
+                let (save, restore) = self.save(b);
+                let node = self.command(node, b);
+                format!(
+                    "{{ let mut {n} = {}; loop {{ if {n} <= 0 {{ \
+                     loop {{ {} if !{node} {{ {} break; }} }} break true; }} \
+                     if !{node} {{ break false; }} {n} -= 1; }} }}",
+                    ae,
+                    save,
+                    restore,
+                    n = n,
+                    node = node
+                )
+            }
+            Node::Setlimit(limit, node) => {
+                let (save, restore) = self.save(b);
+                let m = self.var();
+                let limit = self.command(limit, b);
+                let node = self.command(node, b);
+                let (set, reset) = if b {
+                    (
+                        format!("let {} = env.lb; env.lb = env.c;", m),
+                        format!("env.lb = {};", m),
+                    )
+                } else {
+                    (
+                        format!("let {} = env.l - env.c; env.l = env.c;", m),
+                        format!("env.l += {};", m),
+                    )
+                };
+                let r = self.var();
+                format!(
+                    "{{ {} if !{} {{ false }} else {{ {} {} let {} = {}; \
+                     {} {} }} }}",
+                    save, limit, set, restore, r, node, reset, r
+                )
+            }
+            Node::Literal(s) => format!("env.eq_s{}({})", suffix, bytes(s)),
+            Node::StringVar(name) => {
+                format!("env.eq_s{}(ctx.s_{}.as_bytes())", suffix, name)
+            }
+            Node::Grouping(name, member) => {
+                let which = if *member { "in" } else { "out" };
+                format!("env.{}_grouping{}(G_{})", which, suffix, name)
+            }
+            Node::Call(name) => format!("r_{}(env, ctx)", name),
+            Node::Boolean(name) => format!("ctx.b_{}", name),
+            Node::SetBoolean(name, value) => {
+                format!("{{ ctx.b_{} = {}; true }}", name, value)
+            }
+            Node::Bra | Node::Ket => {
+                let bra = matches!(node, Node::Bra) != b;
+                format!("{{ env.{} = env.c; true }}", if bra { "bra" } else { "ket" })
+            }
+            Node::SliceFrom(source) => {
+                format!("env.slice_from({})", source_bytes(source))
+            }
+            Node::Insert(source) => {
+                format!("env.insert({})", source_bytes(source))
+            }
+            Node::Attach(source) => {
+                format!("env.attach({})", source_bytes(source))
+            }
+            Node::SliceTo(name) => format!(
+                "match env.slice_to() {{ Some(s) => {{ ctx.s_{} = s; true }} \
+                 None => false }}",
+                name
+            ),
+            Node::AssignTo(name) => {
+                format!("{{ ctx.s_{} = env.assign_to(); true }}", name)
+            }
+            Node::Hop(ae) => {
+                let ae = self.ae(ae, b);
+                format!("env.hop{}({})", suffix, ae)
+            }
+            Node::Setmark(name) => {
+                format!("{{ ctx.i_{} = env.c as i32; true }}", name)
+            }
+            Node::Tomark(ae) => {
+                let m = self.var();
+                let ae = self.ae(ae, b);
+                let fail = if b {
+                    format!("(env.c as i32) < {m} || {m} < env.lb as i32", m = m)
+                } else {
+                    format!("(env.c as i32) > {m} || {m} > env.l as i32", m = m)
+                };
+                format!(
+                    "{{ let {} = {}; if {} {{ false }} else {{ env.c = {} as usize; true }} }}",
+                    m, ae, fail, m
+                )
+            }
+            Node::Atmark(ae) => {
+                format!("((env.c as i32) == {})", self.ae(ae, b))
+            }
+            Node::Tolimit => {
+                format!("{{ env.c = env.{}; true }}", if b { "lb" } else { "l" })
+            }
+            Node::Atlimit => {
+                format!("(env.c == env.{})", if b { "lb" } else { "l" })
+            }
+            Node::True => String::from("true"),
+            Node::False => String::from("false"),
+            Node::Assign(name, op, ae) => {
+                format!("{{ ctx.i_{} {} {}; true }}", name, op, self.ae(ae, b))
+            }
+            Node::Compare(left, op, right) => {
+                let left = self.ae(left, b);
+                let right = self.ae(right, b);
+                format!("({} {} {})", left, op, right)
+            }
+            Node::Substring(id) => format!(
+                "{{ among_var = env.find_among{}(A_{}, ctx); among_var != 0 }}",
+                suffix, id
+            ),
+            Node::Among(id) => {
+                let among = &self.parser.amongs[*id];
+                let mut arms = String::new();
+                for (i, command) in among.commands.iter().enumerate() {
+                    let command = self.command(command, b);
+                    write!(arms, "{} => {}, ", i + 1, command).unwrap();
+                }
+                let dispatch = format!("match among_var {{ {}_ => true }}", arms);
+                if among.substring {
+                    dispatch
+                } else {
+                    format!(
+                        "{{ among_var = env.find_among{}(A_{}, ctx); \
+                         among_var != 0 && {} }}",
+                        suffix, id, dispatch
+                    )
+                }
+            }
+        }
+    }
+
+    // Return an `i32` expression for `ae`.
+    fn ae(&mut self, ae: &Ae, b: bool) -> String {
+        match ae {
+            Ae::Number(n) => format!("({})", n),
+            Ae::Integer(name) => format!("ctx.i_{}", name),
+            Ae::Cursor => String::from("(env.c as i32)"),
+            Ae::Limit => {
+                format!("(env.{} as i32)", if b { "lb" } else { "l" })
+            }
+            Ae::Size => String::from("(env.current.len() as i32)"),
+            Ae::Len => String::from("env.len()"),
+            Ae::Sizeof(name) => format!("(ctx.s_{}.len() as i32)", name),
+            Ae::Lenof(name) => {
+                format!("(ctx.s_{}.chars().count() as i32)", name)
+            }
+            Ae::Maxint => String::from("i32::MAX"),
+            Ae::Minint => String::from("i32::MIN"),
+            Ae::Neg(ae) => format!("(-{})", self.ae(ae, b)),
+            Ae::Binary(left, op, right) => {
+                let left = self.ae(left, b);
+                let right = self.ae(right, b);
+                format!("({} {} {})", left, op, right)
+            }
+        }
+    }
+}
+
+// Return a byte string literal of `s`.
+fn bytes(s: &str) -> String {
+    let mut literal = String::from("b\"");
+    for b in s.bytes() {
+        match b {
+            b'"' | b'\\' => write!(literal, "\\{}", b as char).unwrap(),
+            b' '..=b'~' => literal.push(b as char),
+            _ => write!(literal, "\\x{:02x}", b).unwrap(),
+        }
+    }
+    literal.push('"');
+    literal
+}
+
+fn source_bytes(source: &Source) -> String {
+    match source {
+        Source::Literal(s) => bytes(s),
+        Source::Var(name) => format!("ctx.s_{}.as_bytes()", name),
+    }
+}
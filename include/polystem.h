@@ -0,0 +1,63 @@
+/*
+ * Copyright 2019 The Polystem authors.
+ *
+ * For the full copyright and license information, please view the LICENSE file
+ * that was distributed with this source code.
+ */
+
+/*
+ * The C interface of polystem, built with the `capi` feature:
+ *
+ *     cargo rustc --release --lib --features capi --crate-type cdylib
+ *
+ * Keep in step with src/capi.rs, which tests that every declaration is here.
+ */
+
+#ifndef POLYSTEM_H
+#define POLYSTEM_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/* The ids of the stemming algorithms. */
+#define POLYSTEM_S 0
+#define POLYSTEM_PORTER 1
+
+/* The errors returned by the stemming functions. */
+
+/* The algorithm id is unknown, or its stemmer is not compiled in. */
+#define POLYSTEM_ERROR_ALGORITHM -1
+/* The word is not valid UTF-8. */
+#define POLYSTEM_ERROR_UTF8 -2
+/* A pointer argument is null. */
+#define POLYSTEM_ERROR_NULL -3
+
+/*
+ * Stem the NUL-terminated `word` with `algorithm` into `out`, which holds
+ * `len` bytes.
+ *
+ * Return the length of the stem, not counting the NUL byte, or one of the
+ * negative POLYSTEM_ERROR_* codes. As with snprintf, the stem is truncated to
+ * fit `out` and is always NUL-terminated when `len` is not zero, so a return
+ * value of `len` or more means that `out` was too small. A stem is never
+ * longer than its word.
+ */
+intptr_t polystem_stem(uint32_t algorithm, const char *word, char *out,
+                       size_t len);
+
+/*
+ * Stem the `word_len` bytes of `word`, which need not be NUL-terminated, into
+ * `out`, see polystem_stem.
+ */
+intptr_t polystem_stem_n(uint32_t algorithm, const char *word,
+                         size_t word_len, char *out, size_t len);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* POLYSTEM_H */
@@ -0,0 +1,75 @@
+// Copyright 2019 The Polystem authors.
+//
+// For the full copyright and license information, please view the LICENSE file
+// that was distributed with this source code.
+
+//! # Polystem macros
+//!
+//! Compile time stemming with the algorithms from `polystem`.
+
+extern crate proc_macro;
+
+use proc_macro::{Literal, TokenStream, TokenTree};
+
+use polystem::Algorithm;
+
+/// Stem a string literal at compile time.
+///
+/// The first argument is the word and the second is the name of the
+/// algorithm, as accepted by `Algorithm::from_str`. The macro expands to the
+/// stem as a string literal, so it can be used in `const` items and `match`
+/// arms.
+///
+/// # Examples
+///
+/// ```
+/// use polystem_macros::stem;
+///
+/// const CONNECT: &str = stem!("connected", porter);
+///
+/// match CONNECT {
+///     stem!("connecting", porter) => (),
+///     _ => panic!("not conflated"),
+/// }
+/// ```
+#[proc_macro]
+pub fn stem(input: TokenStream) -> TokenStream {
+    let (word, algorithm) = match parse(input) {
+        Ok(args) => args,
+        Err(msg) => return compile_error(msg),
+    };
+
+    let stem = algorithm.stem(&word);
+    TokenTree::from(Literal::string(&stem)).into()
+}
+
+// Parse the arguments `"word", algorithm`.
+fn parse(input: TokenStream) -> Result<(String, Algorithm), String> {
+    let usage = "expected `stem!(\"word\", algorithm)`";
+    let tokens: Vec<TokenTree> = input.into_iter().collect();
+
+    let (word, algorithm) = match tokens.as_slice() {
+        [TokenTree::Literal(w), TokenTree::Punct(p), TokenTree::Ident(a)]
+            if p.as_char() == ',' =>
+        {
+            (w.to_string(), a.to_string())
+        }
+        _ => return Err(usage.to_owned()),
+    };
+
+    if word.len() < 2 || !word.starts_with('"') || !word.ends_with('"') {
+        return Err(usage.to_owned());
+    }
+    let word = &word[1..word.len() - 1];
+    if word.contains('\\') {
+        return Err("escapes are not supported in the word".to_owned());
+    }
+
+    let algorithm = algorithm.parse().map_err(|e| format!("{}", e))?;
+    Ok((word.to_owned(), algorithm))
+}
+
+// Expand to a `compile_error!` invocation reporting `msg`.
+fn compile_error(msg: String) -> TokenStream {
+    format!("compile_error!({:?})", msg).parse().unwrap()
+}
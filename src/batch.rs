@@ -0,0 +1,209 @@
+// Copyright 2019 The Polystem authors.
+//
+// For the full copyright and license information, please view the LICENSE file
+// that was distributed with this source code.
+
+use std::fmt;
+use std::iter::FusedIterator;
+use std::ops::Index;
+
+/// The stems of a batch of words, stored one after the other in a single
+/// `String`, see [`Stemmer::stem_all_into`](crate::Stemmer::stem_all_into).
+///
+/// Stemming a document into `Stems` makes two allocations however many
+/// words it has, and none at all when the `Stems` is cleared and reused.
+///
+/// # Examples
+///
+/// ```
+/// use polystem::{Porter, Stemmer, Stems};
+///
+/// let mut stems = Stems::new();
+/// Porter::stem_all_into(&["rusted", "flies"], &mut stems);
+///
+/// assert_eq!("rust", &stems[0]);
+/// assert_eq!(vec!["rust", "fli"], stems.iter().collect::<Vec<_>>());
+/// ```
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct Stems {
+    text: String,
+    // The end of each stem in `text`.
+    ends: Vec<usize>,
+}
+
+impl Stems {
+    /// Construct an empty `Stems`.
+    pub fn new() -> Stems {
+        Stems::default()
+    }
+
+    /// Construct an empty `Stems` that holds `words` stems of `bytes` bytes
+    /// in total without reallocating.
+    pub fn with_capacity(words: usize, bytes: usize) -> Stems {
+        Stems {
+            text: String::with_capacity(bytes),
+            ends: Vec::with_capacity(words),
+        }
+    }
+
+    /// Return the number of stems.
+    pub fn len(&self) -> usize {
+        self.ends.len()
+    }
+
+    /// Return `true` if there are no stems.
+    pub fn is_empty(&self) -> bool {
+        self.ends.is_empty()
+    }
+
+    /// Return the stem at `index`, or `None` when it is out of bounds.
+    pub fn get(&self, index: usize) -> Option<&str> {
+        let end = *self.ends.get(index)?;
+        Some(&self.text[self.start(index)..end])
+    }
+
+    /// Return an iterator over the stems, in order.
+    pub fn iter(&self) -> StemsIter<'_> {
+        StemsIter {
+            stems: self,
+            front: 0,
+            back: self.len(),
+        }
+    }
+
+    /// Append `stem`.
+    pub fn push(&mut self, stem: &str) {
+        self.text.push_str(stem);
+        self.ends.push(self.text.len());
+    }
+
+    /// Remove every stem, keeping the allocated memory for reuse.
+    pub fn clear(&mut self) {
+        self.text.clear();
+        self.ends.clear();
+    }
+
+    // Return the start of the stem at `index` in `text`.
+    fn start(&self, index: usize) -> usize {
+        match index {
+            0 => 0,
+            i => self.ends[i - 1],
+        }
+    }
+}
+
+impl Index<usize> for Stems {
+    type Output = str;
+
+    fn index(&self, index: usize) -> &str {
+        match self.get(index) {
+            Some(stem) => stem,
+            None => panic!(
+                "index {} out of bounds for {} stems",
+                index,
+                self.len()
+            ),
+        }
+    }
+}
+
+impl fmt::Debug for Stems {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<'a> IntoIterator for &'a Stems {
+    type Item = &'a str;
+    type IntoIter = StemsIter<'a>;
+
+    fn into_iter(self) -> StemsIter<'a> {
+        self.iter()
+    }
+}
+
+/// An iterator over the stems of [`Stems`], see [`Stems::iter`].
+#[derive(Clone, Debug)]
+pub struct StemsIter<'a> {
+    stems: &'a Stems,
+    front: usize,
+    back: usize,
+}
+
+impl<'a> Iterator for StemsIter<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        if self.front == self.back {
+            return None;
+        }
+        self.front += 1;
+        self.stems.get(self.front - 1)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+}
+
+impl<'a> DoubleEndedIterator for StemsIter<'a> {
+    fn next_back(&mut self) -> Option<&'a str> {
+        if self.front == self.back {
+            return None;
+        }
+        self.back -= 1;
+        self.stems.get(self.back)
+    }
+}
+
+impl ExactSizeIterator for StemsIter<'_> {}
+
+impl FusedIterator for StemsIter<'_> {}
+
+#[cfg(all(test, feature = "s"))]
+mod tests {
+    use super::*;
+    use crate::{Stemmer, S};
+
+    #[test]
+    fn test_stems() {
+        let mut stems = Stems::new();
+        assert!(stems.is_empty());
+        assert_eq!(stems.get(0), None);
+
+        stems.push("fly");
+        stems.push("");
+        stems.push("sun");
+        assert_eq!(stems.len(), 3);
+        assert_eq!(&stems[0], "fly");
+        assert_eq!(&stems[1], "");
+        assert_eq!(stems.get(2), Some("sun"));
+        assert_eq!(stems.get(3), None);
+        assert_eq!(stems.iter().rev().collect::<Vec<_>>(), ["sun", "", "fly"]);
+        assert_eq!(format!("{:?}", stems), r#"["fly", "", "sun"]"#);
+
+        stems.clear();
+        assert!(stems.is_empty());
+        assert_eq!(stems.iter().next(), None);
+    }
+
+    #[test]
+    fn test_stem_all_into() {
+        let words = ["flies", "blesses", "", "suns"];
+        let mut stems = Stems::with_capacity(2, 4);
+        S::stem_all_into(&words, &mut stems);
+        S::stem_all_into(&["cats"], &mut stems);
+
+        let expected = ["fly", "bless", "", "sun", "cat"];
+        assert_eq!(stems.iter().collect::<Vec<_>>(), expected);
+    }
+
+    #[test]
+    #[should_panic(expected = "index 1 out of bounds for 1 stems")]
+    fn test_stems_index_out_of_bounds() {
+        let mut stems = Stems::new();
+        stems.push("fly");
+        let _ = &stems[1];
+    }
+}
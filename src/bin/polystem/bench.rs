@@ -0,0 +1,135 @@
+// Copyright 2019 The Polystem authors.
+//
+// For the full copyright and license information, please view the LICENSE file
+// that was distributed with this source code.
+
+// The bench command, which times the stemmers on the words of a corpus.
+
+use std::collections::HashSet;
+use std::fmt;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use polystem::{by_name, Cached, DynStemmer};
+
+// The timings of one stemmer, without and with a cache.
+struct Row {
+    name: String,
+    plain: Duration,
+    cached: Duration,
+    hit_rate: f64,
+}
+
+// The timings of the stemmers on a corpus.
+pub struct Report {
+    words: usize,
+    unique: usize,
+    capacity: usize,
+    rows: Vec<Row>,
+}
+
+// Return the time taken to stem `words` with `stemmer`.
+fn time(stemmer: &dyn DynStemmer, words: &[&str]) -> Duration {
+    let start = Instant::now();
+    for word in words {
+        black_box(stemmer.stem_word(black_box(word)));
+    }
+    start.elapsed()
+}
+
+// Time each of the stemmers named `names`, which `parse` has checked, on
+// `words`, alone and behind a cache of `capacity` words.
+pub fn bench(names: &[String], capacity: usize, words: &[&str]) -> Report {
+    let unique = words.iter().collect::<HashSet<_>>().len();
+    let rows = names
+        .iter()
+        .map(|name| {
+            let plain = time(&*by_name(name).expect("a known stemmer"), words);
+            let stemmer = by_name(name).expect("a known stemmer");
+            let stemmer = Cached::new(stemmer, capacity);
+            let cached = time(&stemmer, words);
+            Row {
+                name: name.clone(),
+                plain,
+                cached,
+                hit_rate: stemmer.stats().hit_rate(),
+            }
+        })
+        .collect();
+
+    Report {
+        words: words.len(),
+        unique,
+        capacity,
+        rows,
+    }
+}
+
+// Return the number of words stemmed per second.
+fn rate(words: usize, time: Duration) -> f64 {
+    words as f64 / time.as_secs_f64().max(1e-9)
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(
+            f,
+            "{} words, {} unique, cache of {} words",
+            self.words, self.unique, self.capacity
+        )?;
+        let width = self
+            .rows
+            .iter()
+            .map(|row| row.name.len())
+            .chain(Some("NAME".len()))
+            .max()
+            .unwrap_or(0);
+        writeln!(
+            f,
+            "{:<w$}  {:>10}  {:>12}  {:>12}  {:>8}",
+            "NAME",
+            "SECONDS",
+            "WORDS/S",
+            "CACHED W/S",
+            "HIT RATE",
+            w = width
+        )?;
+        for row in &self.rows {
+            writeln!(
+                f,
+                "{:<w$}  {:>10.3}  {:>12.0}  {:>12.0}  {:>7.1}%",
+                row.name,
+                row.plain.as_secs_f64(),
+                rate(self.words, row.plain),
+                rate(self.words, row.cached),
+                row.hit_rate * 100.0,
+                w = width
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bench() {
+        let words = ["cats", "ponies", "cats", "rusted", "cats", "ponies"];
+        let names = vec!["s".to_owned(), "porter".to_owned()];
+        let report = bench(&names, 2, &words);
+        assert_eq!(report.words, 6);
+        assert_eq!(report.unique, 3);
+        assert_eq!(report.rows.len(), 2);
+        // `rusted` evicts `ponies`, so only two of the repeats hit.
+        assert!((report.rows[0].hit_rate - 2.0 / 6.0).abs() < 1e-9);
+
+        let text = report.to_string();
+        let lines: Vec<_> = text.lines().collect();
+        assert_eq!(lines[0], "6 words, 3 unique, cache of 2 words");
+        assert!(lines[1].starts_with("NAME    "));
+        assert!(lines[2].starts_with("s       "));
+        assert!(lines[3].starts_with("porter  "));
+    }
+}
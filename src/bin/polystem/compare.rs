@@ -0,0 +1,103 @@
+// Copyright 2019 The Polystem authors.
+//
+// For the full copyright and license information, please view the LICENSE file
+// that was distributed with this source code.
+
+// The compare command, which reports how two stemmers agree on the words of
+// a corpus.
+
+use std::fmt;
+
+use polystem::by_name;
+use polystem::eval::{agreement, Agreement};
+
+// The agreement of two stemmers, by name.
+pub struct Report {
+    names: [String; 2],
+    agreement: Agreement,
+}
+
+// Compare the stemmers named `a` and `b`, which `parse` has checked, on
+// `words`.
+pub fn compare(a: &str, b: &str, words: &[&str]) -> Report {
+    let stemmers = [a, b].map(|name| by_name(name).expect("a known stemmer"));
+    Report {
+        names: [a.to_owned(), b.to_owned()],
+        agreement: agreement(&*stemmers[0], &*stemmers[1], words),
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let agreement = &self.agreement;
+        writeln!(
+            f,
+            "{} unique words, {} stemmed differently, {:.1}% agreement",
+            agreement.words,
+            agreement.disagreements.len(),
+            agreement.rate() * 100.0
+        )?;
+        let width = self
+            .names
+            .iter()
+            .map(String::len)
+            .chain(Some("NAME".len()))
+            .max()
+            .unwrap_or(0);
+        writeln!(
+            f,
+            "{:<w$}  {:>8}  {:>13}",
+            "NAME",
+            "CLASSES",
+            "ONLY MERGES",
+            w = width
+        )?;
+        let rows = [
+            (agreement.a_classes, agreement.a_only_merges),
+            (agreement.b_classes, agreement.b_only_merges),
+        ];
+        for (name, (classes, merges)) in self.names.iter().zip(rows) {
+            writeln!(
+                f,
+                "{:<w$}  {:>8}  {:>13}",
+                name,
+                classes,
+                merges,
+                w = width
+            )?;
+        }
+        if agreement.disagreements.is_empty() {
+            return Ok(());
+        }
+        writeln!(f, "\nWORD\t{}\t{}", self.names[0], self.names[1])?;
+        for d in &agreement.disagreements {
+            writeln!(f, "{}\t{}\t{}", d.word, d.a, d.b)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compare() {
+        let words = ["cats", "flies", "rusted", "rust", "cats"];
+        let report = compare("s", "porter", &words);
+        assert_eq!(
+            report.to_string(),
+            "4 unique words, 2 stemmed differently, 50.0% agreement\n\
+             NAME     CLASSES    ONLY MERGES\n\
+             s              4              0\n\
+             porter         3              1\n\
+             \n\
+             WORD\ts\tporter\n\
+             flies\tfly\tfli\n\
+             rusted\trusted\trust\n"
+        );
+
+        let report = compare("s", "s", &words);
+        assert_eq!(report.to_string().lines().count(), 4);
+    }
+}
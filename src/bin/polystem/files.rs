@@ -0,0 +1,201 @@
+// Copyright 2019 The Polystem authors.
+//
+// For the full copyright and license information, please view the LICENSE file
+// that was distributed with this source code.
+
+// The input and output files of the program. With the `compression` feature,
+// files named `*.gz` and `*.zst` are decompressed as they are read and
+// compressed as they are written.
+
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Read, Write};
+use std::path::{Path, PathBuf};
+
+#[cfg(feature = "compression")]
+use flate2::{read::MultiGzDecoder, write::GzEncoder, Compression};
+
+// Return `e` with `path` in its message.
+fn annotate(path: &str, e: io::Error) -> io::Error {
+    io::Error::new(e.kind(), format!("{}: {}", path, e))
+}
+
+// A reader of the file at `path` that adds the path to its errors, such as
+// those of a corrupt compressed file.
+struct Input<R> {
+    path: String,
+    inner: R,
+}
+
+impl<R: Read> Read for Input<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.inner.read(buf).map_err(|e| annotate(&self.path, e))
+    }
+}
+
+// Open the file at `path` for reading.
+fn open(path: &str) -> io::Result<Box<dyn Read>> {
+    let file = File::open(path).map_err(|e| annotate(path, e))?;
+    let inner: Box<dyn Read> = match path {
+        #[cfg(feature = "compression")]
+        p if p.ends_with(".gz") => Box::new(MultiGzDecoder::new(file)),
+        #[cfg(feature = "compression")]
+        p if p.ends_with(".zst") => {
+            Box::new(zstd::Decoder::new(file).map_err(|e| annotate(path, e))?)
+        }
+        _ => Box::new(file),
+    };
+    Ok(Box::new(Input {
+        path: path.to_owned(),
+        inner,
+    }))
+}
+
+// Call `f` with each of `files` in turn, where `-` is standard input, or
+// with standard input when there are no files.
+pub fn each_input<F>(files: &[String], mut f: F) -> io::Result<()>
+where
+    F: FnMut(Box<dyn Read>) -> io::Result<()>,
+{
+    if files.is_empty() {
+        return f(Box::new(io::stdin().lock()));
+    }
+    for path in files {
+        if path == "-" {
+            f(Box::new(io::stdin().lock()))?;
+            continue;
+        }
+        f(open(path)?)?;
+    }
+    Ok(())
+}
+
+// Return the paths of the files below the directory `root`, relative to it
+// and in order, skipping hidden files and directories, whose names start
+// with a period, and symbolic links to directories.
+pub fn walk(root: &Path) -> io::Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    let mut dirs = vec![PathBuf::new()];
+    while let Some(dir) = dirs.pop() {
+        let path = root.join(&dir);
+        let entries = fs::read_dir(&path)
+            .map_err(|e| annotate(&path.to_string_lossy(), e))?;
+        for entry in entries {
+            let entry = entry?;
+            if entry.file_name().to_string_lossy().starts_with('.') {
+                continue;
+            }
+            let relative = dir.join(entry.file_name());
+            let kind = entry.file_type()?;
+            if kind.is_dir() {
+                dirs.push(relative);
+            } else if !kind.is_symlink() || !entry.path().is_dir() {
+                files.push(relative);
+            }
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+// The destination of the output, which is finished with `finish` so that
+// the errors of the last write are reported.
+pub enum Output {
+    Plain(BufWriter<Box<dyn Write>>),
+    #[cfg(feature = "compression")]
+    Gzip(GzEncoder<BufWriter<Box<dyn Write>>>),
+    #[cfg(feature = "compression")]
+    Zstd(zstd::Encoder<'static, BufWriter<Box<dyn Write>>>),
+}
+
+impl Output {
+    // Create the file at `path`, or use standard output when there is no
+    // path or it is `-`.
+    pub fn create(path: Option<&str>) -> io::Result<Output> {
+        let path = match path {
+            Some(path) if path != "-" => path,
+            _ => {
+                let stdout: Box<dyn Write> = Box::new(io::stdout().lock());
+                return Ok(Output::Plain(BufWriter::new(stdout)));
+            }
+        };
+        let file: Box<dyn Write> =
+            Box::new(File::create(path).map_err(|e| annotate(path, e))?);
+        let file = BufWriter::new(file);
+        Ok(match path {
+            #[cfg(feature = "compression")]
+            p if p.ends_with(".gz") => {
+                Output::Gzip(GzEncoder::new(file, Compression::default()))
+            }
+            #[cfg(feature = "compression")]
+            p if p.ends_with(".zst") => Output::Zstd(
+                zstd::Encoder::new(file, 0).map_err(|e| annotate(path, e))?,
+            ),
+            _ => Output::Plain(file),
+        })
+    }
+
+    // Write the end of the compressed stream, if any, and flush.
+    pub fn finish(self) -> io::Result<()> {
+        match self {
+            Output::Plain(mut w) => w.flush(),
+            #[cfg(feature = "compression")]
+            Output::Gzip(w) => w.finish()?.flush(),
+            #[cfg(feature = "compression")]
+            Output::Zstd(w) => w.finish()?.flush(),
+        }
+    }
+}
+
+impl Write for Output {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        match self {
+            Output::Plain(w) => w.write(buf),
+            #[cfg(feature = "compression")]
+            Output::Gzip(w) => w.write(buf),
+            #[cfg(feature = "compression")]
+            Output::Zstd(w) => w.write(buf),
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match self {
+            Output::Plain(w) => w.flush(),
+            #[cfg(feature = "compression")]
+            Output::Gzip(w) => w.flush(),
+            #[cfg(feature = "compression")]
+            Output::Zstd(w) => w.flush(),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "compression"))]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_compressed_round_trip() {
+        let dir = std::env::temp_dir().join("polystem-compression-test");
+        fs::create_dir_all(&dir).unwrap();
+        for name in &["words.gz", "words.zst", "words.txt"] {
+            let path = dir.join(name);
+            let path = path.to_str().unwrap();
+            let mut output = Output::create(Some(path)).unwrap();
+            output.write_all(b"ponies rusted\n").unwrap();
+            output.finish().unwrap();
+
+            let mut text = String::new();
+            open(path).unwrap().read_to_string(&mut text).unwrap();
+            assert_eq!(text, "ponies rusted\n");
+        }
+
+        let raw = fs::read(dir.join("words.gz")).unwrap();
+        assert_ne!(raw, b"ponies rusted\n");
+        let path = dir.join("bad.zst");
+        fs::write(&path, b"not zstd").unwrap();
+        let path = path.to_str().unwrap();
+        let err = open(path).and_then(|mut r| r.read(&mut [0; 8]));
+        assert!(err.unwrap_err().to_string().starts_with(path));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,978 @@
+// Copyright 2019 The Polystem authors.
+//
+// For the full copyright and license information, please view the LICENSE file
+// that was distributed with this source code.
+
+// Stem the words of text read from files or standard input, and write the
+// text to standard output with every word replaced by its stem.
+
+use std::collections::HashMap;
+use std::env;
+use std::fs;
+use std::io::{self, BufReader, ErrorKind, IsTerminal, Read, Write};
+use std::path::{Path, PathBuf};
+use std::process;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::thread;
+
+use polystem::{
+    by_name, stemmer_names, Algorithm, ConflationMap, Language, Streaming,
+};
+use serde_json::{Map, Value};
+
+use files::{each_input, Output};
+
+mod bench;
+mod compare;
+mod files;
+mod records;
+mod repl;
+
+const USAGE: &str = "\
+Usage: polystem [OPTIONS] [FILE]...
+       polystem [OPTIONS] --recursive --output DIR PATH...
+       polystem list
+       polystem describe NAME
+       polystem repl [--stemmer NAME] [--trace]
+       polystem bench [--stemmer NAME] [--cache N] [FILE]...
+       polystem conflate [--stemmer NAME] [--output FILE] [PATH]...
+       polystem compare NAME NAME [FILE]...
+
+Stem the words of each FILE, or of standard input when there is no FILE or
+FILE is -, and write the text to standard output with every word replaced by
+its stem. Words are runs of bytes between ASCII whitespace, and the
+whitespace is copied as is.
+
+With --format jsonl, each line is a JSON object whose string --field is
+stemmed in the same way. Records without the field are copied as is. With
+--format csv or tsv, only the --column of each record is stemmed, and the
+other columns and the quoting are copied as is.
+
+With --recursive, each PATH is a file or a directory, and every file below
+a directory is stemmed to the same path below the --output directory, which
+is created as needed. Files are stemmed --jobs at a time.
+
+When built with compression support, files named *.gz and *.zst, including
+the --output file, are decompressed and compressed as they are read and
+written.
+
+Commands:
+  list                list the stemming algorithms of the program
+  describe NAME       print the details of the stemming algorithm NAME
+  repl                stem the words of each line typed, and with --trace
+                      print the Porter rules that fired for each word
+  bench               time the stemmers, or the --stemmer, on the words of
+                      each FILE, alone and behind a cache of --cache words
+                      (default: 65536)
+  conflate            write a JSON object mapping each stem of the words of
+                      each PATH, a file or a directory read recursively, to
+                      the lowercased forms stemmed to it and their counts
+  compare             report how the two stemmers NAME agree on the words of
+                      each FILE: the share stemmed alike, their conflation
+                      classes, and the words stemmed differently
+
+Options:
+  -o, --output FILE   write to FILE instead of standard output
+  -r, --recursive     stem the files below each directory PATH into the
+                      --output directory
+  -j, --jobs N        with --recursive, the number of files stemmed at once
+                      (default: the number of CPUs)
+  -s, --stemmer NAME  the stemming algorithm (default: the one for --lang)
+  -l, --lang CODE     the language of the text (default: en)
+  -f, --format NAME   the format of the input: text, jsonl, csv or tsv
+                      (default: text)
+      --field NAME    the field of JSON Lines records to stem (default: text)
+      --stems         keep the field and add its stems as a `stems` array
+  -c, --column N      the column of csv or tsv records to stem (default: 1)
+      --header        copy the first csv or tsv record unchanged
+  -h, --help          print this help and exit
+  -V, --version       print the version and exit";
+
+// The number of bytes read at a time.
+const CAPACITY: usize = 64 * 1024;
+
+// The number of words cached by the bench command unless told otherwise.
+const CACHE_WORDS: usize = 65536;
+
+// The format of the input and output.
+#[derive(Debug, PartialEq)]
+enum Format {
+    // Text, with every word replaced by its stem.
+    Text,
+    // JSON Lines, with the string `field` of each record stemmed, or with
+    // its stems added to the record as a `stems` array when `stems` is set.
+    Jsonl {
+        field: String,
+        stems: bool,
+    },
+    // Delimited records, with the column `column`, counted from zero,
+    // stemmed, and the first record copied unchanged when `header` is set.
+    Delimited {
+        delimiter: u8,
+        column: usize,
+        header: bool,
+    },
+}
+
+// What the command line asks for.
+#[derive(Debug, PartialEq)]
+enum Command {
+    Stem {
+        stemmer: String,
+        format: Format,
+        files: Vec<String>,
+        output: Option<String>,
+    },
+    StemTree {
+        stemmer: String,
+        format: Format,
+        paths: Vec<String>,
+        output: String,
+        jobs: Option<usize>,
+    },
+    List,
+    Describe(Algorithm),
+    Repl {
+        stemmer: String,
+        trace: bool,
+    },
+    Bench {
+        stemmers: Vec<String>,
+        cache: usize,
+        files: Vec<String>,
+    },
+    Conflate {
+        stemmer: String,
+        files: Vec<String>,
+        output: Option<String>,
+    },
+    Compare {
+        stemmers: [String; 2],
+        files: Vec<String>,
+    },
+    Help,
+    Version,
+}
+
+fn main() {
+    let command = match parse(env::args().skip(1)) {
+        Ok(command) => command,
+        Err(message) => {
+            eprintln!("polystem: {}\n\n{}", message, USAGE);
+            process::exit(2);
+        }
+    };
+
+    let result = match command {
+        Command::List => return print!("{}", list()),
+        Command::Describe(algorithm) => {
+            return print!("{}", describe(algorithm))
+        }
+        Command::Help => return println!("{}", USAGE),
+        Command::Version => {
+            return println!("polystem {}", env!("CARGO_PKG_VERSION"))
+        }
+        Command::Repl { stemmer, trace } => {
+            let stemmer = by_name(&stemmer).expect("a known stemmer");
+            let stdin = io::stdin();
+            let prompt = stdin.is_terminal();
+            let mut stdout = io::stdout().lock();
+            repl::repl(&*stemmer, trace, prompt, stdin.lock(), &mut stdout)
+        }
+        Command::Bench {
+            stemmers,
+            cache,
+            files,
+        } => run_bench(&stemmers, cache, &files),
+        Command::Conflate {
+            stemmer,
+            files,
+            output,
+        } => conflate(&stemmer, &files, output.as_deref()),
+        Command::Compare { stemmers, files } => run_compare(&stemmers, &files),
+        Command::Stem {
+            stemmer,
+            format,
+            files,
+            output,
+        } => stem(&stemmer, &format, &files, output.as_deref()),
+        Command::StemTree {
+            stemmer,
+            format,
+            paths,
+            output,
+            jobs,
+        } => stem_tree(&stemmer, &format, &paths, &output, jobs),
+    };
+
+    match result {
+        Ok(()) => {}
+        // The reader of the output has gone, as with `polystem | head`.
+        Err(e) if e.kind() == ErrorKind::BrokenPipe => {}
+        Err(e) => {
+            eprintln!("polystem: {}", e);
+            process::exit(1);
+        }
+    }
+}
+
+// Parse the arguments of the command line, without the program name.
+fn parse<I: IntoIterator<Item = String>>(args: I) -> Result<Command, String> {
+    let mut stemmer = None;
+    let mut lang = None;
+    let mut format = None;
+    let mut field = None;
+    let mut stems = false;
+    let mut column = None;
+    let mut header = false;
+    let mut output = None;
+    let mut trace = false;
+    let mut cache = None;
+    let mut recursive = false;
+    let mut jobs = None;
+    let mut files = Vec::new();
+
+    let mut args = args.into_iter().peekable();
+    let subcommand = args.next_if(|arg| {
+        ["repl", "bench", "conflate", "compare"].contains(&arg.as_str())
+    });
+    let command = args
+        .peek()
+        .filter(|arg| *arg == "list" || *arg == "describe")
+        .cloned();
+    if let Some(command) = command {
+        let operands: Vec<_> = args.skip(1).collect();
+        return match (command.as_str(), operands.as_slice()) {
+            (_, [flag]) if flag == "-h" || flag == "--help" => {
+                Ok(Command::Help)
+            }
+            ("list", []) => Ok(Command::List),
+            ("list", _) => Err("list takes no arguments".to_owned()),
+            ("describe", [name]) => {
+                name.parse().map(Command::Describe).map_err(|e| {
+                    let names: Vec<_> =
+                        Algorithm::ALL.iter().map(|a| a.name()).collect();
+                    format!("{}, expected one of: {}", e, names.join(", "))
+                })
+            }
+            _ => Err("describe needs the NAME of an algorithm".to_owned()),
+        };
+    }
+
+    while let Some(arg) = args.next() {
+        let (flag, inline) = match arg.split_once('=') {
+            Some((flag, value)) if flag.starts_with("--") => {
+                (flag.to_owned(), Some(value.to_owned()))
+            }
+            _ => (arg.clone(), None),
+        };
+        let mut value = || {
+            inline
+                .clone()
+                .or_else(|| args.next())
+                .ok_or_else(|| format!("{} needs a value", flag))
+        };
+
+        match flag.as_str() {
+            "-h" | "--help" => return Ok(Command::Help),
+            "-V" | "--version" => return Ok(Command::Version),
+            "-o" | "--output" => output = Some(value()?),
+            "-s" | "--stemmer" => stemmer = Some(value()?),
+            "-l" | "--lang" => lang = Some(value()?),
+            "-f" | "--format" => format = Some(value()?),
+            "--field" => field = Some(value()?),
+            "--stems" => stems = true,
+            "-c" | "--column" => column = Some(value()?),
+            "--header" => header = true,
+            "--trace" => trace = true,
+            "--cache" => cache = Some(value()?),
+            "-r" | "--recursive" => recursive = true,
+            "-j" | "--jobs" => jobs = Some(value()?),
+            "--" => {
+                files.extend(args);
+                break;
+            }
+            f if f.starts_with('-') && f != "-" => {
+                return Err(format!("unknown option `{}`", f));
+            }
+            _ => files.push(arg),
+        }
+    }
+
+    let lang = match lang {
+        Some(code) => language(&code)?,
+        None => Language::English,
+    };
+    let chosen = stemmer.is_some();
+    let stemmer = match stemmer {
+        Some(name) => check_stemmer(name)?,
+        None => lang.algorithm().name().to_owned(),
+    };
+    if (recursive || jobs.is_some()) && subcommand.is_some() {
+        return Err("--recursive and --jobs need no command".to_owned());
+    }
+    if trace && subcommand.as_deref() != Some("repl") {
+        return Err("--trace needs the repl command".to_owned());
+    }
+    if subcommand.as_deref() == Some("bench") {
+        let cache = match cache.map(|n| n.parse::<usize>()) {
+            Some(Ok(n)) => n,
+            Some(Err(_)) => return Err("--cache needs a number".to_owned()),
+            None => CACHE_WORDS,
+        };
+        let stemmers = if chosen {
+            vec![stemmer]
+        } else {
+            Algorithm::ALL.iter().map(|a| a.name().to_owned()).collect()
+        };
+        return Ok(Command::Bench {
+            stemmers,
+            cache,
+            files,
+        });
+    }
+    if cache.is_some() {
+        return Err("--cache needs the bench command".to_owned());
+    }
+    if subcommand.as_deref() == Some("compare") {
+        if chosen || files.len() < 2 {
+            return Err("compare needs the NAMEs of two stemmers".to_owned());
+        }
+        let rest = files.split_off(2);
+        let mut names = files.into_iter().map(check_stemmer);
+        let a = names.next().expect("two names")?;
+        let b = names.next().expect("two names")?;
+        return Ok(Command::Compare {
+            stemmers: [a, b],
+            files: rest,
+        });
+    }
+    if subcommand.as_deref() == Some("conflate") {
+        return Ok(Command::Conflate {
+            stemmer,
+            files,
+            output,
+        });
+    }
+    if subcommand.is_some() {
+        if !files.is_empty() {
+            return Err("repl reads standard input, not files".to_owned());
+        }
+        if trace && stemmer != "porter" {
+            return Err("--trace needs the porter stemmer".to_owned());
+        }
+        return Ok(Command::Repl { stemmer, trace });
+    }
+    let format = format.as_deref().unwrap_or("text");
+    if (field.is_some() || stems) && format != "jsonl" {
+        return Err("--field and --stems need --format jsonl".to_owned());
+    }
+    if (column.is_some() || header) && format != "csv" && format != "tsv" {
+        return Err(
+            "--column and --header need --format csv or tsv".to_owned()
+        );
+    }
+    let column = match column {
+        Some(n) => match n.parse::<usize>() {
+            Ok(n) if n > 0 => n - 1,
+            _ => return Err(format!("invalid column `{}`", n)),
+        },
+        None => 0,
+    };
+    let format = match format {
+        "text" => Format::Text,
+        "jsonl" => Format::Jsonl {
+            field: field.unwrap_or_else(|| "text".to_owned()),
+            stems,
+        },
+        "csv" | "tsv" => Format::Delimited {
+            delimiter: if format == "csv" { b',' } else { b'\t' },
+            column,
+            header,
+        },
+        name => {
+            return Err(format!(
+                "unknown format `{}`, expected one of: text, jsonl, csv, \
+                 tsv",
+                name
+            ));
+        }
+    };
+
+    if !recursive {
+        if jobs.is_some() {
+            return Err("--jobs needs --recursive".to_owned());
+        }
+        return Ok(Command::Stem {
+            stemmer,
+            format,
+            files,
+            output,
+        });
+    }
+    let output = match output {
+        Some(output) if output != "-" => output,
+        _ => return Err("--recursive needs an --output DIR".to_owned()),
+    };
+    if files.is_empty() || files.iter().any(|path| path == "-") {
+        return Err("--recursive reads files, not standard input".to_owned());
+    }
+    let jobs = match jobs.map(|n| (n.parse::<usize>(), n)) {
+        Some((Ok(n), _)) if n > 0 => Some(n),
+        Some((_, n)) => return Err(format!("invalid number of jobs `{}`", n)),
+        None => None,
+    };
+    Ok(Command::StemTree {
+        stemmer,
+        format,
+        paths: files,
+        output,
+        jobs,
+    })
+}
+
+// Return `name` if it names a stemmer, or else the message of an error.
+fn check_stemmer(name: String) -> Result<String, String> {
+    match by_name(&name) {
+        Ok(_) => Ok(name),
+        Err(e) => Err(format!(
+            "{}, expected one of: {}",
+            e,
+            stemmer_names().join(", ")
+        )),
+    }
+}
+
+// Return a table of the algorithms compiled into the program.
+fn list() -> String {
+    let width = Algorithm::ALL
+        .iter()
+        .map(|a| a.name().len())
+        .chain(Some("NAME".len()))
+        .max()
+        .unwrap_or(0);
+    let mut out = format!(
+        "{:<w$}  {:<8}  {:<14}  VERSION\n",
+        "NAME",
+        "LANGUAGE",
+        "AGGRESSIVENESS",
+        w = width
+    );
+    for algorithm in Algorithm::ALL {
+        let info = algorithm.info();
+        out += &format!(
+            "{:<w$}  {:<8}  {:<14}  {}\n",
+            info.name,
+            info.language.name(),
+            info.aggressiveness,
+            info.algorithm_version,
+            w = width
+        );
+    }
+    out
+}
+
+// Return the details of `algorithm`.
+fn describe(algorithm: Algorithm) -> String {
+    let info = algorithm.info();
+    format!(
+        "name: {}\nlanguage: {}\naggressiveness: {}\nversion: {}\n",
+        info.name,
+        info.language.name(),
+        info.aggressiveness,
+        info.algorithm_version
+    )
+}
+
+// Time the stemmers named `stemmers` on the words of `files`, or of
+// standard input when there are none, and print the report.
+fn run_bench(
+    stemmers: &[String],
+    cache: usize,
+    files: &[String],
+) -> io::Result<()> {
+    let mut text = Vec::new();
+    each_input(files, |mut input| input.read_to_end(&mut text).map(drop))?;
+    let text = String::from_utf8_lossy(&text);
+    let words: Vec<_> = text.split_ascii_whitespace().collect();
+    print!("{}", bench::bench(stemmers, cache, &words));
+    Ok(())
+}
+
+// Compare the stemmers named `stemmers` on the words of `files`, or of
+// standard input when there are none, and print the report.
+fn run_compare(stemmers: &[String; 2], files: &[String]) -> io::Result<()> {
+    let mut text = Vec::new();
+    each_input(files, |mut input| input.read_to_end(&mut text).map(drop))?;
+    let text = String::from_utf8_lossy(&text);
+    let words: Vec<_> = text.split_ascii_whitespace().collect();
+    print!("{}", compare::compare(&stemmers[0], &stemmers[1], &words));
+    Ok(())
+}
+
+// Write the conflation map of the words of `files`, or of standard input
+// when there are none, stemmed with the stemmer named `stemmer`, to the file
+// `output` or standard output. Directories are read with all the files
+// below them.
+fn conflate(
+    stemmer: &str,
+    files: &[String],
+    output: Option<&str>,
+) -> io::Result<()> {
+    let stemmer = by_name(stemmer).expect("a known stemmer");
+    let mut map = ConflationMap::new();
+    // The map does not depend on the order of the text, so directories
+    // are read first.
+    let (dirs, files): (Vec<_>, Vec<_>) = files
+        .iter()
+        .cloned()
+        .partition(|path| Path::new(path).is_dir());
+    for dir in &dirs {
+        map.add_path(&*stemmer, dir)?;
+    }
+    if dirs.is_empty() || !files.is_empty() {
+        each_input(&files, |input| {
+            map.add_reader(&*stemmer, BufReader::new(input))
+        })?;
+    }
+
+    let classes: Map<_, _> = map
+        .iter()
+        .map(|(stem, forms)| {
+            let forms = forms
+                .iter()
+                .map(|(form, &count)| (form.clone(), Value::from(count)))
+                .collect();
+            (stem.clone(), Value::Object(forms))
+        })
+        .collect();
+    let mut output = Output::create(output)?;
+    serde_json::to_writer(&mut output, &classes)?;
+    writeln!(output)?;
+    output.finish()
+}
+
+// Return the language of the ISO 639-1 `code` or English name.
+fn language(code: &str) -> Result<Language, String> {
+    match code.to_ascii_lowercase().as_str() {
+        "en" | "english" => Ok(Language::English),
+        _ => Err(format!("no stemmer for the language `{}`", code)),
+    }
+}
+
+// Stem `files`, or standard input when there are none, to the file
+// `output` or standard output, with the stemmer named `stemmer`, which
+// `parse` has checked.
+fn stem(
+    stemmer: &str,
+    format: &Format,
+    files: &[String],
+    output: Option<&str>,
+) -> io::Result<()> {
+    let stemmer = by_name(stemmer).expect("a known stemmer");
+    let mut output = Output::create(output)?;
+
+    match format {
+        Format::Text => {
+            let stream = Streaming::new(stemmer, CAPACITY);
+            each_input(files, |input| {
+                stream.stem_stream(input, &mut output).map(drop)
+            })?;
+        }
+        Format::Jsonl { field, stems } => each_input(files, |input| {
+            let input = BufReader::new(input);
+            records::stem_jsonl(&*stemmer, field, *stems, input, &mut output)
+        })?,
+        Format::Delimited {
+            delimiter,
+            column,
+            header,
+        } => each_input(files, |input| {
+            records::stem_delimited(
+                &*stemmer,
+                *delimiter,
+                *column,
+                *header,
+                BufReader::new(input),
+                &mut output,
+            )
+        })?,
+    }
+
+    output.finish()
+}
+
+// Stem the files below the directories `paths`, and the files among
+// `paths`, to the same paths below the directory `output`, `jobs` files at a
+// time or one per CPU.
+fn stem_tree(
+    stemmer: &str,
+    format: &Format,
+    paths: &[String],
+    output: &str,
+    jobs: Option<usize>,
+) -> io::Result<()> {
+    let output = Path::new(output);
+    let mut tasks = Vec::new();
+    for path in paths {
+        let path = Path::new(path);
+        if path.is_dir() {
+            tasks.extend(
+                files::walk(path)?
+                    .into_iter()
+                    .map(|file| (path.join(&file), output.join(file))),
+            );
+        } else if let Some(name) = path.file_name() {
+            tasks.push((path.to_owned(), output.join(name)));
+        } else {
+            let message = format!("{}: not a file", path.display());
+            return Err(io::Error::new(ErrorKind::InvalidInput, message));
+        }
+    }
+
+    let mut inputs = HashMap::new();
+    for (input, output) in &tasks {
+        if let Some(other) = inputs.insert(output, input) {
+            let message = format!(
+                "{} and {} are both stemmed to {}",
+                other.display(),
+                input.display(),
+                output.display()
+            );
+            return Err(io::Error::new(ErrorKind::InvalidInput, message));
+        }
+    }
+
+    let jobs = jobs.unwrap_or_else(|| {
+        thread::available_parallelism().map_or(1, |n| n.get())
+    });
+    let tasks = Mutex::new(tasks.into_iter());
+    let failed = AtomicBool::new(false);
+    thread::scope(|scope| {
+        let workers: Vec<_> = (0..jobs)
+            .map(|_| {
+                scope.spawn(|| {
+                    // Stop at the first error, of this worker or another.
+                    while !failed.load(Ordering::Relaxed) {
+                        let next = tasks.lock().unwrap().next();
+                        let (input, output) = match next {
+                            Some(paths) => paths,
+                            None => break,
+                        };
+                        let result = stem_path(stemmer, format, input, output);
+                        if result.is_err() {
+                            failed.store(true, Ordering::Relaxed);
+                            return result;
+                        }
+                    }
+                    Ok(())
+                })
+            })
+            .collect();
+        workers
+            .into_iter()
+            .try_for_each(|worker| worker.join().expect("a worker to finish"))
+    })
+}
+
+// Stem the file `input` to the file `output`, creating its directory.
+fn stem_path(
+    stemmer: &str,
+    format: &Format,
+    input: PathBuf,
+    output: PathBuf,
+) -> io::Result<()> {
+    let utf8 = |path: PathBuf| {
+        path.into_os_string().into_string().map_err(|path| {
+            let message = format!("{}: not UTF-8", Path::new(&path).display());
+            io::Error::new(ErrorKind::InvalidInput, message)
+        })
+    };
+    if let Some(dir) = output.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    let (input, output) = (utf8(input)?, utf8(output)?);
+    stem(stemmer, format, &[input], Some(&output))
+}
+
+#[cfg(all(test, feature = "english"))]
+mod tests {
+    use super::*;
+
+    fn parse_args(args: &[&str]) -> Result<Command, String> {
+        parse(args.iter().map(|arg| arg.to_string()))
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            parse_args(&[]),
+            Ok(Command::Stem {
+                stemmer: "porter".to_owned(),
+                format: Format::Text,
+                files: vec![],
+                output: None,
+            })
+        );
+        assert_eq!(
+            parse_args(&["--stemmer=s", "a.txt", "-l", "EN", "-"]),
+            Ok(Command::Stem {
+                stemmer: "s".to_owned(),
+                format: Format::Text,
+                files: vec!["a.txt".to_owned(), "-".to_owned()],
+                output: None,
+            })
+        );
+        assert_eq!(
+            parse_args(&["-s", "porter", "-o", "out.gz", "--", "--help"]),
+            Ok(Command::Stem {
+                stemmer: "porter".to_owned(),
+                format: Format::Text,
+                files: vec!["--help".to_owned()],
+                output: Some("out.gz".to_owned()),
+            })
+        );
+        assert_eq!(
+            parse_args(&["--format", "jsonl", "--stems"]),
+            Ok(Command::Stem {
+                stemmer: "porter".to_owned(),
+                format: Format::Jsonl {
+                    field: "text".to_owned(),
+                    stems: true,
+                },
+                files: vec![],
+                output: None,
+            })
+        );
+        assert_eq!(
+            parse_args(&["-f", "tsv", "--column=3", "--header"]),
+            Ok(Command::Stem {
+                stemmer: "porter".to_owned(),
+                format: Format::Delimited {
+                    delimiter: b'\t',
+                    column: 2,
+                    header: true,
+                },
+                files: vec![],
+                output: None,
+            })
+        );
+        assert_eq!(parse_args(&["list"]), Ok(Command::List));
+        assert_eq!(
+            parse_args(&["describe", "Porter"]),
+            Ok(Command::Describe(Algorithm::Porter))
+        );
+        assert_eq!(parse_args(&["describe", "-h"]), Ok(Command::Help));
+        assert_eq!(
+            parse_args(&["bench", "--cache=10", "corpus.txt"]),
+            Ok(Command::Bench {
+                stemmers: vec!["s".to_owned(), "porter".to_owned()],
+                cache: 10,
+                files: vec!["corpus.txt".to_owned()],
+            })
+        );
+        assert_eq!(
+            parse_args(&["bench", "-s", "porter"]),
+            Ok(Command::Bench {
+                stemmers: vec!["porter".to_owned()],
+                cache: CACHE_WORDS,
+                files: vec![],
+            })
+        );
+        assert_eq!(
+            parse_args(&["repl", "--trace"]),
+            Ok(Command::Repl {
+                stemmer: "porter".to_owned(),
+                trace: true,
+            })
+        );
+        assert_eq!(
+            parse_args(&["conflate", "-s", "s", "corpus", "-o", "map.json"]),
+            Ok(Command::Conflate {
+                stemmer: "s".to_owned(),
+                files: vec!["corpus".to_owned()],
+                output: Some("map.json".to_owned()),
+            })
+        );
+        assert_eq!(
+            parse_args(&["compare", "s", "porter", "a.txt", "-"]),
+            Ok(Command::Compare {
+                stemmers: ["s".to_owned(), "porter".to_owned()],
+                files: vec!["a.txt".to_owned(), "-".to_owned()],
+            })
+        );
+        assert_eq!(
+            parse_args(&["-r", "corpus", "-o", "out", "--jobs=4"]),
+            Ok(Command::StemTree {
+                stemmer: "porter".to_owned(),
+                format: Format::Text,
+                paths: vec!["corpus".to_owned()],
+                output: "out".to_owned(),
+                jobs: Some(4),
+            })
+        );
+        assert_eq!(parse_args(&["a.txt", "--help"]), Ok(Command::Help));
+        assert_eq!(parse_args(&["-V"]), Ok(Command::Version));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            parse_args(&["--stemmer", "porter2"]),
+            Err("unknown stemming algorithm `porter2`, expected one of: \
+                 s, porter"
+                .to_owned())
+        );
+        assert_eq!(
+            parse_args(&["--lang", "de"]),
+            Err("no stemmer for the language `de`".to_owned())
+        );
+        assert_eq!(
+            parse_args(&["--stemmer"]),
+            Err("--stemmer needs a value".to_owned())
+        );
+        assert_eq!(parse_args(&["-x"]), Err("unknown option `-x`".to_owned()));
+        assert_eq!(
+            parse_args(&["repl", "-s", "s", "--trace"]),
+            Err("--trace needs the porter stemmer".to_owned())
+        );
+        assert_eq!(
+            parse_args(&["repl", "words.txt"]),
+            Err("repl reads standard input, not files".to_owned())
+        );
+        assert_eq!(
+            parse_args(&["--cache", "10"]),
+            Err("--cache needs the bench command".to_owned())
+        );
+        assert_eq!(
+            parse_args(&["bench", "--cache", "many"]),
+            Err("--cache needs a number".to_owned())
+        );
+        assert_eq!(
+            parse_args(&["--trace"]),
+            Err("--trace needs the repl command".to_owned())
+        );
+        assert_eq!(
+            parse_args(&["list", "porter"]),
+            Err("list takes no arguments".to_owned())
+        );
+        assert_eq!(
+            parse_args(&["describe"]),
+            Err("describe needs the NAME of an algorithm".to_owned())
+        );
+        assert_eq!(
+            parse_args(&["describe", "porter2"]),
+            Err("unknown stemming algorithm `porter2`, expected one of: \
+                 s, porter"
+                .to_owned())
+        );
+        assert_eq!(
+            parse_args(&["--field", "body"]),
+            Err("--field and --stems need --format jsonl".to_owned())
+        );
+        assert_eq!(
+            parse_args(&["-f", "xml"]),
+            Err("unknown format `xml`, expected one of: text, jsonl, csv, \
+                 tsv"
+            .to_owned())
+        );
+        assert_eq!(
+            parse_args(&["--header"]),
+            Err("--column and --header need --format csv or tsv".to_owned())
+        );
+        assert_eq!(
+            parse_args(&["-f", "csv", "-c", "0"]),
+            Err("invalid column `0`".to_owned())
+        );
+        assert_eq!(
+            parse_args(&["-j", "2", "a.txt"]),
+            Err("--jobs needs --recursive".to_owned())
+        );
+        assert_eq!(
+            parse_args(&["-r", "corpus"]),
+            Err("--recursive needs an --output DIR".to_owned())
+        );
+        assert_eq!(
+            parse_args(&["-r", "-o", "out"]),
+            Err("--recursive reads files, not standard input".to_owned())
+        );
+        assert_eq!(
+            parse_args(&["-r", "corpus", "-o", "out", "-j", "0"]),
+            Err("invalid number of jobs `0`".to_owned())
+        );
+        assert_eq!(
+            parse_args(&["compare", "porter"]),
+            Err("compare needs the NAMEs of two stemmers".to_owned())
+        );
+        assert_eq!(
+            parse_args(&["compare", "-s", "s", "porter", "s"]),
+            Err("compare needs the NAMEs of two stemmers".to_owned())
+        );
+        assert_eq!(
+            parse_args(&["compare", "s", "porter2"]),
+            Err("unknown stemming algorithm `porter2`, expected one of: \
+                 s, porter"
+                .to_owned())
+        );
+        assert_eq!(
+            parse_args(&["bench", "-r"]),
+            Err("--recursive and --jobs need no command".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_stem_tree() {
+        let dir = env::temp_dir()
+            .join(format!("polystem-stem-tree-{}", process::id()));
+        let (input, output) = (dir.join("in"), dir.join("out"));
+        fs::create_dir_all(input.join("a").join("b")).unwrap();
+        fs::create_dir_all(input.join(".git")).unwrap();
+        fs::write(input.join("top.txt"), "ponies\n").unwrap();
+        fs::write(input.join("a").join("b").join("deep.txt"), "rusted")
+            .unwrap();
+        fs::write(input.join(".git").join("HEAD"), "ref").unwrap();
+
+        let paths = [input.to_str().unwrap().to_owned()];
+        let out = output.to_str().unwrap();
+        let result = stem_tree("porter", &Format::Text, &paths, out, Some(2));
+        let walked = files::walk(&output);
+        let top = fs::read_to_string(output.join("top.txt"));
+        let deep =
+            fs::read_to_string(output.join("a").join("b").join("deep.txt"));
+        let top_file = input.join("top.txt").to_str().unwrap().to_owned();
+        let twice = stem_tree(
+            "porter",
+            &Format::Text,
+            &[paths[0].clone(), top_file],
+            out,
+            None,
+        );
+        fs::remove_dir_all(&dir).unwrap();
+
+        result.unwrap();
+        assert_eq!(
+            walked.unwrap(),
+            vec![Path::new("a").join("b").join("deep.txt"), "top.txt".into()]
+        );
+        assert_eq!(top.unwrap(), "poni\n");
+        assert_eq!(deep.unwrap(), "rust");
+        let e = twice.unwrap_err();
+        assert_eq!(e.kind(), ErrorKind::InvalidInput);
+        assert!(e.to_string().contains("are both stemmed to"));
+    }
+
+    #[test]
+    fn test_list_describe() {
+        assert_eq!(
+            list(),
+            "NAME    LANGUAGE  AGGRESSIVENESS  VERSION\n\
+             s       english   light           3\n\
+             porter  english   moderate        3\n"
+        );
+        assert_eq!(
+            describe(Algorithm::S),
+            "name: s\nlanguage: english\naggressiveness: light\nversion: 3\n"
+        );
+    }
+}
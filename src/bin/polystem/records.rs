@@ -0,0 +1,258 @@
+// Copyright 2019 The Polystem authors.
+//
+// For the full copyright and license information, please view the LICENSE file
+// that was distributed with this source code.
+
+// Stemming of the records of structured formats, where only part of each
+// record is text to stem.
+
+use std::borrow::Cow;
+use std::io::{self, BufRead, ErrorKind, Write};
+use std::str;
+
+use polystem::DynStemmer;
+use serde_json::Value;
+
+// Stem the string `field` of each JSON Lines record of `input` into
+// `output`, or add its stems as a `stems` array. Blank lines are skipped.
+pub fn stem_jsonl<R: BufRead, W: Write>(
+    stemmer: &dyn DynStemmer,
+    field: &str,
+    stems: bool,
+    input: R,
+    output: &mut W,
+) -> io::Result<()> {
+    for (i, line) in input.lines().enumerate() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let invalid = |message: &dyn std::fmt::Display| {
+            let message = format!("line {}: {}", i + 1, message);
+            io::Error::new(ErrorKind::InvalidData, message)
+        };
+
+        let mut record: Value =
+            serde_json::from_str(&line).map_err(|e| invalid(&e))?;
+        let object = record
+            .as_object_mut()
+            .ok_or_else(|| invalid(&"not a JSON object"))?;
+        match object.get(field) {
+            None => {}
+            Some(Value::String(text)) if stems => {
+                let stems = text
+                    .split_ascii_whitespace()
+                    .map(|word| Value::String(stemmer.stem_word(word)))
+                    .collect();
+                object.insert("stems".to_owned(), Value::Array(stems));
+            }
+            Some(Value::String(text)) => {
+                let text = stem_words(stemmer, text);
+                object.insert(field.to_owned(), Value::String(text));
+            }
+            Some(_) => {
+                let message = format!("the field `{}` is not a string", field);
+                return Err(invalid(&message));
+            }
+        }
+
+        serde_json::to_writer(&mut *output, &record)?;
+        output.write_all(b"\n")?;
+    }
+    Ok(())
+}
+
+// Replace every word of `text`, a run of characters between ASCII
+// whitespace, with its stem, and keep the whitespace as is.
+fn stem_words(stemmer: &dyn DynStemmer, text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while !rest.is_empty() {
+        let start = rest
+            .find(|c: char| !c.is_ascii_whitespace())
+            .unwrap_or(rest.len());
+        out.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let end = rest
+            .find(|c: char| c.is_ascii_whitespace())
+            .unwrap_or(rest.len());
+        if end > 0 {
+            out.push_str(&stemmer.stem_word(&rest[..end]));
+        }
+        rest = &rest[end..];
+    }
+    out
+}
+
+// Stem the words of the column `column`, counted from zero, of each
+// record of the delimited `input` into `output`, keeping the quotes of the
+// column. The other columns and the line endings are copied as is, as is
+// the first record when `header` is set.
+pub fn stem_delimited<R: BufRead, W: Write>(
+    stemmer: &dyn DynStemmer,
+    delimiter: u8,
+    column: usize,
+    header: bool,
+    mut input: R,
+    output: &mut W,
+) -> io::Result<()> {
+    let mut record = Vec::new();
+    let mut first = true;
+    loop {
+        record.clear();
+        // A quoted field can span lines, so read until the quotes balance.
+        while input.read_until(b'\n', &mut record)? > 0 {
+            let quotes = record.iter().filter(|&&b| b == b'"').count();
+            if quotes.is_multiple_of(2) {
+                break;
+            }
+        }
+        if record.is_empty() {
+            return Ok(());
+        }
+
+        if header && first {
+            output.write_all(&record)?;
+        } else {
+            stem_record(stemmer, delimiter, column, &record, output)?;
+        }
+        first = false;
+    }
+}
+
+// Stem the column `column` of the delimited `record`.
+fn stem_record<W: Write>(
+    stemmer: &dyn DynStemmer,
+    delimiter: u8,
+    column: usize,
+    record: &[u8],
+    output: &mut W,
+) -> io::Result<()> {
+    let end = if record.ends_with(b"\r\n") {
+        record.len() - 2
+    } else if record.ends_with(b"\n") {
+        record.len() - 1
+    } else {
+        record.len()
+    };
+
+    let mut index = 0;
+    let mut start = 0;
+    let mut quoted = false;
+    let mut span = None;
+    for (i, &b) in record[..end].iter().enumerate() {
+        if b == b'"' {
+            quoted = !quoted;
+        } else if b == delimiter && !quoted {
+            if index == column {
+                span = Some((start, i));
+                break;
+            }
+            index += 1;
+            start = i + 1;
+        }
+    }
+    if span.is_none() && index == column {
+        span = Some((start, end));
+    }
+
+    match span {
+        Some((start, end)) => {
+            output.write_all(&record[..start])?;
+            output.write_all(&stem_field(stemmer, &record[start..end]))?;
+            output.write_all(&record[end..])
+        }
+        None => output.write_all(record),
+    }
+}
+
+// Stem the words of the delimited `field`, which keeps its quotes if it
+// has them. A field that is not valid UTF-8 is returned unchanged.
+fn stem_field<'a>(stemmer: &dyn DynStemmer, field: &'a [u8]) -> Cow<'a, [u8]> {
+    let text = match str::from_utf8(field) {
+        Ok(text) => text,
+        Err(_) => return Cow::Borrowed(field),
+    };
+    let stemmed =
+        match text.strip_prefix('"').and_then(|t| t.strip_suffix('"')) {
+            Some(inner) => {
+                let stemmed =
+                    stem_words(stemmer, &inner.replace("\"\"", "\""));
+                format!("\"{}\"", stemmed.replace('"', "\"\""))
+            }
+            None => stem_words(stemmer, text),
+        };
+    Cow::Owned(stemmed.into_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use polystem::by_name;
+
+    fn jsonl(field: &str, stems: bool, input: &str) -> io::Result<String> {
+        let mut output = Vec::new();
+        let stemmer = by_name("porter").unwrap();
+        stem_jsonl(&*stemmer, field, stems, input.as_bytes(), &mut output)?;
+        Ok(String::from_utf8(output).unwrap())
+    }
+
+    #[test]
+    fn test_stem_jsonl() {
+        let input = "{\"id\":1,\"text\":\" ponies  rusted\"}\n\n{\"id\":2}\n";
+        assert_eq!(
+            jsonl("text", false, input).unwrap(),
+            "{\"id\":1,\"text\":\" poni  rust\"}\n{\"id\":2}\n"
+        );
+        assert_eq!(
+            jsonl("text", true, input).unwrap(),
+            "{\"id\":1,\"text\":\" ponies  rusted\",\
+             \"stems\":[\"poni\",\"rust\"]}\n\
+             {\"id\":2}\n"
+        );
+
+        let err = |input| jsonl("id", false, input).unwrap_err().to_string();
+        assert_eq!(
+            err("{\"id\":1}"),
+            "line 1: the field `id` is not a string"
+        );
+        assert_eq!(err("\n[]"), "line 2: not a JSON object");
+        assert!(err("{").starts_with("line 1: EOF while parsing"));
+    }
+
+    fn delimited(delimiter: u8, column: usize, input: &str) -> String {
+        let mut output = Vec::new();
+        let stemmer = by_name("porter").unwrap();
+        stem_delimited(
+            &*stemmer,
+            delimiter,
+            column,
+            true,
+            input.as_bytes(),
+            &mut output,
+        )
+        .unwrap();
+        String::from_utf8(output).unwrap()
+    }
+
+    #[test]
+    fn test_stem_delimited() {
+        let input = "id,title,notes\r\n\
+                     1,\"ponies \"\"rusted\"\" \",\"connected\"\r\n\
+                     2,\"Connections\nrunning\",jumping\n\
+                     3\n\
+                     4,jumping";
+        assert_eq!(
+            delimited(b',', 1, input),
+            "id,title,notes\r\n\
+             1,\"poni \"\"rusted\"\" \",\"connected\"\r\n\
+             2,\"connect\nrun\",jumping\n\
+             3\n\
+             4,jump"
+        );
+        assert_eq!(
+            delimited(b'\t', 2, "a\tb\tponies\nc\td\t\"rusted\"\n"),
+            "a\tb\tponies\nc\td\t\"rust\"\n"
+        );
+    }
+}
@@ -0,0 +1,82 @@
+// Copyright 2019 The Polystem authors.
+//
+// For the full copyright and license information, please view the LICENSE file
+// that was distributed with this source code.
+
+// The interactive mode of the program, which stems the words of each line
+// as it is typed.
+
+use std::io::{self, BufRead, Write};
+
+use polystem::{DynStemmer, Porter};
+
+// Stem the words of each line of `input` to `output`, prompting for each
+// line when `prompt` is set. With `trace` set, each word is stemmed with
+// `Porter::stem_traced` and printed with the rules that fired.
+pub fn repl<R: BufRead, W: Write>(
+    stemmer: &dyn DynStemmer,
+    trace: bool,
+    prompt: bool,
+    mut input: R,
+    output: &mut W,
+) -> io::Result<()> {
+    let mut line = String::new();
+    loop {
+        if prompt {
+            output.write_all(b"> ")?;
+            output.flush()?;
+        }
+        line.clear();
+        if input.read_line(&mut line)? == 0 {
+            if prompt {
+                writeln!(output)?;
+            }
+            return Ok(());
+        }
+
+        if !trace {
+            let stems: Vec<_> = line
+                .split_ascii_whitespace()
+                .map(|word| stemmer.stem_word(word))
+                .collect();
+            writeln!(output, "{}", stems.join(" "))?;
+            continue;
+        }
+        for word in line.split_ascii_whitespace() {
+            let (stem, rules) = Porter::stem_traced(word);
+            writeln!(output, "{} → {}", word, stem)?;
+            for rule in rules {
+                writeln!(output, "  {}", rule)?;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use polystem::S;
+
+    fn run(stemmer: &dyn DynStemmer, trace: bool, input: &str) -> String {
+        let mut output = Vec::new();
+        repl(stemmer, trace, false, input.as_bytes(), &mut output).unwrap();
+        String::from_utf8(output).unwrap()
+    }
+
+    #[test]
+    fn test_repl() {
+        assert_eq!(
+            run(&S, false, "ponies  flies\n\ncats"),
+            "pony fly\n\ncat\n"
+        );
+        assert_eq!(
+            run(&Porter::default(), true, "hopping a\n"),
+            "hopping → hop\n  step1ab: ing→\n  step1ab: p→\n\
+             a → a\n"
+        );
+
+        let mut output = Vec::new();
+        repl(&S, false, true, "cats\n".as_bytes(), &mut output).unwrap();
+        assert_eq!(output, b"> cat\n> \n");
+    }
+}
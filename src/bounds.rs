@@ -0,0 +1,125 @@
+// Copyright 2019 The Polystem authors.
+//
+// For the full copyright and license information, please view the LICENSE file
+// that was distributed with this source code.
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{DynStemmer, StemError};
+
+/// Limits on the length, in characters, of the words and stems handled by a
+/// [`Bounded`] stemmer.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct LengthBounds {
+    /// Words shorter than this are returned unchanged.
+    pub min_word_len: usize,
+    /// Stems are never shorter than this. When a stemmer removes too much,
+    /// the stem is the prefix of the lowercased word of this length.
+    pub min_stem_len: usize,
+}
+
+impl LengthBounds {
+    // Apply the bounds to `stem`, the result of stemming `word`.
+    fn apply(&self, word: &str, stem: String) -> String {
+        if stem.chars().count() >= self.min_stem_len {
+            return stem;
+        }
+
+        word.to_lowercase()
+            .chars()
+            .take(self.min_stem_len)
+            .collect()
+    }
+
+    // Return `true` when `word` is too short to be stemmed.
+    fn skip(&self, word: &str) -> bool {
+        word.chars().count() < self.min_word_len
+    }
+}
+
+/// A stemmer wrapper that enforces [`LengthBounds`] on any stemmer.
+///
+/// # Examples
+///
+/// ```
+/// use polystem::{Algorithm, Bounded, DynStemmer, LengthBounds};
+///
+/// let bounds = LengthBounds {
+///     min_word_len: 4,
+///     min_stem_len: 3,
+/// };
+/// let stemmer = Bounded::new(Algorithm::Porter, bounds);
+///
+/// assert_eq!("its", stemmer.stem_word("its"));
+/// assert_eq!("tie", stemmer.stem_word("ties"));
+/// assert_eq!("hop", stemmer.stem_word("hopping"));
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Bounded<D> {
+    stemmer: D,
+    bounds: LengthBounds,
+}
+
+impl<D: DynStemmer> Bounded<D> {
+    /// Construct a new `Bounded` wrapping `stemmer`.
+    pub fn new(stemmer: D, bounds: LengthBounds) -> Bounded<D> {
+        Bounded { stemmer, bounds }
+    }
+
+    /// Return the bounds enforced by the stemmer.
+    pub fn bounds(&self) -> LengthBounds {
+        self.bounds
+    }
+}
+
+impl<D: DynStemmer> DynStemmer for Bounded<D> {
+    fn stem_word(&self, word: &str) -> String {
+        if self.bounds.skip(word) {
+            return String::from(word);
+        }
+
+        let stem = self.stemmer.stem_word(word);
+        self.bounds.apply(word, stem)
+    }
+
+    fn try_stem_word(&self, word: &str) -> Result<String, StemError> {
+        let stem = self.stemmer.try_stem_word(word)?;
+        if self.bounds.skip(word) {
+            return Ok(String::from(word));
+        }
+
+        Ok(self.bounds.apply(word, stem))
+    }
+}
+
+#[cfg(all(test, feature = "s"))]
+mod tests {
+    use super::*;
+    use crate::S;
+
+    #[test]
+    fn test_bounded() {
+        let stemmer = Bounded::new(S, LengthBounds::default());
+        assert_eq!(stemmer.stem_word("flies"), "fly");
+
+        let bounds = LengthBounds {
+            min_word_len: 5,
+            min_stem_len: 0,
+        };
+        let stemmer = Bounded::new(S, bounds);
+        assert_eq!(stemmer.stem_word("Suns"), "Suns");
+        assert_eq!(stemmer.stem_word("Flies"), "fly");
+
+        let bounds = LengthBounds {
+            min_word_len: 0,
+            min_stem_len: 4,
+        };
+        let stemmer = Bounded::new(S, bounds);
+        assert_eq!(stemmer.stem_word("Flies"), "flie");
+        assert_eq!(stemmer.stem_word("as"), "as");
+        assert_eq!(stemmer.try_stem_word(""), Err(StemError::Empty));
+    }
+}
@@ -0,0 +1,469 @@
+// Copyright 2019 The Polystem authors.
+//
+// For the full copyright and license information, please view the LICENSE file
+// that was distributed with this source code.
+
+use std::cell::RefCell;
+use std::collections::hash_map::RandomState;
+use std::collections::HashMap;
+use std::hash::BuildHasher;
+use std::sync::{Mutex, MutexGuard};
+
+use crate::{DynStemmer, Pos, StemError};
+
+/// The number of lookups of a cache that found the word, and that missed.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CacheStats {
+    pub hits: u64,
+    pub misses: u64,
+}
+
+impl CacheStats {
+    /// Return the fraction of lookups that found the word, or `0.0` before
+    /// the first lookup.
+    pub fn hit_rate(&self) -> f64 {
+        let lookups = self.hits + self.misses;
+        if lookups == 0 {
+            return 0.0;
+        }
+        self.hits as f64 / lookups as f64
+    }
+}
+
+// The index of no slot, ending the list of slots.
+const NIL: usize = usize::MAX;
+
+// A cached stem, linked into the list of slots from most to least recently
+// used.
+#[derive(Clone, Debug)]
+struct Slot {
+    word: String,
+    stem: String,
+    prev: usize,
+    next: usize,
+}
+
+// A map from word to stem that evicts the least recently used word when it
+// is full.
+#[derive(Clone, Debug)]
+struct Lru {
+    capacity: usize,
+    index: HashMap<String, usize>,
+    slots: Vec<Slot>,
+    head: usize,
+    tail: usize,
+    stats: CacheStats,
+}
+
+impl Lru {
+    fn new(capacity: usize) -> Lru {
+        Lru {
+            capacity,
+            index: HashMap::new(),
+            slots: Vec::new(),
+            head: NIL,
+            tail: NIL,
+            stats: CacheStats::default(),
+        }
+    }
+
+    // Return the stem of `word` and mark it as the most recently used.
+    fn get(&mut self, word: &str) -> Option<String> {
+        match self.index.get(word) {
+            Some(&i) => {
+                self.stats.hits += 1;
+                self.unlink(i);
+                self.push_front(i);
+                Some(self.slots[i].stem.clone())
+            }
+            None => {
+                self.stats.misses += 1;
+                None
+            }
+        }
+    }
+
+    // Cache `stem` for `word`, evicting the least recently used word when
+    // the cache is full.
+    fn insert(&mut self, word: &str, stem: &str) {
+        if self.capacity == 0 || self.index.contains_key(word) {
+            return;
+        }
+
+        let i = if self.slots.len() < self.capacity {
+            self.slots.push(Slot {
+                word: word.to_owned(),
+                stem: stem.to_owned(),
+                prev: NIL,
+                next: NIL,
+            });
+            self.slots.len() - 1
+        } else {
+            let i = self.tail;
+            self.unlink(i);
+            let slot = &mut self.slots[i];
+            self.index.remove(&slot.word);
+            slot.word.clear();
+            slot.word.push_str(word);
+            slot.stem.clear();
+            slot.stem.push_str(stem);
+            i
+        };
+
+        self.index.insert(word.to_owned(), i);
+        self.push_front(i);
+    }
+
+    // Remove slot `i` from the list.
+    fn unlink(&mut self, i: usize) {
+        let Slot { prev, next, .. } = self.slots[i];
+        match prev {
+            NIL => self.head = next,
+            prev => self.slots[prev].next = next,
+        }
+        match next {
+            NIL => self.tail = prev,
+            next => self.slots[next].prev = prev,
+        }
+    }
+
+    // Insert slot `i` at the front of the list.
+    fn push_front(&mut self, i: usize) {
+        self.slots[i].prev = NIL;
+        self.slots[i].next = self.head;
+        match self.head {
+            NIL => self.tail = i,
+            head => self.slots[head].prev = i,
+        }
+        self.head = i;
+    }
+
+    fn clear(&mut self) {
+        self.index.clear();
+        self.slots.clear();
+        self.head = NIL;
+        self.tail = NIL;
+    }
+}
+
+/// A stemmer wrapper that caches the stems of the most recently used words.
+///
+/// Word frequencies follow Zipf's law, so a small cache answers most of the
+/// tokens of a text. At most `capacity` words are cached, and the least
+/// recently used word is evicted first.
+///
+/// # Examples
+///
+/// ```
+/// use polystem::{Algorithm, Cached, DynStemmer};
+///
+/// let stemmer = Cached::new(Algorithm::Porter, 1024);
+///
+/// for word in "the rusted gate rusted away".split(' ') {
+///     stemmer.stem_word(word);
+/// }
+///
+/// assert_eq!(1, stemmer.stats().hits);
+/// assert_eq!(4, stemmer.stats().misses);
+/// ```
+#[derive(Debug)]
+pub struct Cached<D> {
+    stemmer: D,
+    lru: RefCell<Lru>,
+}
+
+impl<D: DynStemmer> Cached<D> {
+    /// Construct a new `Cached` wrapping `stemmer`, which caches up to
+    /// `capacity` words.
+    pub fn new(stemmer: D, capacity: usize) -> Cached<D> {
+        Cached {
+            stemmer,
+            lru: RefCell::new(Lru::new(capacity)),
+        }
+    }
+
+    /// Return the maximum number of cached words.
+    pub fn capacity(&self) -> usize {
+        self.lru.borrow().capacity
+    }
+
+    /// Return the number of cached words.
+    pub fn len(&self) -> usize {
+        self.lru.borrow().index.len()
+    }
+
+    /// Return `true` if no word is cached.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Return the hit and miss counts of the cache.
+    pub fn stats(&self) -> CacheStats {
+        self.lru.borrow().stats
+    }
+
+    /// Remove every cached word. The statistics are kept.
+    pub fn clear(&self) {
+        self.lru.borrow_mut().clear();
+    }
+}
+
+impl<D: DynStemmer> DynStemmer for Cached<D> {
+    fn stem_word(&self, word: &str) -> String {
+        if let Some(stem) = self.lru.borrow_mut().get(word) {
+            return stem;
+        }
+
+        let stem = self.stemmer.stem_word(word);
+        self.lru.borrow_mut().insert(word, &stem);
+        stem
+    }
+
+    fn try_stem_word(&self, word: &str) -> Result<String, StemError> {
+        self.stemmer.try_stem_word(word)
+    }
+
+    fn stem_candidates_word(&self, word: &str) -> Vec<String> {
+        self.stemmer.stem_candidates_word(word)
+    }
+
+    fn stem_with_pos_word(&self, word: &str, pos: Pos) -> String {
+        self.stemmer.stem_with_pos_word(word, pos)
+    }
+
+    fn stem_scored_word(&self, word: &str) -> (String, f32) {
+        self.stemmer.stem_scored_word(word)
+    }
+}
+
+/// A thread safe counterpart to [`Cached`], for indexing threads that share
+/// one cache.
+///
+/// The words are spread over independently locked shards by their hash, so
+/// threads stemming different words rarely wait for each other. Each shard
+/// evicts its least recently used word when it is full.
+///
+/// # Examples
+///
+/// ```
+/// use std::sync::Arc;
+/// use std::thread;
+///
+/// use polystem::{Algorithm, DynStemmer, SharedCached};
+///
+/// let stemmer = Arc::new(SharedCached::new(Algorithm::Porter, 1024));
+/// let threads: Vec<_> = (0..4)
+///     .map(|_| {
+///         let stemmer = Arc::clone(&stemmer);
+///         thread::spawn(move || stemmer.stem_word("rusted"))
+///     })
+///     .collect();
+///
+/// for thread in threads {
+///     assert_eq!("rust", thread.join().unwrap());
+/// }
+/// assert_eq!(1, stemmer.len());
+/// ```
+#[derive(Debug)]
+pub struct SharedCached<D> {
+    stemmer: D,
+    shards: Vec<Mutex<Lru>>,
+    hasher: RandomState,
+}
+
+impl<D: DynStemmer> SharedCached<D> {
+    /// The number of shards of a cache built with `new`.
+    pub const SHARDS: usize = 16;
+
+    /// Construct a new `SharedCached` wrapping `stemmer`, which caches up to
+    /// `capacity` words.
+    pub fn new(stemmer: D, capacity: usize) -> SharedCached<D> {
+        SharedCached::with_shards(stemmer, capacity, Self::SHARDS)
+    }
+
+    /// Construct a new `SharedCached` wrapping `stemmer`, which caches up to
+    /// `capacity` words split evenly over `shards` shards.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `shards` is zero.
+    pub fn with_shards(
+        stemmer: D,
+        capacity: usize,
+        shards: usize,
+    ) -> SharedCached<D> {
+        assert!(shards > 0, "a cache needs at least one shard");
+        let shards = (0..shards)
+            .map(|i| {
+                let extra = usize::from(i < capacity % shards);
+                Mutex::new(Lru::new(capacity / shards + extra))
+            })
+            .collect();
+
+        SharedCached {
+            stemmer,
+            shards,
+            hasher: RandomState::new(),
+        }
+    }
+
+    /// Return the maximum number of cached words.
+    pub fn capacity(&self) -> usize {
+        self.locks().map(|lru| lru.capacity).sum()
+    }
+
+    /// Return the number of cached words.
+    pub fn len(&self) -> usize {
+        self.locks().map(|lru| lru.index.len()).sum()
+    }
+
+    /// Return `true` if no word is cached.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Return the hit and miss counts of the cache, summed over the shards.
+    pub fn stats(&self) -> CacheStats {
+        self.locks()
+            .fold(CacheStats::default(), |sum, lru| CacheStats {
+                hits: sum.hits + lru.stats.hits,
+                misses: sum.misses + lru.stats.misses,
+            })
+    }
+
+    /// Remove every cached word. The statistics are kept.
+    pub fn clear(&self) {
+        for mut lru in self.locks() {
+            lru.clear();
+        }
+    }
+
+    // Lock the shard of `word`.
+    fn shard(&self, word: &str) -> MutexGuard<'_, Lru> {
+        let i = self.hasher.hash_one(word) as usize % self.shards.len();
+        lock(&self.shards[i])
+    }
+
+    // Lock each shard in turn.
+    fn locks(&self) -> impl Iterator<Item = MutexGuard<'_, Lru>> {
+        self.shards.iter().map(lock)
+    }
+}
+
+// Lock `shard`. A cache is always in a consistent state between calls, so
+// the lock of a thread that panicked is taken over.
+fn lock(shard: &Mutex<Lru>) -> MutexGuard<'_, Lru> {
+    shard.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+impl<D: DynStemmer> DynStemmer for SharedCached<D> {
+    fn stem_word(&self, word: &str) -> String {
+        if let Some(stem) = self.shard(word).get(word) {
+            return stem;
+        }
+
+        // The lock is not held while stemming, so that other threads can use
+        // the shard meanwhile.
+        let stem = self.stemmer.stem_word(word);
+        self.shard(word).insert(word, &stem);
+        stem
+    }
+
+    fn try_stem_word(&self, word: &str) -> Result<String, StemError> {
+        self.stemmer.try_stem_word(word)
+    }
+
+    fn stem_candidates_word(&self, word: &str) -> Vec<String> {
+        self.stemmer.stem_candidates_word(word)
+    }
+
+    fn stem_with_pos_word(&self, word: &str, pos: Pos) -> String {
+        self.stemmer.stem_with_pos_word(word, pos)
+    }
+
+    fn stem_scored_word(&self, word: &str) -> (String, f32) {
+        self.stemmer.stem_scored_word(word)
+    }
+}
+
+#[cfg(all(test, feature = "s"))]
+mod tests {
+    use super::*;
+    use crate::{Stemmer, S};
+    use std::thread;
+
+    #[test]
+    fn test_cached() {
+        let stemmer = Cached::new(S, 2);
+        assert_eq!(stemmer.capacity(), 2);
+        assert!(stemmer.is_empty());
+
+        assert_eq!(stemmer.stem_word("flies"), "fly");
+        assert_eq!(stemmer.stem_word("suns"), "sun");
+        assert_eq!(stemmer.stem_word("flies"), "fly");
+        assert_eq!(stemmer.len(), 2);
+
+        // "suns" is the least recently used word and is evicted.
+        assert_eq!(stemmer.stem_word("cats"), "cat");
+        assert_eq!(stemmer.len(), 2);
+        assert_eq!(stemmer.stem_word("flies"), "fly");
+        assert_eq!(stemmer.stem_word("suns"), "sun");
+
+        let stats = stemmer.stats();
+        assert_eq!(stats, CacheStats { hits: 2, misses: 4 });
+        assert!((stats.hit_rate() - 1.0 / 3.0).abs() < 1e-9);
+
+        stemmer.clear();
+        assert!(stemmer.is_empty());
+        assert_eq!(stemmer.stem_word("suns"), "sun");
+        assert_eq!(stemmer.try_stem_word(""), Err(StemError::Empty));
+    }
+
+    #[test]
+    fn test_shared_cached() {
+        let stemmer = SharedCached::with_shards(S, 64, 4);
+        assert_eq!(stemmer.capacity(), 64);
+        assert_eq!(SharedCached::with_shards(S, 10, 4).capacity(), 10);
+
+        let words = ["flies", "suns", "cats", "flies", "suns", "dogs"];
+        thread::scope(|scope| {
+            for _ in 0..4 {
+                scope.spawn(|| {
+                    for word in words.iter() {
+                        assert_eq!(stemmer.stem_word(word), S::stem(word));
+                    }
+                });
+            }
+        });
+
+        assert_eq!(stemmer.len(), 4);
+        let stats = stemmer.stats();
+        assert_eq!(stats.hits + stats.misses, 24);
+        assert!(stats.misses >= 4);
+
+        stemmer.clear();
+        assert!(stemmer.is_empty());
+        assert_eq!(stemmer.stats(), stats);
+    }
+
+    #[test]
+    fn test_shared_cached_eviction() {
+        let stemmer = SharedCached::with_shards(S, 2, 1);
+        for word in &["flies", "suns", "cats", "dogs"] {
+            stemmer.stem_word(word);
+        }
+        assert_eq!(stemmer.len(), 2);
+        assert_eq!(stemmer.stem_word("dogs"), "dog");
+        assert_eq!(stemmer.stats(), CacheStats { hits: 1, misses: 4 });
+    }
+
+    #[test]
+    fn test_cached_zero_capacity() {
+        let stemmer = Cached::new(S, 0);
+        assert_eq!(stemmer.stem_word("flies"), "fly");
+        assert_eq!(stemmer.stem_word("flies"), "fly");
+        assert!(stemmer.is_empty());
+        assert_eq!(stemmer.stats().hits, 0);
+        assert_eq!(CacheStats::default().hit_rate(), 0.0);
+    }
+}
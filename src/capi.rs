@@ -0,0 +1,192 @@
+// Copyright 2019 The Polystem authors.
+//
+// For the full copyright and license information, please view the LICENSE file
+// that was distributed with this source code.
+
+//! The C interface, declared in `include/polystem.h`.
+//!
+//! Build the shared library with
+//! `cargo rustc --release --lib --features capi --crate-type cdylib`.
+
+use std::ffi::CStr;
+use std::os::raw::c_char;
+use std::slice;
+use std::str;
+
+use crate::Algorithm;
+
+/// The id of the s-stemmer.
+pub const POLYSTEM_S: u32 = 0;
+/// The id of the Porter stemmer.
+pub const POLYSTEM_PORTER: u32 = 1;
+
+/// The algorithm id is unknown, or its stemmer is not compiled in.
+pub const POLYSTEM_ERROR_ALGORITHM: isize = -1;
+/// The word is not valid UTF-8.
+pub const POLYSTEM_ERROR_UTF8: isize = -2;
+/// A pointer argument is null.
+pub const POLYSTEM_ERROR_NULL: isize = -3;
+
+/// Stem the NUL-terminated `word` with the algorithm `algorithm` into
+/// `out`, which holds `len` bytes.
+///
+/// Return the length of the stem, not counting the NUL byte, or one of the
+/// negative `POLYSTEM_ERROR_*` codes. As with `snprintf`, the stem is
+/// truncated to fit `out` and is always NUL-terminated when `len` is not
+/// zero, so a return value of `len` or more means that `out` was too small.
+///
+/// # Safety
+///
+/// `word` must point to a NUL-terminated string, and `out` to `len`
+/// writable bytes unless `len` is zero.
+#[no_mangle]
+pub unsafe extern "C" fn polystem_stem(
+    algorithm: u32,
+    word: *const c_char,
+    out: *mut c_char,
+    len: usize,
+) -> isize {
+    if word.is_null() {
+        return POLYSTEM_ERROR_NULL;
+    }
+    let word = CStr::from_ptr(word).to_bytes();
+    stem(algorithm, word, out, len)
+}
+
+/// Stem the `word_len` bytes of `word`, which need not be NUL-terminated,
+/// into `out`, see `polystem_stem`.
+///
+/// # Safety
+///
+/// `word` must point to `word_len` readable bytes, and `out` to `len`
+/// writable bytes unless `len` is zero.
+#[no_mangle]
+pub unsafe extern "C" fn polystem_stem_n(
+    algorithm: u32,
+    word: *const c_char,
+    word_len: usize,
+    out: *mut c_char,
+    len: usize,
+) -> isize {
+    if word.is_null() {
+        return POLYSTEM_ERROR_NULL;
+    }
+    let word = slice::from_raw_parts(word.cast::<u8>(), word_len);
+    stem(algorithm, word, out, len)
+}
+
+// Return the algorithm of the C id `id`.
+fn algorithm(id: u32) -> Option<Algorithm> {
+    match id {
+        #[cfg(feature = "s")]
+        POLYSTEM_S => Some(Algorithm::S),
+        #[cfg(feature = "porter")]
+        POLYSTEM_PORTER => Some(Algorithm::Porter),
+        _ => None,
+    }
+}
+
+// Stem `word` into the `len` bytes at `out`, see `polystem_stem`.
+unsafe fn stem(id: u32, word: &[u8], out: *mut c_char, len: usize) -> isize {
+    let algorithm = match algorithm(id) {
+        Some(algorithm) => algorithm,
+        None => return POLYSTEM_ERROR_ALGORITHM,
+    };
+    let word = match str::from_utf8(word) {
+        Ok(word) => word,
+        Err(_) => return POLYSTEM_ERROR_UTF8,
+    };
+    if out.is_null() && len > 0 {
+        return POLYSTEM_ERROR_NULL;
+    }
+
+    algorithm.with_stem(word, |stem| {
+        if len > 0 {
+            let out = slice::from_raw_parts_mut(out.cast::<u8>(), len);
+            let n = stem.len().min(len - 1);
+            out[..n].copy_from_slice(&stem.as_bytes()[..n]);
+            out[n] = 0;
+        }
+        stem.len() as isize
+    })
+}
+
+#[cfg(all(test, feature = "english"))]
+mod tests {
+    use super::*;
+    use std::ffi::CString;
+
+    // Stem `word` with `polystem_stem` into a buffer of `len` bytes.
+    fn stem_c(algorithm: u32, word: &str, len: usize) -> (isize, Vec<u8>) {
+        let word = CString::new(word).unwrap();
+        let mut out = vec![0xff; len];
+        let n = unsafe {
+            polystem_stem(
+                algorithm,
+                word.as_ptr(),
+                out.as_mut_ptr().cast(),
+                len,
+            )
+        };
+        (n, out)
+    }
+
+    #[test]
+    fn test_polystem_stem() {
+        assert_eq!(
+            stem_c(POLYSTEM_PORTER, "rusted", 8),
+            (4, b"rust\0\xff\xff\xff".to_vec())
+        );
+        assert_eq!(stem_c(POLYSTEM_S, "flies", 4), (3, b"fly\0".to_vec()));
+        assert_eq!(stem_c(POLYSTEM_S, "flies", 3), (3, b"fl\0".to_vec()));
+        assert_eq!(stem_c(POLYSTEM_S, "flies", 0), (3, vec![]));
+        assert_eq!(stem_c(7, "flies", 4).0, POLYSTEM_ERROR_ALGORITHM);
+
+        let n = unsafe {
+            polystem_stem(
+                POLYSTEM_S,
+                std::ptr::null(),
+                std::ptr::null_mut(),
+                0,
+            )
+        };
+        assert_eq!(n, POLYSTEM_ERROR_NULL);
+    }
+
+    #[test]
+    fn test_polystem_stem_n() {
+        let word = b"connections\xff";
+        let mut out = [0u8; 16];
+        let mut stem = |len| unsafe {
+            let n = polystem_stem_n(
+                POLYSTEM_PORTER,
+                word.as_ptr().cast(),
+                len,
+                out.as_mut_ptr().cast(),
+                out.len(),
+            );
+            (n, out)
+        };
+        let (n, out) = stem(11);
+        assert_eq!((n, &out[..8]), (7, &b"connect\0"[..]));
+        assert_eq!(stem(12).0, POLYSTEM_ERROR_UTF8);
+    }
+
+    // The header declares every function and constant of this module.
+    #[test]
+    fn test_header() {
+        let header = include_str!("../include/polystem.h");
+        let names = [
+            "polystem_stem(",
+            "polystem_stem_n(",
+            "POLYSTEM_S 0",
+            "POLYSTEM_PORTER 1",
+            "POLYSTEM_ERROR_ALGORITHM -1",
+            "POLYSTEM_ERROR_UTF8 -2",
+            "POLYSTEM_ERROR_NULL -3",
+        ];
+        for name in &names {
+            assert!(header.contains(name), "{} is not in the header", name);
+        }
+    }
+}
@@ -0,0 +1,118 @@
+// Copyright 2019 The Polystem authors.
+//
+// For the full copyright and license information, please view the LICENSE file
+// that was distributed with this source code.
+
+use std::collections::HashSet;
+
+use crate::{DynStemmer, StemError};
+
+/// A stemmer that tries a sequence of stemmers in order.
+///
+/// The result of the first stemmer that changes the word, or whose result is
+/// in the dictionary, is returned. When no stemmer applies, the result of
+/// the last stemmer is returned. This allows hybrid strategies such as a
+/// dictionary lemmatizer backed by an algorithmic stemmer.
+///
+/// # Examples
+///
+/// ```
+/// use polystem::{Algorithm, Chain, DynStemmer};
+///
+/// let chain = Chain::new()
+///     .then(Algorithm::S)
+///     .then(Algorithm::Porter)
+///     .dictionary(vec!["meeting"]);
+///
+/// assert_eq!("fly", chain.stem_word("flies"));
+/// assert_eq!("rust", chain.stem_word("rusted"));
+/// assert_eq!("meeting", chain.stem_word("meeting"));
+/// ```
+#[derive(Default)]
+pub struct Chain {
+    stemmers: Vec<Box<dyn DynStemmer>>,
+    dictionary: HashSet<String>,
+}
+
+impl Chain {
+    /// Construct an empty `Chain`, which returns words unchanged.
+    pub fn new() -> Chain {
+        Chain::default()
+    }
+
+    /// Add `stemmer` to the end of the chain.
+    pub fn then<D: DynStemmer + 'static>(mut self, stemmer: D) -> Chain {
+        self.stemmers.push(Box::new(stemmer));
+        self
+    }
+
+    /// Add `words` to the dictionary of known stems.
+    pub fn dictionary<I, W>(mut self, words: I) -> Chain
+    where
+        I: IntoIterator<Item = W>,
+        W: Into<String>,
+    {
+        self.dictionary.extend(words.into_iter().map(Into::into));
+        self
+    }
+
+    // Return `true` if `stem` is an acceptable result for `word`.
+    fn accept(&self, word: &str, stem: &str) -> bool {
+        !stem.eq_ignore_ascii_case(word) || self.dictionary.contains(stem)
+    }
+}
+
+impl DynStemmer for Chain {
+    fn stem_word(&self, word: &str) -> String {
+        let mut last = None;
+        for stemmer in &self.stemmers {
+            let stem = stemmer.stem_word(word);
+            if self.accept(word, &stem) {
+                return stem;
+            }
+            last = Some(stem);
+        }
+
+        last.unwrap_or_else(|| String::from(word))
+    }
+
+    fn try_stem_word(&self, word: &str) -> Result<String, StemError> {
+        let mut last = None;
+        for stemmer in &self.stemmers {
+            let stem = stemmer.try_stem_word(word);
+            if let Ok(stem) = &stem {
+                if self.accept(word, stem) {
+                    return Ok(stem.to_owned());
+                }
+            }
+            last = Some(stem);
+        }
+
+        last.unwrap_or_else(|| StemError::check(word).map(|_| word.into()))
+    }
+}
+
+#[cfg(all(test, feature = "english"))]
+mod tests {
+    use super::*;
+    use crate::{Porter, S};
+
+    #[test]
+    fn test_chain() {
+        let chain = Chain::new();
+        assert_eq!(chain.stem_word("flies"), "flies");
+        assert_eq!(chain.try_stem_word(""), Err(StemError::Empty));
+
+        let chain = Chain::new().then(S).then(Porter::default());
+        assert_eq!(chain.stem_word("suns"), "sun");
+        assert_eq!(chain.stem_word("hopping"), "hop");
+        assert_eq!(chain.stem_word("sun"), "sun");
+        assert_eq!(chain.try_stem_word("hopping"), Ok("hop".into()));
+        assert_eq!(chain.try_stem_word("café"), Err(StemError::NonAscii));
+
+        let chain = Chain::new().then(S).then(Porter::default());
+        let chain = chain.dictionary(vec![String::from("connecting")]);
+        assert_eq!(chain.stem_word("connecting"), "connecting");
+        assert_eq!(chain.stem_word("connected"), "connect");
+    }
+}
@@ -0,0 +1,343 @@
+// Copyright 2019 The Polystem authors.
+//
+// For the full copyright and license information, please view the LICENSE file
+// that was distributed with this source code.
+
+use std::collections::btree_map;
+use std::collections::BTreeMap;
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader};
+use std::iter::FromIterator;
+use std::marker::PhantomData;
+use std::path::Path;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{Case, DynStemmer, Stemmer};
+
+/// The surface forms observed for a stem, with the number of times each form
+/// was seen.
+pub type Forms = BTreeMap<String, usize>;
+
+/// A map from each stem to the surface forms that were conflated to it.
+///
+/// A `ConflationIndex` is built from a stream of tokens and is useful for
+/// query expansion, or for checking how aggressively a stemmer conflates
+/// words.
+///
+/// # Examples
+///
+/// ```
+/// use polystem::{ConflationIndex, Porter};
+///
+/// let mut index = ConflationIndex::<Porter>::new();
+/// index.extend(vec!["connect", "connected", "connecting", "connected"]);
+///
+/// let forms = index.forms("connect").unwrap();
+/// assert_eq!(3, forms.len());
+/// assert_eq!(Some(&2), forms.get("connected"));
+/// ```
+#[derive(Clone, Debug)]
+pub struct ConflationIndex<T> {
+    map: ConflationMap,
+    stemmer: PhantomData<T>,
+}
+
+impl<T: Stemmer> ConflationIndex<T> {
+    /// Construct an empty `ConflationIndex`.
+    pub fn new() -> ConflationIndex<T> {
+        ConflationIndex {
+            map: ConflationMap::new(),
+            stemmer: PhantomData,
+        }
+    }
+
+    /// Stem `token` and count it as a surface form of its stem.
+    pub fn insert(&mut self, token: &str) {
+        self.map.insert(&T::stem(token), token);
+    }
+
+    /// Return the surface forms seen for `stem`.
+    pub fn forms(&self, stem: &str) -> Option<&Forms> {
+        self.map.forms(stem)
+    }
+
+    /// Iterate over each stem and its surface forms, ordered by stem.
+    pub fn iter(&self) -> btree_map::Iter<'_, String, Forms> {
+        self.map.iter()
+    }
+
+    /// Return the number of distinct stems.
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Return `true` if no tokens have been inserted.
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+}
+
+impl<T: Stemmer> Default for ConflationIndex<T> {
+    fn default() -> ConflationIndex<T> {
+        ConflationIndex::new()
+    }
+}
+
+impl<'a, T: Stemmer> Extend<&'a str> for ConflationIndex<T> {
+    fn extend<I: IntoIterator<Item = &'a str>>(&mut self, tokens: I) {
+        for token in tokens {
+            self.insert(token);
+        }
+    }
+}
+
+impl<'a, T: Stemmer> FromIterator<&'a str> for ConflationIndex<T> {
+    fn from_iter<I: IntoIterator<Item = &'a str>>(
+        tokens: I,
+    ) -> ConflationIndex<T> {
+        let mut index = ConflationIndex::new();
+        index.extend(tokens);
+        index
+    }
+}
+
+impl<'a, T> IntoIterator for &'a ConflationIndex<T> {
+    type Item = (&'a String, &'a Forms);
+    type IntoIter = btree_map::Iter<'a, String, Forms>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.map.iter()
+    }
+}
+
+impl<T> From<ConflationIndex<T>> for ConflationMap {
+    fn from(index: ConflationIndex<T>) -> ConflationMap {
+        index.map
+    }
+}
+
+/// A map from each stem to the surface forms that were conflated to it, built
+/// with a stemmer chosen at runtime, such as from the words of a corpus.
+///
+/// Unlike a [`ConflationIndex`], a `ConflationMap` does not know its stemmer,
+/// which is given to each of the methods adding text. With the `serde`
+/// feature it serializes as a map from each stem to a map from each form to
+/// its count, for query expansion tables and for auditing a stemmer.
+///
+/// # Examples
+///
+/// ```
+/// use polystem::{Algorithm, ConflationMap};
+///
+/// let mut map = ConflationMap::new();
+/// map.add_text(&Algorithm::Porter, "Connected, connecting: they connected.");
+///
+/// let forms = map.forms("connect").unwrap();
+/// assert_eq!(Some(&2), forms.get("connected"));
+/// assert_eq!(Some(&1), forms.get("connecting"));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+pub struct ConflationMap {
+    classes: BTreeMap<String, Forms>,
+}
+
+impl ConflationMap {
+    /// Construct an empty `ConflationMap`.
+    pub fn new() -> ConflationMap {
+        ConflationMap::default()
+    }
+
+    /// Count `form` as a surface form of `stem`.
+    pub fn insert(&mut self, stem: &str, form: &str) {
+        if !self.classes.contains_key(stem) {
+            self.classes.insert(stem.to_owned(), Forms::new());
+        }
+        let forms = self.classes.get_mut(stem).unwrap();
+        *forms.entry(form.to_owned()).or_insert(0) += 1;
+    }
+
+    /// Stem the words of `text` with `stemmer` and count each as a surface
+    /// form of its stem.
+    ///
+    /// Words are runs of letters, digits and inner apostrophes, and are
+    /// lowercased, so that `"Ponies"` and `"ponies"` count as one form.
+    pub fn add_text<D: DynStemmer + ?Sized>(
+        &mut self,
+        stemmer: &D,
+        text: &str,
+    ) {
+        let words = text
+            .split(|c: char| !c.is_alphanumeric() && c != '\'')
+            .map(|word| word.trim_matches('\''))
+            .filter(|word| !word.is_empty());
+        for word in words {
+            let word = Case::Lowercase.fold(word);
+            self.insert(&stemmer.stem_word(&word), &word);
+        }
+    }
+
+    /// Add the text read from `reader`, a line at a time, as by
+    /// [`add_text`](ConflationMap::add_text).
+    pub fn add_reader<D, R>(
+        &mut self,
+        stemmer: &D,
+        reader: R,
+    ) -> io::Result<()>
+    where
+        D: DynStemmer + ?Sized,
+        R: BufRead,
+    {
+        for line in reader.lines() {
+            self.add_text(stemmer, &line?);
+        }
+        Ok(())
+    }
+
+    /// Add the text of the file at `path`, or of every file below it if it
+    /// is a directory, as by [`add_text`](ConflationMap::add_text).
+    ///
+    /// Directories are walked in the order of their file names, skipping
+    /// hidden files and directories, whose names start with a period, and
+    /// symbolic links to directories. The files must be UTF-8, and errors
+    /// name the file they occurred in.
+    pub fn add_path<D, P>(&mut self, stemmer: &D, path: P) -> io::Result<()>
+    where
+        D: DynStemmer + ?Sized,
+        P: AsRef<Path>,
+    {
+        let path = path.as_ref();
+        let annotate = |e: io::Error| {
+            io::Error::new(e.kind(), format!("{}: {}", path.display(), e))
+        };
+        if !path.is_dir() {
+            let file = File::open(path).map_err(annotate)?;
+            return self
+                .add_reader(stemmer, BufReader::new(file))
+                .map_err(annotate);
+        }
+
+        let mut entries: Vec<_> = fs::read_dir(path)
+            .and_then(|entries| {
+                entries.map(|entry| entry.map(|e| e.path())).collect()
+            })
+            .map_err(annotate)?;
+        entries.sort();
+        for entry in entries {
+            let hidden = entry
+                .file_name()
+                .is_some_and(|name| name.to_string_lossy().starts_with('.'));
+            if hidden || entry.is_symlink() && entry.is_dir() {
+                continue;
+            }
+            self.add_path(stemmer, &entry)?;
+        }
+        Ok(())
+    }
+
+    /// Add the stems and forms of `other`, built from other text, for
+    /// building the map of the parts of a corpus separately.
+    pub fn merge(&mut self, other: ConflationMap) {
+        for (stem, forms) in other.classes {
+            let total = self.classes.entry(stem).or_default();
+            for (form, count) in forms {
+                *total.entry(form).or_insert(0) += count;
+            }
+        }
+    }
+
+    /// Return the surface forms seen for `stem`.
+    pub fn forms(&self, stem: &str) -> Option<&Forms> {
+        self.classes.get(stem)
+    }
+
+    /// Iterate over each stem and its surface forms, ordered by stem.
+    pub fn iter(&self) -> btree_map::Iter<'_, String, Forms> {
+        self.classes.iter()
+    }
+
+    /// Return the number of distinct stems.
+    pub fn len(&self) -> usize {
+        self.classes.len()
+    }
+
+    /// Return `true` if no forms have been added.
+    pub fn is_empty(&self) -> bool {
+        self.classes.is_empty()
+    }
+}
+
+impl<'a> IntoIterator for &'a ConflationMap {
+    type Item = (&'a String, &'a Forms);
+    type IntoIter = btree_map::Iter<'a, String, Forms>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.classes.iter()
+    }
+}
+
+#[cfg(all(test, feature = "s"))]
+mod tests {
+    use super::*;
+    use crate::S;
+
+    #[test]
+    fn test_conflation_index() {
+        let text = "flies fly flies suns sun";
+        let index: ConflationIndex<S> = text.split(' ').collect();
+
+        assert_eq!(index.len(), 2);
+        let stems: Vec<&String> = index.iter().map(|(s, _)| s).collect();
+        assert_eq!(stems, vec!["fly", "sun"]);
+
+        let forms = index.forms("fly").unwrap();
+        assert_eq!(forms.get("flies"), Some(&2));
+        assert_eq!(forms.get("fly"), Some(&1));
+        assert_eq!(index.forms("flies"), None);
+
+        let map = ConflationMap::from(index);
+        assert_eq!(map.forms("sun").unwrap().get("suns"), Some(&1));
+    }
+
+    #[test]
+    fn test_conflation_map() {
+        let mut map = ConflationMap::new();
+        map.add_text(&S, "Flies, the fly's 'flies'; suns\n");
+        assert_eq!(map.forms("fly").unwrap().get("flies"), Some(&2));
+        assert_eq!(map.forms("fly'").unwrap().get("fly's"), Some(&1));
+        assert_eq!(map.forms("the").unwrap().get("the"), Some(&1));
+
+        let mut other = ConflationMap::new();
+        other.add_reader(&S, "suns\nsun\n".as_bytes()).unwrap();
+        map.merge(other);
+        let sun = map.forms("sun").unwrap();
+        assert_eq!(sun.get("suns"), Some(&2));
+        assert_eq!(sun.get("sun"), Some(&1));
+        assert_eq!(map.len(), 4);
+    }
+
+    #[test]
+    fn test_add_path() {
+        let dir = std::env::temp_dir()
+            .join(format!("polystem-conflation-{}", std::process::id()));
+        fs::create_dir_all(dir.join("nested")).unwrap();
+        fs::create_dir_all(dir.join(".hidden")).unwrap();
+        fs::write(dir.join("a.txt"), "flies").unwrap();
+        fs::write(dir.join("nested").join("b.txt"), "Flies fly").unwrap();
+        fs::write(dir.join(".hidden").join("c.txt"), "suns").unwrap();
+
+        let mut map = ConflationMap::new();
+        map.add_path(&S, &dir).unwrap();
+        let missing = map.add_path(&S, dir.join("missing"));
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(map.len(), 1);
+        assert_eq!(map.forms("fly").unwrap().get("flies"), Some(&2));
+        let e = missing.unwrap_err();
+        assert_eq!(e.kind(), io::ErrorKind::NotFound);
+        assert!(e.to_string().contains("missing"));
+    }
+}
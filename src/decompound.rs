@@ -0,0 +1,210 @@
+// Copyright 2019 The Polystem authors.
+//
+// For the full copyright and license information, please view the LICENSE file
+// that was distributed with this source code.
+
+use std::collections::HashSet;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::Case;
+
+/// Which parts of a compound a [`Decompounder`] returns.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum Overlap {
+    /// Split the compound into the fewest known words that together make
+    /// it up, as `"sunflowerseed"` into `"sunflower"` and `"seed"`. A word
+    /// that is not made up entirely of known words is not split.
+    #[default]
+    Disjoint,
+    /// Return every known word found inside the compound, overlapping or
+    /// not, in the order they start: `"sun"`, `"sunflower"`, `"flower"`,
+    /// `"lower"` and `"seed"`. Recall is higher, precision lower.
+    All,
+}
+
+/// A splitter of compound words into the words of a vocabulary, for
+/// languages such as German and Dutch that write compounds as one word.
+///
+/// The vocabulary and the words are compared in lowercase, and parts are
+/// returned in lowercase. Parts shorter than the minimum length, three
+/// characters by default, are not split off.
+///
+/// # Examples
+///
+/// ```
+/// use polystem::{Decompounder, Overlap};
+///
+/// let vocabulary = vec!["wind", "kraft", "werk", "kraftwerk"];
+/// let decompounder = Decompounder::new(vocabulary);
+///
+/// assert_eq!(vec!["wind", "kraftwerk"], decompounder.split("Windkraftwerk"));
+/// assert!(decompounder.split("Windrad").is_empty());
+///
+/// let decompounder = decompounder.overlap(Overlap::All);
+/// assert_eq!(
+///     vec!["wind", "kraft", "kraftwerk", "werk"],
+///     decompounder.split("windkraftwerk")
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Decompounder {
+    vocabulary: HashSet<String>,
+    // The number of characters of the longest word of the vocabulary.
+    max_part: usize,
+    min_part: usize,
+    overlap: Overlap,
+}
+
+impl Decompounder {
+    /// Construct a `Decompounder` splitting words into the words of
+    /// `vocabulary`.
+    pub fn new<I>(vocabulary: I) -> Decompounder
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let vocabulary: HashSet<_> = vocabulary
+            .into_iter()
+            .map(|word| Case::Lowercase.fold(word.as_ref()).into_owned())
+            .collect();
+        let max_part = vocabulary
+            .iter()
+            .map(|word| word.chars().count())
+            .max()
+            .unwrap_or(0);
+        Decompounder {
+            vocabulary,
+            max_part,
+            min_part: 3,
+            overlap: Overlap::default(),
+        }
+    }
+
+    /// Do not split off parts of fewer than `chars` characters.
+    pub fn min_part(mut self, chars: usize) -> Decompounder {
+        self.min_part = chars.max(1);
+        self
+    }
+
+    /// Set which parts of a compound are returned.
+    pub fn overlap(mut self, overlap: Overlap) -> Decompounder {
+        self.overlap = overlap;
+        self
+    }
+
+    /// Return the parts of `word`, or nothing if it is not a compound of
+    /// the words of the vocabulary.
+    pub fn split(&self, word: &str) -> Vec<String> {
+        let word = Case::Lowercase.fold(word);
+        let mut bounds: Vec<_> = word.char_indices().map(|(i, _)| i).collect();
+        bounds.push(word.len());
+        match self.overlap {
+            Overlap::Disjoint => self.split_disjoint(&word, &bounds),
+            Overlap::All => self.split_all(&word, &bounds),
+        }
+    }
+
+    // Return `true` if the characters of `word` between the bounds `i` and
+    // `j` are a part.
+    fn is_part(
+        &self,
+        word: &str,
+        bounds: &[usize],
+        i: usize,
+        j: usize,
+    ) -> bool {
+        let len = j - i;
+        len >= self.min_part
+            && len <= self.max_part
+            && self.vocabulary.contains(&word[bounds[i]..bounds[j]])
+    }
+
+    // Split `word`, with characters starting at `bounds`, into the fewest
+    // parts that make it up.
+    fn split_disjoint(&self, word: &str, bounds: &[usize]) -> Vec<String> {
+        let n = bounds.len() - 1;
+        // The fewest parts making up the first `j` characters, and the start
+        // of the last of them.
+        let mut best: Vec<Option<(usize, usize)>> = vec![None; n + 1];
+        best[0] = Some((0, 0));
+        for j in 1..=n {
+            for i in (0..j).rev() {
+                let parts = match best[i] {
+                    Some((parts, _)) => parts,
+                    None => continue,
+                };
+                if !self.is_part(word, bounds, i, j) {
+                    continue;
+                }
+                if best[j].is_none_or(|(fewest, _)| parts + 1 < fewest) {
+                    best[j] = Some((parts + 1, i));
+                }
+            }
+        }
+
+        match best[n] {
+            Some((parts, _)) if parts > 1 => {}
+            _ => return Vec::new(),
+        }
+        let mut out = Vec::new();
+        let mut j = n;
+        while j > 0 {
+            let (_, i) = best[j].unwrap();
+            out.push(word[bounds[i]..bounds[j]].to_owned());
+            j = i;
+        }
+        out.reverse();
+        out
+    }
+
+    // Return every part found inside `word`, with characters starting at
+    // `bounds`, other than the whole word.
+    fn split_all(&self, word: &str, bounds: &[usize]) -> Vec<String> {
+        let n = bounds.len() - 1;
+        let mut out = Vec::new();
+        for i in 0..n {
+            for j in i + 1..=n.min(i + self.max_part) {
+                if (i, j) != (0, n) && self.is_part(word, bounds, i, j) {
+                    out.push(word[bounds[i]..bounds[j]].to_owned());
+                }
+            }
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_disjoint() {
+        let decompounder =
+            Decompounder::new(vec!["Sun", "flower", "sunflower", "seed"]);
+        assert_eq!(decompounder.split("sunflowerseed"), ["sunflower", "seed"]);
+        assert_eq!(decompounder.split("SunFlowerSeeds"), Vec::<String>::new());
+        assert!(decompounder.split("sunflower").is_empty());
+        assert!(decompounder.split("").is_empty());
+
+        let decompounder = Decompounder::new(vec!["öl", "tanker", "ölt"]);
+        assert!(decompounder.split("öltanker").is_empty());
+        let decompounder = decompounder.min_part(2);
+        assert_eq!(decompounder.split("Öltanker"), ["öl", "tanker"]);
+    }
+
+    #[test]
+    fn test_split_all() {
+        let vocabulary = vec!["sun", "flower", "lower", "sunflower", "seed"];
+        let decompounder = Decompounder::new(vocabulary).overlap(Overlap::All);
+        assert_eq!(
+            decompounder.split("sunflowers"),
+            ["sun", "sunflower", "flower", "lower"]
+        );
+        assert_eq!(decompounder.split("sunflower").len(), 3);
+        assert!(decompounder.split("rain").is_empty());
+    }
+}
@@ -0,0 +1,264 @@
+// Copyright 2019 The Polystem authors.
+//
+// For the full copyright and license information, please view the LICENSE file
+// that was distributed with this source code.
+
+use std::error;
+use std::fmt;
+use std::io;
+
+use crate::Script;
+
+/// The error returned by [`Stemmer::try_stem`](crate::Stemmer::try_stem)
+/// when a word can not be stemmed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StemError {
+    /// The word is the empty string.
+    Empty,
+    /// The word contains non-ASCII characters. The rules of the stemmers only
+    /// cover ASCII letters, so such words are not stemmed reliably.
+    NonAscii,
+    /// The word contains letters from a script other than Latin.
+    UnsupportedScript,
+}
+
+impl StemError {
+    // Check that `word` can be handed to one of the byte oriented stemmers.
+    pub(crate) fn check(word: &str) -> Result<(), StemError> {
+        if word.is_empty() {
+            return Err(StemError::Empty);
+        }
+        if word.is_ascii() {
+            return Ok(());
+        }
+        if word
+            .chars()
+            .any(|c| !matches!(Script::of(c), None | Some(Script::Latin)))
+        {
+            return Err(StemError::UnsupportedScript);
+        }
+
+        Err(StemError::NonAscii)
+    }
+}
+
+impl fmt::Display for StemError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let msg = match self {
+            StemError::Empty => "cannot stem an empty word",
+            StemError::NonAscii => "cannot stem a word with non-ASCII bytes",
+            StemError::UnsupportedScript => "word is not in the Latin script",
+        };
+        f.write_str(msg)
+    }
+}
+
+impl error::Error for StemError {}
+
+/// The error returned by [`Stemmer::stem_to_buf`](crate::Stemmer::stem_to_buf)
+/// when the buffer is too small for the stem.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BufTooSmall {
+    required: usize,
+}
+
+impl BufTooSmall {
+    pub(crate) fn new(required: usize) -> BufTooSmall {
+        BufTooSmall { required }
+    }
+
+    /// Return the number of bytes the buffer needs.
+    pub fn required(&self) -> usize {
+        self.required
+    }
+}
+
+impl fmt::Display for BufTooSmall {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "buffer too small, {} bytes required", self.required)
+    }
+}
+
+impl error::Error for BufTooSmall {}
+
+/// The error returned when parsing the name of an unknown
+/// [`Algorithm`](crate::Algorithm).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseAlgorithmError {
+    name: String,
+}
+
+impl ParseAlgorithmError {
+    pub(crate) fn new(name: &str) -> ParseAlgorithmError {
+        ParseAlgorithmError {
+            name: name.to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ParseAlgorithmError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "unknown stemming algorithm `{}`", self.name)
+    }
+}
+
+impl error::Error for ParseAlgorithmError {}
+
+/// The error returned when loading the files of a dictionary backed
+/// stemmer, such as `Hunspell` or `FstLemmatizer`.
+#[derive(Debug)]
+pub enum DictionaryError {
+    /// A file could not be read.
+    Io(io::Error),
+    /// A line of a file is malformed.
+    Parse {
+        /// The name of the file, or its kind when it was not read from a
+        /// path.
+        file: String,
+        /// The line number, from 1.
+        line: usize,
+        message: String,
+    },
+    /// A binary dictionary is malformed.
+    Format(String),
+}
+
+impl DictionaryError {
+    #[cfg_attr(not(feature = "hunspell"), allow(dead_code))]
+    pub(crate) fn parse(file: &str, line: usize, message: &str) -> Self {
+        DictionaryError::Parse {
+            file: file.to_owned(),
+            line,
+            message: message.to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for DictionaryError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DictionaryError::Io(e) => {
+                write!(f, "cannot read dictionary: {}", e)
+            }
+            DictionaryError::Parse {
+                file,
+                line,
+                message,
+            } => write!(f, "{}:{}: {}", file, line, message),
+            DictionaryError::Format(message) => {
+                write!(f, "invalid dictionary: {}", message)
+            }
+        }
+    }
+}
+
+impl error::Error for DictionaryError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            DictionaryError::Io(e) => Some(e),
+            DictionaryError::Parse { .. } | DictionaryError::Format(_) => None,
+        }
+    }
+}
+
+impl From<io::Error> for DictionaryError {
+    fn from(e: io::Error) -> DictionaryError {
+        DictionaryError::Io(e)
+    }
+}
+
+/// The error returned when reading the groups of words of a
+/// [`GoldGroups`](crate::eval::GoldGroups).
+#[derive(Debug)]
+pub enum GoldError {
+    /// The groups could not be read.
+    Io(io::Error),
+    /// A word is in more than one group.
+    Duplicate {
+        word: String,
+        /// The line number of the second group, from 1, when the groups
+        /// were read.
+        line: Option<usize>,
+    },
+}
+
+impl fmt::Display for GoldError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GoldError::Io(e) => write!(f, "cannot read groups: {}", e),
+            GoldError::Duplicate { word, line } => {
+                if let Some(line) = line {
+                    write!(f, "line {}: ", line)?;
+                }
+                write!(f, "`{}` is in more than one group", word)
+            }
+        }
+    }
+}
+
+impl error::Error for GoldError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            GoldError::Io(e) => Some(e),
+            GoldError::Duplicate { .. } => None,
+        }
+    }
+}
+
+impl From<io::Error> for GoldError {
+    fn from(e: io::Error) -> GoldError {
+        GoldError::Io(e)
+    }
+}
+
+/// The error returned when reading the configuration of a
+/// [`Pipeline`](crate::Pipeline) from a file.
+#[cfg(any(feature = "toml", feature = "yaml"))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ConfigError {
+    /// The file is malformed, or has an unknown or mistyped setting.
+    Syntax(String),
+    /// A setting has an invalid value, such as a regular expression that
+    /// does not compile.
+    Invalid(String),
+}
+
+#[cfg(any(feature = "toml", feature = "yaml"))]
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ConfigError::Syntax(message) => {
+                write!(f, "malformed pipeline configuration: {}", message)
+            }
+            ConfigError::Invalid(message) => {
+                write!(f, "invalid pipeline configuration: {}", message)
+            }
+        }
+    }
+}
+
+#[cfg(any(feature = "toml", feature = "yaml"))]
+impl error::Error for ConfigError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check() {
+        assert_eq!(StemError::check("flies"), Ok(()));
+        assert_eq!(StemError::check(""), Err(StemError::Empty));
+        assert_eq!(StemError::check("café"), Err(StemError::NonAscii));
+        assert_eq!(
+            StemError::check("кошки"),
+            Err(StemError::UnsupportedScript)
+        );
+    }
+
+    #[test]
+    fn test_buf_too_small() {
+        let err = BufTooSmall::new(7);
+        assert_eq!(err.required(), 7);
+        assert_eq!(err.to_string(), "buffer too small, 7 bytes required");
+    }
+}
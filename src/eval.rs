@@ -0,0 +1,394 @@
+// Copyright 2019 The Polystem authors.
+//
+// For the full copyright and license information, please view the LICENSE file
+// that was distributed with this source code.
+
+//! Evaluating stemmers against groups of words known to share a meaning.
+//!
+//! Chris Paice's method ("An evaluation method for stemming algorithms",
+//! SIGIR 1994) counts the pairs of words of the same group a stemmer fails
+//! to merge, and the pairs of words of different groups it merges, giving
+//! an understemming index, an overstemming index and their ratio, the
+//! stemming weight. A light stemmer has a high understemming index and a
+//! low weight, and a heavy one the reverse.
+//!
+//! Two stemmers can also be compared without groups, by [`agreement`], for
+//! the words they stem differently and how their conflation classes differ.
+//!
+//! # Examples
+//!
+//! ```
+//! use polystem::eval::{paice, GoldGroups};
+//! use polystem::{Algorithm, S};
+//!
+//! let gold = "flies fly\nrun runs running\nnew\nnews\n";
+//! let gold = GoldGroups::read(gold.as_bytes()).unwrap();
+//!
+//! let s = paice(&S, &gold);
+//! let porter = paice(&Algorithm::Porter, &gold);
+//!
+//! assert_eq!(0.5, s.understemming_index());
+//! assert_eq!(0.25, porter.understemming_index());
+//! assert!(s.stemming_weight() < porter.stemming_weight());
+//! ```
+
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::io::BufRead;
+
+use crate::{DynStemmer, GoldError};
+
+/// Groups of words, such as the inflections and derivations of a word,
+/// that a stemmer should conflate, and that it should keep apart from the
+/// words of the other groups. Each word is in one group.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct GoldGroups {
+    groups: Vec<Vec<String>>,
+    words: HashSet<String>,
+}
+
+impl GoldGroups {
+    /// Construct an empty `GoldGroups`.
+    pub fn new() -> GoldGroups {
+        GoldGroups::default()
+    }
+
+    /// Read groups with the words of one group on each line, separated by
+    /// whitespace. Blank lines and lines starting with `#` are skipped.
+    pub fn read<R: BufRead>(reader: R) -> Result<GoldGroups, GoldError> {
+        let mut gold = GoldGroups::new();
+        for (i, line) in reader.lines().enumerate() {
+            let line = line?;
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            gold.add_group(line.split_whitespace())
+                .map_err(|e| match e {
+                    GoldError::Duplicate { word, .. } => {
+                        GoldError::Duplicate {
+                            word,
+                            line: Some(i + 1),
+                        }
+                    }
+                    e => e,
+                })?;
+        }
+        Ok(gold)
+    }
+
+    /// Add a group of `words`, unless it is empty. An error is returned,
+    /// and no word added, if a word is already in a group.
+    pub fn add_group<I>(&mut self, words: I) -> Result<(), GoldError>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let mut group: Vec<String> = Vec::new();
+        for word in words {
+            let word = word.as_ref();
+            if self.words.contains(word) || group.iter().any(|w| w == word) {
+                return Err(GoldError::Duplicate {
+                    word: word.to_owned(),
+                    line: None,
+                });
+            }
+            group.push(word.to_owned());
+        }
+        if !group.is_empty() {
+            self.words.extend(group.iter().cloned());
+            self.groups.push(group);
+        }
+        Ok(())
+    }
+
+    /// Return the number of groups.
+    pub fn len(&self) -> usize {
+        self.groups.len()
+    }
+
+    /// Return `true` if there are no groups.
+    pub fn is_empty(&self) -> bool {
+        self.groups.is_empty()
+    }
+
+    /// Return the number of words, in all groups.
+    pub fn words(&self) -> usize {
+        self.words.len()
+    }
+
+    /// Return an iterator over the groups, in the order they were added.
+    pub fn iter(&self) -> impl Iterator<Item = &[String]> {
+        self.groups.iter().map(Vec::as_slice)
+    }
+}
+
+/// The counts of pairs of words of Paice's evaluation of a stemmer,
+/// returned by [`paice`], from which its indices are computed.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Paice {
+    /// The number of pairs of words of the same group (DMT).
+    pub desired_merges: u64,
+    /// The number of pairs of words of the same group with different
+    /// stems (UMT).
+    pub unachieved_merges: u64,
+    /// The number of pairs of words of different groups (DNT).
+    pub desired_non_merges: u64,
+    /// The number of pairs of words of different groups with the same
+    /// stem (WMT).
+    pub wrong_merges: u64,
+}
+
+impl Paice {
+    /// Return the understemming index (UI), the share of the pairs of words
+    /// of the same group that are not merged, or 0 if there are none.
+    pub fn understemming_index(&self) -> f64 {
+        ratio(self.unachieved_merges, self.desired_merges)
+    }
+
+    /// Return the overstemming index (OI), the share of the pairs of words
+    /// of different groups that are merged, or 0 if there are none.
+    pub fn overstemming_index(&self) -> f64 {
+        ratio(self.wrong_merges, self.desired_non_merges)
+    }
+
+    /// Return the stemming weight (SW), the overstemming index divided by
+    /// the understemming index. It is infinite if nothing is understemmed,
+    /// and not a number if nothing is understemmed or overstemmed.
+    pub fn stemming_weight(&self) -> f64 {
+        self.overstemming_index() / self.understemming_index()
+    }
+}
+
+/// Evaluate `stemmer` against the groups of `gold` by Paice's method.
+pub fn paice<D: DynStemmer + ?Sized>(stemmer: &D, gold: &GoldGroups) -> Paice {
+    let mut paice = Paice::default();
+    // The number of words of each group with each stem.
+    let mut classes: HashMap<String, HashMap<usize, u64>> = HashMap::new();
+    let mut squares = 0;
+    for (i, group) in gold.groups.iter().enumerate() {
+        let mut stems: HashMap<String, u64> = HashMap::new();
+        for word in group {
+            let stem = stemmer.stem_word(word);
+            *stems.entry(stem.clone()).or_default() += 1;
+            *classes.entry(stem).or_default().entry(i).or_default() += 1;
+        }
+        let n = group.len() as u64;
+        paice.desired_merges += n * (n - 1) / 2;
+        paice.unachieved_merges += pairs_between(stems.values());
+        squares += n * n;
+    }
+    let words = gold.words() as u64;
+    paice.desired_non_merges = (words * words - squares) / 2;
+    for class in classes.values() {
+        paice.wrong_merges += pairs_between(class.values());
+    }
+    paice
+}
+
+/// A word that two stemmers stem differently, found by [`agreement`].
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Disagreement {
+    /// The word.
+    pub word: String,
+    /// The stem of the first stemmer.
+    pub a: String,
+    /// The stem of the second stemmer.
+    pub b: String,
+}
+
+/// How two stemmers agree on a set of words, returned by [`agreement`].
+///
+/// The conflation classes of a stemmer are the sets of words it stems
+/// alike. Besides the words stemmed differently, the classes of the two
+/// stemmers are compared by their number and by the pairs of words only one
+/// of them merges into a class.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Agreement {
+    /// The number of distinct words compared.
+    pub words: u64,
+    /// The words stemmed differently, ordered by word.
+    pub disagreements: Vec<Disagreement>,
+    /// The number of conflation classes of the first stemmer.
+    pub a_classes: u64,
+    /// The number of conflation classes of the second stemmer.
+    pub b_classes: u64,
+    /// The number of pairs of words merged by the first stemmer only.
+    pub a_only_merges: u64,
+    /// The number of pairs of words merged by the second stemmer only.
+    pub b_only_merges: u64,
+}
+
+impl Agreement {
+    /// Return the share of the words given the same stem by both stemmers,
+    /// or 1 if there are none.
+    pub fn rate(&self) -> f64 {
+        if self.words == 0 {
+            return 1.0;
+        }
+        1.0 - ratio(self.disagreements.len() as u64, self.words)
+    }
+
+    /// Return the number of classes of the second stemmer less those of
+    /// the first, which is negative if the second conflates more.
+    pub fn class_delta(&self) -> i64 {
+        self.b_classes as i64 - self.a_classes as i64
+    }
+}
+
+/// Compare the stems of `words` by the stemmers `a` and `b`. Repeated words
+/// are compared once.
+///
+/// # Examples
+///
+/// ```
+/// use polystem::eval::agreement;
+/// use polystem::{Algorithm, S};
+///
+/// let words = vec!["flies", "fly", "ponies", "pony", "cats", "cat"];
+/// let agreement = agreement(&S, &Algorithm::Porter, words);
+///
+/// assert_eq!(0.5, agreement.rate());
+/// let words: Vec<_> =
+///     agreement.disagreements.iter().map(|d| d.word.as_str()).collect();
+/// assert_eq!(vec!["flies", "ponies", "pony"], words);
+/// assert_eq!((3, 4), (agreement.a_classes, agreement.b_classes));
+/// ```
+pub fn agreement<A, B, I>(a: &A, b: &B, words: I) -> Agreement
+where
+    A: DynStemmer + ?Sized,
+    B: DynStemmer + ?Sized,
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    let words: BTreeSet<String> =
+        words.into_iter().map(|w| w.as_ref().to_owned()).collect();
+    let mut agreement = Agreement {
+        words: words.len() as u64,
+        ..Agreement::default()
+    };
+    // The number of words of each stem, and of each pair of stems.
+    let mut a_sizes: HashMap<String, u64> = HashMap::new();
+    let mut b_sizes: HashMap<String, u64> = HashMap::new();
+    let mut both_sizes: HashMap<(String, String), u64> = HashMap::new();
+    for word in words {
+        let (a_stem, b_stem) = (a.stem_word(&word), b.stem_word(&word));
+        *a_sizes.entry(a_stem.clone()).or_default() += 1;
+        *b_sizes.entry(b_stem.clone()).or_default() += 1;
+        if a_stem != b_stem {
+            agreement.disagreements.push(Disagreement {
+                word,
+                a: a_stem.clone(),
+                b: b_stem.clone(),
+            });
+        }
+        *both_sizes.entry((a_stem, b_stem)).or_default() += 1;
+    }
+    let both = pairs_within(both_sizes.values());
+    agreement.a_classes = a_sizes.len() as u64;
+    agreement.b_classes = b_sizes.len() as u64;
+    agreement.a_only_merges = pairs_within(a_sizes.values()) - both;
+    agreement.b_only_merges = pairs_within(b_sizes.values()) - both;
+    agreement
+}
+
+// Return the number of pairs of items of the same part of a set split into
+// parts of `sizes`.
+fn pairs_within<'a, I: Iterator<Item = &'a u64>>(sizes: I) -> u64 {
+    sizes.map(|&n| n * n.saturating_sub(1) / 2).sum()
+}
+
+// Return the number of pairs of items of different parts of a set split
+// into parts of `sizes`.
+fn pairs_between<'a, I: Iterator<Item = &'a u64>>(sizes: I) -> u64 {
+    let (total, squares) = sizes
+        .fold((0, 0), |(total, squares), &n| (total + n, squares + n * n));
+    (total * total - squares) / 2
+}
+
+// Return `n / d`, or 0 if `d` is 0.
+fn ratio(n: u64, d: u64) -> f64 {
+    if d == 0 {
+        return 0.0;
+    }
+    n as f64 / d as f64
+}
+
+#[cfg(all(test, feature = "english"))]
+mod tests {
+    use super::*;
+    use crate::{Algorithm, S};
+
+    #[test]
+    fn test_gold_groups() {
+        let text = "# groups\nflies fly\n\n  run runs running \nnew\n";
+        let gold = GoldGroups::read(text.as_bytes()).unwrap();
+        assert_eq!(gold.len(), 3);
+        assert_eq!(gold.words(), 6);
+        let groups: Vec<_> = gold.iter().collect();
+        assert_eq!(groups[1], ["run", "runs", "running"]);
+
+        let e = GoldGroups::read("a b\nc a\n".as_bytes()).unwrap_err();
+        assert_eq!(e.to_string(), "line 2: `a` is in more than one group");
+        let mut gold = GoldGroups::new();
+        assert!(gold.add_group(Vec::<String>::new()).is_ok());
+        assert!(gold.is_empty());
+        let e = gold.add_group(vec!["x", "y", "x"]).unwrap_err();
+        assert_eq!(e.to_string(), "`x` is in more than one group");
+        assert_eq!(gold.words(), 0);
+    }
+
+    #[test]
+    fn test_paice() {
+        let gold = "flies fly\nrun runs running\nnew\nnews\n";
+        let gold = GoldGroups::read(gold.as_bytes()).unwrap();
+        let scores = paice(&S, &gold);
+        assert_eq!(
+            scores,
+            Paice {
+                desired_merges: 4,
+                unachieved_merges: 2,
+                desired_non_merges: 17,
+                wrong_merges: 1,
+            }
+        );
+        assert_eq!(scores.understemming_index(), 0.5);
+        assert_eq!(scores.overstemming_index(), 1.0 / 17.0);
+        assert_eq!(scores.stemming_weight(), 2.0 / 17.0);
+
+        let empty = paice(&S, &GoldGroups::new());
+        assert_eq!(empty.understemming_index(), 0.0);
+        assert!(empty.stemming_weight().is_nan());
+    }
+
+    #[test]
+    fn test_agreement() {
+        let words = "flies fly suns sun sun rusted rust rusting";
+        let words: Vec<_> = words.split(' ').collect();
+        let stemmers: [&dyn DynStemmer; 2] = [&S, &Algorithm::Porter];
+        let agreement = agreement(stemmers[0], stemmers[1], &words);
+        assert_eq!(agreement.words, 7);
+        let stems: Vec<_> = agreement
+            .disagreements
+            .iter()
+            .map(|d| (d.word.as_str(), d.a.as_str(), d.b.as_str()))
+            .collect();
+        assert_eq!(
+            stems,
+            [
+                ("flies", "fly", "fli"),
+                ("rusted", "rusted", "rust"),
+                ("rusting", "rusting", "rust"),
+            ]
+        );
+        assert_eq!(agreement.rate(), 1.0 - 3.0 / 7.0);
+        assert_eq!((agreement.a_classes, agreement.b_classes), (5, 4));
+        assert_eq!(agreement.class_delta(), -1);
+        assert_eq!((agreement.a_only_merges, agreement.b_only_merges), (1, 3));
+
+        let same = super::agreement(&S, &S, &words);
+        assert!(same.disagreements.is_empty());
+        assert_eq!(same.rate(), 1.0);
+        assert_eq!((same.a_only_merges, same.b_only_merges), (0, 0));
+        assert_eq!(super::agreement(&S, &S, Vec::<&str>::new()).rate(), 1.0);
+    }
+}
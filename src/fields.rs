@@ -0,0 +1,272 @@
+// Copyright 2019 The Polystem authors.
+//
+// For the full copyright and license information, please view the LICENSE file
+// that was distributed with this source code.
+
+use std::collections::HashMap;
+
+#[cfg(feature = "serde_json")]
+use serde_json::{Map, Value};
+
+use crate::tokenize::{Token, TokenKind};
+use crate::Pipeline;
+
+// How the values of a field are analyzed.
+#[derive(Clone, Debug)]
+enum Analyzer {
+    Pipeline(Pipeline),
+    // The whole value is one token, as for identifiers.
+    Keyword,
+}
+
+impl Analyzer {
+    fn analyze(&self, text: &str) -> Vec<Token> {
+        match self {
+            Analyzer::Pipeline(pipeline) => pipeline.analyze(text),
+            Analyzer::Keyword if text.is_empty() => Vec::new(),
+            Analyzer::Keyword => vec![Token {
+                term: text.to_owned(),
+                kind: TokenKind::Stem,
+                start: 0,
+                end: text.len(),
+            }],
+        }
+    }
+}
+
+/// The analyzers of the fields of structured records, such as the
+/// documents of a search index, with a [`Pipeline`] for each field.
+///
+/// A field may also be a keyword field, whose whole value is a single
+/// token, as suits identifiers and tags. Fields with no analyzer of their
+/// own are analyzed by the [`fallback`](FieldAnalyzers::fallback)
+/// pipeline, or else not at all.
+///
+/// # Examples
+///
+/// ```
+/// use polystem::{Algorithm, FieldAnalyzers, Pipeline};
+///
+/// let mut analyzers = FieldAnalyzers::new();
+/// analyzers.insert("title", Pipeline::new().lowercase().stem(Algorithm::S));
+/// analyzers.insert("body", Pipeline::new().stem(Algorithm::Porter));
+/// analyzers.keyword("id");
+///
+/// let record = vec![
+///     ("id", "PONY-42"),
+///     ("title", "Ponies"),
+///     ("body", "rusted gates"),
+///     ("color", "grey"),
+/// ];
+/// let fields = analyzers.analyze_record(record);
+/// let terms: Vec<_> = fields
+///     .iter()
+///     .map(|(field, tokens)| {
+///         let terms = tokens.iter().map(|t| t.term.as_str());
+///         (*field, terms.collect::<Vec<_>>())
+///     })
+///     .collect();
+///
+/// assert_eq!(
+///     vec![
+///         ("id", vec!["PONY-42"]),
+///         ("title", vec!["pony"]),
+///         ("body", vec!["rust", "gate"]),
+///     ],
+///     terms
+/// );
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct FieldAnalyzers {
+    fields: HashMap<String, Analyzer>,
+    fallback: Option<Analyzer>,
+}
+
+impl FieldAnalyzers {
+    /// Construct a `FieldAnalyzers` with no fields.
+    pub fn new() -> FieldAnalyzers {
+        FieldAnalyzers::default()
+    }
+
+    /// Analyze the fields with no analyzer of their own with `pipeline`.
+    pub fn fallback(mut self, pipeline: Pipeline) -> FieldAnalyzers {
+        self.fallback = Some(Analyzer::Pipeline(pipeline));
+        self
+    }
+
+    /// Analyze the values of `field` with `pipeline`, replacing an earlier
+    /// analyzer of `field`.
+    pub fn insert(&mut self, field: &str, pipeline: Pipeline) {
+        let analyzer = Analyzer::Pipeline(pipeline);
+        self.fields.insert(field.to_owned(), analyzer);
+    }
+
+    /// Make `field` a keyword field, whose whole value is a single token,
+    /// replacing an earlier analyzer of `field`.
+    pub fn keyword(&mut self, field: &str) {
+        self.fields.insert(field.to_owned(), Analyzer::Keyword);
+    }
+
+    /// Return the pipeline of `field`, which is the fallback pipeline if
+    /// the field has no analyzer of its own, or nothing if it is a keyword
+    /// field or is not analyzed.
+    pub fn pipeline(&self, field: &str) -> Option<&Pipeline> {
+        match self.analyzer(field)? {
+            Analyzer::Pipeline(pipeline) => Some(pipeline),
+            Analyzer::Keyword => None,
+        }
+    }
+
+    // Return the analyzer of `field`, if it is analyzed.
+    fn analyzer(&self, field: &str) -> Option<&Analyzer> {
+        self.fields.get(field).or(self.fallback.as_ref())
+    }
+
+    /// Analyze `text`, the value of `field`, or return `None` if the field
+    /// is not analyzed.
+    pub fn analyze(&self, field: &str, text: &str) -> Option<Vec<Token>> {
+        self.analyzer(field).map(|analyzer| analyzer.analyze(text))
+    }
+
+    /// Analyze `text`, the value of `field`, returning only the analyzed
+    /// words, or `None` if the field is not analyzed.
+    pub fn terms(&self, field: &str, text: &str) -> Option<Vec<String>> {
+        let tokens = self.analyze(field, text)?;
+        Some(tokens.into_iter().map(|t| t.term).collect())
+    }
+
+    /// Analyze the fields of `record`, pairs of a field name and its value
+    /// such as the header and the columns of a CSV row, returning the
+    /// tokens of each analyzed field in the order of the record.
+    pub fn analyze_record<I, K, V>(&self, record: I) -> Vec<(K, Vec<Token>)>
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: AsRef<str>,
+        V: AsRef<str>,
+    {
+        record
+            .into_iter()
+            .filter_map(|(field, value)| {
+                let tokens = self.analyze(field.as_ref(), value.as_ref())?;
+                Some((field, tokens))
+            })
+            .collect()
+    }
+
+    /// Analyze the fields of the JSON object `record`, returning an object
+    /// with the terms of each analyzed field as an array.
+    ///
+    /// Strings and arrays of strings are analyzed, and the terms of the
+    /// strings of an array are concatenated. The other values of keyword
+    /// fields, such as numeric identifiers, are their JSON text, and those
+    /// of other fields, as well as nested objects, are not analyzed. A
+    /// `record` that is not an object has no fields.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polystem::{Algorithm, FieldAnalyzers, Pipeline};
+    /// use serde_json::json;
+    ///
+    /// let mut analyzers = FieldAnalyzers::new()
+    ///     .fallback(Pipeline::new().stem(Algorithm::Porter));
+    /// analyzers.keyword("id");
+    ///
+    /// let record = json!({"id": 42, "tags": ["ponies", "rusted"]});
+    /// assert_eq!(
+    ///     json!({"id": ["42"], "tags": ["poni", "rust"]}),
+    ///     analyzers.analyze_json(&record)
+    /// );
+    /// ```
+    #[cfg(feature = "serde_json")]
+    pub fn analyze_json(&self, record: &Value) -> Value {
+        let mut out = Map::new();
+        let fields = match record {
+            Value::Object(fields) => fields,
+            _ => return Value::Object(out),
+        };
+        for (field, value) in fields {
+            let analyzer = match self.analyzer(field) {
+                Some(analyzer) => analyzer,
+                None => continue,
+            };
+            let keyword = matches!(analyzer, Analyzer::Keyword);
+            let texts: Vec<String> = match value {
+                Value::String(text) => vec![text.clone()],
+                Value::Array(values) => values
+                    .iter()
+                    .filter_map(|v| v.as_str().map(str::to_owned))
+                    .collect(),
+                Value::Object(_) | Value::Null => continue,
+                other if keyword => vec![other.to_string()],
+                _ => continue,
+            };
+            let terms = texts
+                .iter()
+                .flat_map(|text| analyzer.analyze(text))
+                .map(|token| Value::String(token.term))
+                .collect();
+            out.insert(field.clone(), Value::Array(terms));
+        }
+        Value::Object(out)
+    }
+}
+
+#[cfg(all(test, feature = "english"))]
+mod tests {
+    use super::*;
+    use crate::Algorithm;
+
+    #[test]
+    fn test_field_analyzers() {
+        let mut analyzers = FieldAnalyzers::new();
+        analyzers.insert("title", Pipeline::new().stem(Algorithm::S));
+        analyzers.keyword("id");
+        assert_eq!(
+            analyzers.terms("title", "Flies"),
+            Some(vec!["fly".into()])
+        );
+        assert_eq!(analyzers.terms("id", "a b"), Some(vec!["a b".into()]));
+        assert_eq!(analyzers.terms("id", ""), Some(vec![]));
+        assert_eq!(analyzers.terms("body", "flies"), None);
+        assert!(analyzers.pipeline("title").is_some());
+        assert!(analyzers.pipeline("id").is_none());
+
+        let tokens = analyzers.analyze("id", "x-1").unwrap();
+        assert_eq!(tokens[0].range(), 0..3);
+
+        analyzers.insert("id", Pipeline::new());
+        let analyzers = analyzers.fallback(Pipeline::new().lowercase());
+        assert_eq!(analyzers.terms("id", "a b").unwrap(), ["a", "b"]);
+        assert_eq!(analyzers.terms("body", "Flies").unwrap(), ["flies"]);
+        let record = analyzers.analyze_record(vec![
+            (String::from("body"), "A"),
+            (String::from("title"), "suns"),
+        ]);
+        let fields: Vec<_> =
+            record.iter().map(|(f, t)| (f.as_str(), t.len())).collect();
+        assert_eq!(fields, vec![("body", 1), ("title", 1)]);
+    }
+
+    #[cfg(feature = "serde_json")]
+    #[test]
+    fn test_analyze_json() {
+        use serde_json::json;
+
+        let mut analyzers = FieldAnalyzers::new();
+        analyzers.insert("body", Pipeline::new().stem(Algorithm::S));
+        analyzers.keyword("id");
+        analyzers.keyword("meta");
+        let record = json!({
+            "id": 7,
+            "body": ["cats", 3, "suns"],
+            "meta": {"a": "b"},
+            "other": "x",
+        });
+        assert_eq!(
+            analyzers.analyze_json(&record),
+            json!({"id": ["7"], "body": ["cat", "sun"]})
+        );
+        assert_eq!(analyzers.analyze_json(&json!("body")), json!({}));
+    }
+}
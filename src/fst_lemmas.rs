@@ -0,0 +1,255 @@
+// Copyright 2019 The Polystem authors.
+//
+// For the full copyright and license information, please view the LICENSE file
+// that was distributed with this source code.
+
+use std::collections::{BTreeMap, HashMap};
+use std::convert::{TryFrom, TryInto};
+use std::fs;
+use std::io::{self, Write};
+use std::path::Path;
+
+use fst::{Map, MapBuilder};
+
+use crate::{DictionaryError, DynStemmer, StemError};
+
+// The start of a dictionary file, with the version of the format.
+const MAGIC: &[u8; 8] = b"PSLEMMA1";
+
+/// A lemmatizer backed by a dictionary of surface forms and their lemmas,
+/// stored as a finite state transducer.
+///
+/// The transducer shares the prefixes and suffixes of the forms, so that a
+/// dictionary of millions of forms takes a few megabytes and a lookup takes
+/// microseconds. Dictionaries are made with an [`FstLemmatizerBuilder`],
+/// and can be saved to a file and loaded with [`FstLemmatizer::open`].
+///
+/// A word with no lemma is its own stem, and a word that is not found as
+/// written is looked up again in lowercase.
+///
+/// # Examples
+///
+/// ```
+/// use polystem::{DynStemmer, FstLemmatizerBuilder};
+///
+/// let mut builder = FstLemmatizerBuilder::new();
+/// builder.insert("mice", "mouse");
+/// builder.insert("went", "go");
+/// let lemmatizer = builder.build();
+///
+/// assert_eq!("mouse", lemmatizer.stem_word("Mice"));
+/// assert_eq!("cats", lemmatizer.stem_word("cats"));
+/// ```
+#[derive(Clone)]
+pub struct FstLemmatizer {
+    // The value of a form is the offset and length of its lemma in
+    // `lemmas`, packed as `offset << 32 | length`.
+    forms: Map<Vec<u8>>,
+    lemmas: String,
+}
+
+/// A builder of an [`FstLemmatizer`] from pairs of forms and lemmas.
+#[derive(Clone, Debug, Default)]
+pub struct FstLemmatizerBuilder {
+    lemmas: BTreeMap<String, String>,
+}
+
+impl FstLemmatizerBuilder {
+    /// Construct an empty `FstLemmatizerBuilder`.
+    pub fn new() -> FstLemmatizerBuilder {
+        FstLemmatizerBuilder::default()
+    }
+
+    /// Add `form` with its `lemma`, replacing an earlier lemma of `form`.
+    pub fn insert(&mut self, form: &str, lemma: &str) {
+        self.lemmas.insert(form.to_owned(), lemma.to_owned());
+    }
+
+    /// Build the lemmatizer.
+    pub fn build(self) -> FstLemmatizer {
+        let mut lemmas = String::new();
+        let mut offsets = HashMap::new();
+        let mut builder = MapBuilder::memory();
+        for (form, lemma) in &self.lemmas {
+            let value = *offsets.entry(lemma.as_str()).or_insert_with(|| {
+                let offset = lemmas.len() as u64;
+                lemmas.push_str(lemma);
+                offset << 32 | lemma.len() as u64
+            });
+            builder
+                .insert(form, value)
+                .expect("forms are inserted in order");
+        }
+
+        let bytes = builder.into_inner().expect("write to memory");
+        FstLemmatizer {
+            forms: Map::new(bytes).expect("a valid transducer"),
+            lemmas,
+        }
+    }
+}
+
+impl<'a> Extend<(&'a str, &'a str)> for FstLemmatizerBuilder {
+    fn extend<I: IntoIterator<Item = (&'a str, &'a str)>>(
+        &mut self,
+        pairs: I,
+    ) {
+        for (form, lemma) in pairs {
+            self.insert(form, lemma);
+        }
+    }
+}
+
+impl FstLemmatizer {
+    /// Load a lemmatizer saved by [`FstLemmatizer::write`], checking the
+    /// checksum of its transducer.
+    pub fn from_bytes(
+        bytes: Vec<u8>,
+    ) -> Result<FstLemmatizer, DictionaryError> {
+        let invalid =
+            |message: &str| DictionaryError::Format(message.to_owned());
+        if bytes.get(..MAGIC.len()) != Some(&MAGIC[..]) {
+            return Err(invalid("not a lemma dictionary"));
+        }
+        let header = MAGIC.len() + 8;
+        let len = bytes
+            .get(MAGIC.len()..header)
+            .map(|len| u64::from_le_bytes(len.try_into().unwrap()))
+            .and_then(|len| usize::try_from(len).ok())
+            .filter(|&len| len <= bytes.len() - header)
+            .ok_or_else(|| invalid("truncated lemmas"))?;
+
+        let lemmas = std::str::from_utf8(&bytes[header..header + len])
+            .map_err(|_| invalid("lemmas are not valid UTF-8"))?
+            .to_owned();
+        let forms = Map::new(bytes[header + len..].to_vec())
+            .map_err(|e| invalid(&e.to_string()))?;
+        forms
+            .as_fst()
+            .verify()
+            .map_err(|e| invalid(&e.to_string()))?;
+        Ok(FstLemmatizer { forms, lemmas })
+    }
+
+    /// Load a lemmatizer from the file at `path`.
+    pub fn open<P: AsRef<Path>>(
+        path: P,
+    ) -> Result<FstLemmatizer, DictionaryError> {
+        FstLemmatizer::from_bytes(fs::read(path)?)
+    }
+
+    /// Write the lemmatizer to `w`, in the format read by
+    /// [`FstLemmatizer::from_bytes`].
+    pub fn write<W: Write>(&self, mut w: W) -> io::Result<()> {
+        w.write_all(MAGIC)?;
+        w.write_all(&(self.lemmas.len() as u64).to_le_bytes())?;
+        w.write_all(self.lemmas.as_bytes())?;
+        w.write_all(self.forms.as_fst().as_bytes())
+    }
+
+    /// Return the number of forms.
+    pub fn len(&self) -> usize {
+        self.forms.len()
+    }
+
+    /// Return `true` if there are no forms.
+    pub fn is_empty(&self) -> bool {
+        self.forms.is_empty()
+    }
+
+    /// Return the lemma of `form`, if it is in the dictionary.
+    pub fn lemma(&self, form: &str) -> Option<&str> {
+        let value = self.forms.get(form)?;
+        let start = (value >> 32) as usize;
+        let end = start + (value & 0xffff_ffff) as usize;
+        self.lemmas.get(start..end)
+    }
+
+    fn lookup(&self, word: &str) -> Option<&str> {
+        self.lemma(word).or_else(|| {
+            let lower = word.to_lowercase();
+            if lower == word {
+                return None;
+            }
+            self.lemma(&lower)
+        })
+    }
+}
+
+impl std::fmt::Debug for FstLemmatizer {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("FstLemmatizer")
+            .field("forms", &self.forms.len())
+            .finish()
+    }
+}
+
+impl DynStemmer for FstLemmatizer {
+    fn stem_word(&self, word: &str) -> String {
+        String::from(self.lookup(word).unwrap_or(word))
+    }
+
+    fn try_stem_word(&self, word: &str) -> Result<String, StemError> {
+        if word.is_empty() {
+            return Err(StemError::Empty);
+        }
+        Ok(self.stem_word(word))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lemmatizer() -> FstLemmatizer {
+        let mut builder = FstLemmatizerBuilder::new();
+        builder.extend(vec![
+            ("went", "go"),
+            ("goes", "go"),
+            ("geese", "goose"),
+            ("Paris", "Paris"),
+            ("mice", "rat"),
+        ]);
+        builder.insert("mice", "mouse");
+        builder.build()
+    }
+
+    #[test]
+    fn test_lemma() {
+        let lemmatizer = lemmatizer();
+        assert_eq!(lemmatizer.len(), 5);
+        assert_eq!(lemmatizer.lemma("went"), Some("go"));
+        assert_eq!(lemmatizer.lemma("goes"), Some("go"));
+        assert_eq!(lemmatizer.lemma("mice"), Some("mouse"));
+        assert_eq!(lemmatizer.lemma("Geese"), None);
+        assert_eq!(lemmatizer.lemmas, "Parisgoosegomouse");
+
+        assert_eq!(lemmatizer.stem_word("Geese"), "goose");
+        assert_eq!(lemmatizer.stem_word("Paris"), "Paris");
+        assert_eq!(lemmatizer.stem_word("cats"), "cats");
+        assert_eq!(lemmatizer.try_stem_word(""), Err(StemError::Empty));
+        assert!(FstLemmatizerBuilder::new().build().is_empty());
+    }
+
+    #[test]
+    fn test_write() {
+        let mut bytes = Vec::new();
+        lemmatizer().write(&mut bytes).unwrap();
+        let lemmatizer = FstLemmatizer::from_bytes(bytes.clone()).unwrap();
+        assert_eq!(lemmatizer.lemma("geese"), Some("goose"));
+
+        let err = |bytes: &[u8]| {
+            FstLemmatizer::from_bytes(bytes.to_vec())
+                .unwrap_err()
+                .to_string()
+        };
+        assert_eq!(
+            err(b"PSLEMMA0"),
+            "invalid dictionary: not a lemma dictionary"
+        );
+        assert_eq!(err(&bytes[..12]), "invalid dictionary: truncated lemmas");
+        assert!(
+            err(&bytes[..bytes.len() - 1]).starts_with("invalid dictionary")
+        );
+    }
+}
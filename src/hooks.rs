@@ -0,0 +1,111 @@
+// Copyright 2019 The Polystem authors.
+//
+// For the full copyright and license information, please view the LICENSE file
+// that was distributed with this source code.
+
+use crate::{DynStemmer, StemError};
+
+/// A function run on a token before or after it is stemmed.
+pub type Hook = Box<dyn Fn(String) -> String + Send + Sync>;
+
+/// A stemmer wrapper that runs hooks before and after stemming each token.
+///
+/// The `before` hooks see the token as it was given, for example to strip
+/// possessives, and the `after` hooks see the stem, for example to apply a
+/// synonym map. Hooks run in the order they were added.
+///
+/// # Examples
+///
+/// ```
+/// use polystem::{Algorithm, DynStemmer, Hooked};
+///
+/// let stemmer = Hooked::new(Algorithm::Porter)
+///     .before(|token| token.trim_end_matches("'s").to_owned())
+///     .after(|stem| if stem == "automobil" { "car".into() } else { stem });
+///
+/// assert_eq!("rust", stemmer.stem_word("rusted"));
+/// assert_eq!("dog", stemmer.stem_word("dog's"));
+/// assert_eq!("car", stemmer.stem_word("automobiles"));
+/// ```
+pub struct Hooked<D> {
+    stemmer: D,
+    before: Vec<Hook>,
+    after: Vec<Hook>,
+}
+
+impl<D: DynStemmer> Hooked<D> {
+    /// Construct a new `Hooked` wrapping `stemmer`, without any hooks.
+    pub fn new(stemmer: D) -> Hooked<D> {
+        Hooked {
+            stemmer,
+            before: Vec::new(),
+            after: Vec::new(),
+        }
+    }
+
+    /// Add a hook that runs on each token before it is stemmed.
+    pub fn before<F>(mut self, hook: F) -> Hooked<D>
+    where
+        F: Fn(String) -> String + Send + Sync + 'static,
+    {
+        self.before.push(Box::new(hook));
+        self
+    }
+
+    /// Add a hook that runs on each stem.
+    pub fn after<F>(mut self, hook: F) -> Hooked<D>
+    where
+        F: Fn(String) -> String + Send + Sync + 'static,
+    {
+        self.after.push(Box::new(hook));
+        self
+    }
+
+    // Run `hooks` over `token` in order.
+    fn run(hooks: &[Hook], token: String) -> String {
+        hooks.iter().fold(token, |token, hook| hook(token))
+    }
+}
+
+impl<D: DynStemmer> DynStemmer for Hooked<D> {
+    fn stem_word(&self, word: &str) -> String {
+        let token = Self::run(&self.before, word.to_owned());
+        let stem = self.stemmer.stem_word(&token);
+        Self::run(&self.after, stem)
+    }
+
+    fn try_stem_word(&self, word: &str) -> Result<String, StemError> {
+        let token = Self::run(&self.before, word.to_owned());
+        let stem = self.stemmer.try_stem_word(&token)?;
+        Ok(Self::run(&self.after, stem))
+    }
+
+    fn stem_candidates_word(&self, word: &str) -> Vec<String> {
+        let token = Self::run(&self.before, word.to_owned());
+        let stems = self.stemmer.stem_candidates_word(&token);
+        stems
+            .into_iter()
+            .map(|stem| Self::run(&self.after, stem))
+            .collect()
+    }
+}
+
+#[cfg(all(test, feature = "s"))]
+mod tests {
+    use super::*;
+    use crate::S;
+
+    #[test]
+    fn test_hooked() {
+        let stemmer = Hooked::new(S);
+        assert_eq!(stemmer.stem_word("flies"), "fly");
+
+        let stemmer = Hooked::new(S)
+            .before(|t| t.replace('-', ""))
+            .before(|t| t + "s")
+            .after(|s| s.to_uppercase());
+        assert_eq!(stemmer.stem_word("fl-ie"), "FLY");
+        assert_eq!(stemmer.try_stem_word("sun"), Ok(String::from("SUN")));
+        assert_eq!(stemmer.stem_candidates_word("sun"), vec!["SUN"]);
+    }
+}
@@ -0,0 +1,687 @@
+// Copyright 2019 The Polystem authors.
+//
+// For the full copyright and license information, please view the LICENSE file
+// that was distributed with this source code.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use crate::{DictionaryError, DynStemmer, StemError};
+
+/// A lemmatizer backed by a Hunspell dictionary, a pair of `.aff` and `.dic`
+/// files, which are available for dozens of languages.
+///
+/// A word is lemmatized by undoing the prefix and suffix rules of the
+/// `.aff` file until a word of the `.dic` file that allows those affixes is
+/// found. A word with no lemma is its own stem, and a word that is not
+/// found as written is looked up again in lowercase.
+///
+/// The `SET`, `FLAG`, `AF`, `NEEDAFFIX`, `FORBIDDENWORD`, `PFX` and `SFX`
+/// directives are read, and the others are ignored. Continuation classes of
+/// affixes, compounding and conversion tables are not supported, so words
+/// with more than one suffix are not analysed.
+///
+/// # Examples
+///
+/// ```
+/// use polystem::{DynStemmer, Hunspell};
+///
+/// let aff = "SFX S Y 2\n\
+///            SFX S 0 s [^y]\n\
+///            SFX S y ies y\n";
+/// let dic = "2\ncat/S\nfly/S\n";
+/// let hunspell = Hunspell::new(aff, dic).unwrap();
+///
+/// assert_eq!("fly", hunspell.stem_word("flies"));
+/// assert_eq!("cat", hunspell.stem_word("Cats"));
+/// ```
+#[derive(Clone, Debug)]
+pub struct Hunspell {
+    // The flags of each word; homonyms have an entry each.
+    words: HashMap<String, Vec<Vec<Flag>>>,
+    prefixes: Vec<Affix>,
+    suffixes: Vec<Affix>,
+    need_affix: Option<Flag>,
+    forbidden: Option<Flag>,
+}
+
+type Flag = u64;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum FlagType {
+    Char,
+    Long,
+    Num,
+}
+
+#[derive(Clone, Debug)]
+struct Affix {
+    flag: Flag,
+    cross: bool,
+    strip: String,
+    add: String,
+    cond: Vec<Cond>,
+}
+
+// An element of the condition of an affix, which the root must match.
+#[derive(Clone, Debug)]
+enum Cond {
+    Any,
+    Char(char),
+    Set(Vec<char>, bool),
+}
+
+impl Cond {
+    fn matches(&self, c: char) -> bool {
+        match self {
+            Cond::Any => true,
+            Cond::Char(d) => c == *d,
+            Cond::Set(chars, negated) => chars.contains(&c) != *negated,
+        }
+    }
+}
+
+impl Affix {
+    // Return the root of `word` without this prefix.
+    fn remove_prefix(&self, word: &str) -> Option<String> {
+        let rest = word.strip_prefix(self.add.as_str())?;
+        if rest.is_empty() {
+            return None;
+        }
+        let root = format!("{}{}", self.strip, rest);
+        let mut chars = root.chars();
+        let matched = self
+            .cond
+            .iter()
+            .all(|cond| chars.next().is_some_and(|c| cond.matches(c)));
+        matched.then_some(root)
+    }
+
+    // Return the root of `word` without this suffix.
+    fn remove_suffix(&self, word: &str) -> Option<String> {
+        let rest = word.strip_suffix(self.add.as_str())?;
+        if rest.is_empty() {
+            return None;
+        }
+        let root = format!("{}{}", rest, self.strip);
+        let mut chars = root.chars().rev();
+        let matched = self
+            .cond
+            .iter()
+            .rev()
+            .all(|cond| chars.next().is_some_and(|c| cond.matches(c)));
+        matched.then_some(root)
+    }
+}
+
+// The state of the parser of a `.aff` file.
+struct AffParser {
+    flag_type: FlagType,
+    aliases: Vec<Vec<Flag>>,
+    // The kind, flag and cross product of the affix rules being read, and
+    // how many remain.
+    rules: Option<(String, Flag, bool, usize)>,
+}
+
+impl AffParser {
+    // Parse the flags `s` of a word, which may be a number of an `AF` alias.
+    fn word_flags(&self, s: &str) -> Result<Vec<Flag>, &'static str> {
+        if !self.aliases.is_empty() && s.bytes().all(|b| b.is_ascii_digit()) {
+            return s
+                .parse::<usize>()
+                .ok()
+                .and_then(|i| self.aliases.get(i.wrapping_sub(1)))
+                .cloned()
+                .ok_or("unknown flag alias");
+        }
+        self.flags(s)
+    }
+
+    fn flags(&self, s: &str) -> Result<Vec<Flag>, &'static str> {
+        match self.flag_type {
+            FlagType::Char => Ok(s.chars().map(Flag::from).collect()),
+            FlagType::Long => {
+                let chars: Vec<char> = s.chars().collect();
+                if !chars.len().is_multiple_of(2) {
+                    return Err("long flags have two characters");
+                }
+                Ok(chars
+                    .chunks(2)
+                    .map(|pair| {
+                        Flag::from(pair[0]) << 32 | Flag::from(pair[1])
+                    })
+                    .collect())
+            }
+            FlagType::Num => s
+                .split(',')
+                .map(|n| n.parse().map_err(|_| "invalid numeric flag"))
+                .collect(),
+        }
+    }
+
+    fn flag(&self, s: &str) -> Result<Flag, &'static str> {
+        match self.flags(s)?[..] {
+            [flag] => Ok(flag),
+            _ => Err("expected a single flag"),
+        }
+    }
+}
+
+// Parse the condition of an affix, such as `[^aeiou]y`.
+fn parse_cond(s: &str) -> Result<Vec<Cond>, &'static str> {
+    let mut cond = Vec::new();
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        let elem = match c {
+            '.' => Cond::Any,
+            '[' => {
+                let mut set = Vec::new();
+                let mut negated = false;
+                loop {
+                    match chars.next() {
+                        Some(']') => break,
+                        Some('^') if set.is_empty() && !negated => {
+                            negated = true
+                        }
+                        Some(c) => set.push(c),
+                        None => return Err("unterminated `[` in condition"),
+                    }
+                }
+                Cond::Set(set, negated)
+            }
+            c => Cond::Char(c),
+        };
+        cond.push(elem);
+    }
+    // A condition of `.` matches any root, even an empty one.
+    if let [Cond::Any] = cond[..] {
+        cond.clear();
+    }
+    Ok(cond)
+}
+
+// Return `s`, or the empty string for the `0` placeholder.
+fn zero(s: &str) -> String {
+    if s == "0" {
+        String::new()
+    } else {
+        s.to_owned()
+    }
+}
+
+// Decode `bytes`, the contents of `file`, with the character set named by
+// the `SET` line of the `.aff` file `aff`.
+fn decode(
+    aff: &[u8],
+    file: &str,
+    bytes: Vec<u8>,
+) -> Result<String, DictionaryError> {
+    let (line, set) = aff
+        .split(|&b| b == b'\n')
+        .enumerate()
+        .find_map(|(i, line)| Some((i + 1, line.strip_prefix(b"SET ")?)))
+        .map(|(i, set)| (i, String::from_utf8_lossy(set).trim().to_owned()))
+        .unwrap_or((0, String::from("UTF-8")));
+    match set.to_ascii_uppercase().as_str() {
+        "UTF-8" => String::from_utf8(bytes).map_err(|e| {
+            let valid = &e.as_bytes()[..e.utf8_error().valid_up_to()];
+            let line = valid.iter().filter(|&&b| b == b'\n').count() + 1;
+            DictionaryError::parse(file, line, "invalid UTF-8")
+        }),
+        "ISO8859-1" | "ISO-8859-1" => {
+            Ok(bytes.into_iter().map(char::from).collect())
+        }
+        _ => Err(DictionaryError::parse(
+            "aff",
+            line,
+            "unsupported character set",
+        )),
+    }
+}
+
+impl Hunspell {
+    /// Create a lemmatizer from the contents of a `.aff` and a `.dic` file.
+    pub fn new(aff: &str, dic: &str) -> Result<Hunspell, DictionaryError> {
+        let mut hunspell = Hunspell {
+            words: HashMap::new(),
+            prefixes: Vec::new(),
+            suffixes: Vec::new(),
+            need_affix: None,
+            forbidden: None,
+        };
+        let parser = hunspell.read_aff(aff)?;
+        hunspell.read_dic(&parser, dic)?;
+        Ok(hunspell)
+    }
+
+    /// Create a lemmatizer from the `.aff` and `.dic` files at `aff` and
+    /// `dic`, in UTF-8 or ISO 8859-1 as given by the `SET` directive.
+    pub fn open<P: AsRef<Path>, Q: AsRef<Path>>(
+        aff: P,
+        dic: Q,
+    ) -> Result<Hunspell, DictionaryError> {
+        let aff = aff.as_ref();
+        let dic = dic.as_ref();
+        let load = || {
+            let aff_bytes = fs::read(aff)?;
+            let dic_text = decode(&aff_bytes, "dic", fs::read(dic)?)?;
+            let aff_text = decode(&aff_bytes, "aff", aff_bytes.clone())?;
+            Hunspell::new(&aff_text, &dic_text)
+        };
+
+        // Name the files in errors.
+        load().map_err(|e| match e {
+            DictionaryError::Parse {
+                file,
+                line,
+                message,
+            } => {
+                let path = if file == "aff" { aff } else { dic };
+                DictionaryError::Parse {
+                    file: path.display().to_string(),
+                    line,
+                    message,
+                }
+            }
+            e => e,
+        })
+    }
+
+    fn read_aff(&mut self, aff: &str) -> Result<AffParser, DictionaryError> {
+        let mut parser = AffParser {
+            flag_type: FlagType::Char,
+            aliases: Vec::new(),
+            rules: None,
+        };
+        let mut aliases = None;
+
+        for (i, line) in aff.lines().enumerate() {
+            let error =
+                |message: &str| DictionaryError::parse("aff", i + 1, message);
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let (directive, args) = match fields.split_first() {
+                Some((d, _)) if d.starts_with('#') => continue,
+                Some((d, args)) => (*d, args),
+                None => continue,
+            };
+
+            match (directive, args) {
+                ("FLAG", [flag_type, ..]) => {
+                    parser.flag_type = match *flag_type {
+                        "long" => FlagType::Long,
+                        "num" => FlagType::Num,
+                        "UTF-8" => FlagType::Char,
+                        _ => return Err(error("unknown flag type")),
+                    }
+                }
+                ("AF", [n, ..]) if aliases.is_none() => {
+                    aliases = Some(n.parse::<usize>().map_err(|_| {
+                        error("expected the number of aliases")
+                    })?)
+                }
+                ("AF", [flags, ..]) => {
+                    let flags = parser.flags(flags).map_err(error)?;
+                    parser.aliases.push(flags);
+                }
+                ("NEEDAFFIX", [flag, ..]) | ("PSEUDOROOT", [flag, ..]) => {
+                    self.need_affix = Some(parser.flag(flag).map_err(error)?)
+                }
+                ("FORBIDDENWORD", [flag, ..]) => {
+                    self.forbidden = Some(parser.flag(flag).map_err(error)?)
+                }
+                ("PFX", _) | ("SFX", _) => self
+                    .read_affix(&mut parser, directive, args)
+                    .map_err(error)?,
+                _ => {}
+            }
+        }
+
+        if parser
+            .rules
+            .as_ref()
+            .is_some_and(|&(_, _, _, remaining)| remaining > 0)
+        {
+            let lines = aff.lines().count();
+            return Err(DictionaryError::parse(
+                "aff",
+                lines,
+                "missing affix rules",
+            ));
+        }
+        Ok(parser)
+    }
+
+    fn read_affix(
+        &mut self,
+        parser: &mut AffParser,
+        kind: &str,
+        args: &[&str],
+    ) -> Result<(), &'static str> {
+        let flag = match args.first() {
+            Some(flag) => parser.flag(flag)?,
+            None => return Err("expected a flag"),
+        };
+
+        let cross = match parser.rules.as_mut() {
+            Some((k, f, cross, remaining))
+                if *remaining > 0 && k == kind && *f == flag =>
+            {
+                *remaining -= 1;
+                *cross
+            }
+            Some((_, _, _, remaining)) if *remaining > 0 => {
+                return Err("expected another rule of the previous affix")
+            }
+            _ => {
+                // The header of the rules of an affix.
+                let (cross, count) = match args {
+                    [_, cross, count, ..] => (
+                        *cross == "Y",
+                        count.parse().map_err(|_| "expected a rule count")?,
+                    ),
+                    _ => return Err("expected an affix header"),
+                };
+                parser.rules = Some((kind.to_owned(), flag, cross, count));
+                return Ok(());
+            }
+        };
+
+        let (strip, add, cond) = match args {
+            [_, strip, add] => (*strip, *add, "."),
+            [_, strip, add, cond, ..] => (*strip, *add, *cond),
+            _ => return Err("expected an affix rule"),
+        };
+        // Continuation classes after a `/` are not supported.
+        let add = add.split('/').next().unwrap_or_default();
+        let affix = Affix {
+            flag,
+            cross,
+            strip: zero(strip),
+            add: zero(add),
+            cond: parse_cond(cond)?,
+        };
+        if kind == "PFX" {
+            self.prefixes.push(affix);
+        } else {
+            self.suffixes.push(affix);
+        }
+        Ok(())
+    }
+
+    fn read_dic(
+        &mut self,
+        parser: &AffParser,
+        dic: &str,
+    ) -> Result<(), DictionaryError> {
+        let mut lines = dic
+            .lines()
+            .enumerate()
+            .skip_while(|(_, l)| l.trim().is_empty());
+        if let Some((i, count)) = lines.next() {
+            if count.trim().parse::<usize>().is_err() {
+                return Err(DictionaryError::parse(
+                    "dic",
+                    i + 1,
+                    "expected the number of words",
+                ));
+            }
+        }
+
+        for (i, line) in lines {
+            // Lines starting with a tab are comments.
+            if line.starts_with('\t') || line.trim().is_empty() {
+                continue;
+            }
+            let entry = line.split_whitespace().next().unwrap_or_default();
+            let (word, flags) = split_entry(entry);
+            let flags = match flags {
+                Some(flags) => {
+                    parser.word_flags(flags).map_err(|message| {
+                        DictionaryError::parse("dic", i + 1, message)
+                    })?
+                }
+                None => Vec::new(),
+            };
+            self.words.entry(word).or_default().push(flags);
+        }
+        Ok(())
+    }
+
+    /// Return the lemmas of `word`, most likely first, or no lemma when the
+    /// word can not be analysed.
+    pub fn lemmas(&self, word: &str) -> Vec<String> {
+        let mut lemmas = Vec::new();
+        self.analyse(word, &mut lemmas);
+        let lower = word.to_lowercase();
+        if lemmas.is_empty() && lower != word {
+            self.analyse(&lower, &mut lemmas);
+        }
+        lemmas
+    }
+
+    fn analyse(&self, word: &str, lemmas: &mut Vec<String>) {
+        let mut push = |lemma: String| {
+            if !lemmas.contains(&lemma) {
+                lemmas.push(lemma);
+            }
+        };
+
+        if self.is_root(word, &[]) {
+            push(word.to_owned());
+        }
+        for suffix in &self.suffixes {
+            let root = match suffix.remove_suffix(word) {
+                Some(root) => root,
+                None => continue,
+            };
+            if self.is_root(&root, &[suffix.flag]) {
+                push(root.clone());
+            }
+            if !suffix.cross {
+                continue;
+            }
+            for prefix in self.prefixes.iter().filter(|p| p.cross) {
+                if let Some(root) = prefix.remove_prefix(&root) {
+                    if self.is_root(&root, &[suffix.flag, prefix.flag]) {
+                        push(root);
+                    }
+                }
+            }
+        }
+        for prefix in &self.prefixes {
+            if let Some(root) = prefix.remove_prefix(word) {
+                if self.is_root(&root, &[prefix.flag]) {
+                    push(root);
+                }
+            }
+        }
+    }
+
+    // Return `true` if `word` is in the dictionary and allows the affixes
+    // with `flags`.
+    fn is_root(&self, word: &str, flags: &[Flag]) -> bool {
+        let entries = match self.words.get(word) {
+            Some(entries) => entries,
+            None => return false,
+        };
+        let has = |entry: &Vec<Flag>, flag: Option<Flag>| {
+            flag.is_some_and(|flag| entry.contains(&flag))
+        };
+        entries.iter().any(|entry| {
+            !has(entry, self.forbidden)
+                && (!flags.is_empty() || !has(entry, self.need_affix))
+                && flags.iter().all(|flag| entry.contains(flag))
+        })
+    }
+}
+
+// Split a `.dic` entry into its word and flags, at the first `/` that is
+// not escaped.
+fn split_entry(entry: &str) -> (String, Option<&str>) {
+    let mut word = String::new();
+    let mut chars = entry.char_indices();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '\\' => {
+                if let Some((_, next)) = chars.next() {
+                    word.push(next);
+                }
+            }
+            '/' => return (word, Some(&entry[i + 1..])),
+            c => word.push(c),
+        }
+    }
+    (word, None)
+}
+
+impl DynStemmer for Hunspell {
+    fn stem_word(&self, word: &str) -> String {
+        self.lemmas(word)
+            .into_iter()
+            .next()
+            .unwrap_or_else(|| String::from(word))
+    }
+
+    fn try_stem_word(&self, word: &str) -> Result<String, StemError> {
+        if word.is_empty() {
+            return Err(StemError::Empty);
+        }
+        Ok(self.stem_word(word))
+    }
+
+    fn stem_candidates_word(&self, word: &str) -> Vec<String> {
+        let lemmas = self.lemmas(word);
+        if lemmas.is_empty() {
+            return vec![String::from(word)];
+        }
+        lemmas
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const AFF: &str = "\
+# A small English dictionary.
+SET UTF-8
+TRY esianrtolcdugmphbyfvkwz
+
+SFX S Y 3
+SFX S 0 s [^sxy]
+SFX S y ies [^aeiou]y
+SFX S 0 es [sx]
+
+SFX D N 1
+SFX D 0 ed [^e]
+
+PFX U Y 1
+PFX U 0 un .
+
+NEEDAFFIX X
+FORBIDDENWORD F
+";
+
+    const DIC: &str = "\
+6
+cat/S
+fly/SD
+box/S
+kind/US
+walk/XD
+gooses/F
+";
+
+    #[test]
+    fn test_lemmas() {
+        let hunspell = Hunspell::new(AFF, DIC).unwrap();
+        let cases = [
+            ("cats", "cat"),
+            ("flies", "fly"),
+            ("boxes", "box"),
+            ("unkind", "kind"),
+            ("unkinds", "kind"),
+            ("walked", "walk"),
+            ("Cats", "cat"),
+            ("kind", "kind"),
+        ];
+        for (word, lemma) in cases.iter() {
+            assert_eq!(hunspell.lemmas(word), [*lemma], "{}", word);
+        }
+
+        // `walk` needs an affix, `gooses` is forbidden, `flys` does not
+        // match the condition of the rule, and `unflies` is not a cross
+        // product.
+        for word in ["walk", "gooses", "flys", "unflies", "dogs"].iter() {
+            assert!(hunspell.lemmas(word).is_empty(), "{}", word);
+        }
+        assert_eq!(hunspell.stem_word("dogs"), "dogs");
+        assert_eq!(hunspell.stem_candidates_word("dogs"), ["dogs"]);
+        assert_eq!(hunspell.try_stem_word(""), Err(StemError::Empty));
+    }
+
+    #[test]
+    fn test_homonyms() {
+        let dic = "2\nleaves/F\nleaves\n";
+        let hunspell = Hunspell::new(AFF, dic).unwrap();
+        assert_eq!(hunspell.lemmas("leaves"), ["leaves"]);
+    }
+
+    #[test]
+    fn test_flag_types() {
+        let aff = "FLAG long\nSFX Aa Y 1\nSFX Aa 0 s .\n";
+        let hunspell = Hunspell::new(aff, "1\ncat/AaBb\n").unwrap();
+        assert_eq!(hunspell.lemmas("cats"), ["cat"]);
+
+        let aff = "FLAG num\nAF 1\nAF 101,7\nSFX 101 Y 1\nSFX 101 0 s .\n";
+        let hunspell = Hunspell::new(aff, "2\ncat/1\na\\/b\n").unwrap();
+        assert_eq!(hunspell.lemmas("cats"), ["cat"]);
+        assert_eq!(hunspell.lemmas("a/b"), ["a/b"]);
+    }
+
+    #[test]
+    fn test_errors() {
+        let err =
+            Hunspell::new("SFX S Y 2\nSFX S 0 s .\n", "0\n").unwrap_err();
+        assert_eq!(err.to_string(), "aff:2: missing affix rules");
+
+        let err = Hunspell::new("SFX S Y 1\nSFX T 0 s .\n", "").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "aff:2: expected another rule of the previous affix"
+        );
+
+        let err = Hunspell::new(AFF, "cat/S\n").unwrap_err();
+        assert_eq!(err.to_string(), "dic:1: expected the number of words");
+
+        let err = Hunspell::new("FLAG long\n", "1\ncat/S\n").unwrap_err();
+        assert_eq!(err.to_string(), "dic:2: long flags have two characters");
+    }
+
+    #[test]
+    fn test_open() {
+        let dir = std::env::temp_dir().join("polystem-hunspell-test");
+        fs::create_dir_all(&dir).unwrap();
+        let aff = dir.join("latin1.aff");
+        let dic = dir.join("latin1.dic");
+        fs::write(&aff, b"SET ISO8859-1\nSFX S Y 1\nSFX S 0 s .\n").unwrap();
+        fs::write(&dic, b"1\ncaf\xe9/S\n").unwrap();
+
+        let hunspell = Hunspell::open(&aff, &dic).unwrap();
+        assert_eq!(hunspell.lemmas("cafés"), ["café"]);
+
+        fs::write(&aff, b"SFX S Y 1\nSFX S 0 s .\n").unwrap();
+        let err = Hunspell::open(&aff, &dic).unwrap_err();
+        assert!(err.to_string().ends_with("latin1.dic:2: invalid UTF-8"));
+
+        fs::write(&dic, b"cat\n").unwrap();
+        let err = Hunspell::open(&aff, &dic).unwrap_err();
+        assert!(err
+            .to_string()
+            .ends_with("latin1.dic:1: expected the number of words"));
+
+        let err = Hunspell::open(dir.join("missing.aff"), &dic).unwrap_err();
+        assert!(matches!(err, DictionaryError::Io(_)));
+    }
+}
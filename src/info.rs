@@ -0,0 +1,81 @@
+// Copyright 2019 The Polystem authors.
+//
+// For the full copyright and license information, please view the LICENSE file
+// that was distributed with this source code.
+
+use std::borrow::Cow;
+use std::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::Language;
+
+/// How much of a word a stemmer removes, in the classes of stemmer
+/// strength used in the Information Retrieval literature.
+///
+/// A light stemmer conflates fewer words, which favours precision, and a
+/// heavy one conflates more, which favours recall.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum Aggressiveness {
+    /// Removes inflectional endings only, such as plurals.
+    Light,
+    /// Removes inflectional and common derivational endings.
+    Moderate,
+    /// Removes long chains of derivational endings.
+    Heavy,
+}
+
+impl Aggressiveness {
+    /// Return the lowercase name of the class.
+    pub fn name(self) -> &'static str {
+        match self {
+            Aggressiveness::Light => "light",
+            Aggressiveness::Moderate => "moderate",
+            Aggressiveness::Heavy => "heavy",
+        }
+    }
+}
+
+impl fmt::Display for Aggressiveness {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad(self.name())
+    }
+}
+
+/// Describes a stemmer and the version of its rules.
+///
+/// The `algorithm_version` of a stemmer is incremented whenever a change to
+/// the crate alters the stem of any word. An index can record the
+/// `StemmerInfo` it was built with and use [`StemmerInfo::is_compatible`] to
+/// detect that its stems need to be rebuilt.
+///
+/// # Examples
+///
+/// ```
+/// use polystem::{Porter, Stemmer};
+///
+/// let info = Porter::INFO;
+///
+/// assert_eq!("porter", info.name);
+/// assert!(info.is_compatible(&Porter::INFO));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct StemmerInfo {
+    pub name: Cow<'static, str>,
+    pub language: Language,
+    pub aggressiveness: Aggressiveness,
+    pub algorithm_version: u32,
+}
+
+impl StemmerInfo {
+    /// Return `true` if stems produced by the stemmer described by `other`
+    /// are identical to the stems of this stemmer.
+    pub fn is_compatible(&self, other: &StemmerInfo) -> bool {
+        self.name == other.name
+            && self.algorithm_version == other.algorithm_version
+    }
+}
@@ -0,0 +1,142 @@
+// Copyright 2019 The Polystem authors.
+//
+// For the full copyright and license information, please view the LICENSE file
+// that was distributed with this source code.
+
+use std::collections::HashMap;
+use std::convert::TryFrom;
+
+use crate::Stems;
+
+/// The id of a stem in an [`Interner`].
+///
+/// Ids are handed out in order from zero, so they can index a table of
+/// postings directly.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Symbol(u32);
+
+impl Symbol {
+    /// Return the id of the symbol.
+    pub fn id(self) -> u32 {
+        self.0
+    }
+}
+
+/// A table of distinct stems, each stored once and named by a [`Symbol`],
+/// see [`Stemmer::stem_all_interned`](crate::Stemmer::stem_all_interned).
+///
+/// # Examples
+///
+/// ```
+/// use polystem::{Interner, Porter, Stemmer};
+///
+/// let mut interner = Interner::new();
+/// let symbols = Porter::stem_all_interned(
+///     &["connected", "connecting", "rusted"],
+///     &mut interner,
+/// );
+///
+/// assert_eq!(symbols[0], symbols[1]);
+/// assert_eq!(Some("rust"), interner.resolve(symbols[2]));
+/// assert_eq!(2, interner.len());
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Interner {
+    symbols: HashMap<Box<str>, Symbol>,
+    stems: Stems,
+}
+
+impl Interner {
+    /// Construct an empty `Interner`.
+    pub fn new() -> Interner {
+        Interner::default()
+    }
+
+    /// Return the symbol of `stem`, adding it when it is new.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the interner already holds `u32::MAX` stems.
+    pub fn intern(&mut self, stem: &str) -> Symbol {
+        if let Some(&symbol) = self.symbols.get(stem) {
+            return symbol;
+        }
+
+        let id = u32::try_from(self.stems.len())
+            .ok()
+            .filter(|&id| id != u32::MAX)
+            .expect("too many stems to intern");
+        self.symbols.insert(stem.into(), Symbol(id));
+        self.stems.push(stem);
+        Symbol(id)
+    }
+
+    /// Return the symbol of `stem`, or `None` when it was never interned.
+    pub fn get(&self, stem: &str) -> Option<Symbol> {
+        self.symbols.get(stem).copied()
+    }
+
+    /// Return the stem named by `symbol`, or `None` when `symbol` is from
+    /// another interner.
+    pub fn resolve(&self, symbol: Symbol) -> Option<&str> {
+        self.stems.get(symbol.0 as usize)
+    }
+
+    /// Return the number of distinct stems.
+    pub fn len(&self) -> usize {
+        self.stems.len()
+    }
+
+    /// Return `true` if no stem has been interned.
+    pub fn is_empty(&self) -> bool {
+        self.stems.is_empty()
+    }
+
+    /// Return the stems, in the order of their symbols.
+    pub fn stems(&self) -> &Stems {
+        &self.stems
+    }
+}
+
+#[cfg(all(test, feature = "s"))]
+mod tests {
+    use super::*;
+    use crate::{Algorithm, Stemmer, S};
+
+    #[test]
+    fn test_interner() {
+        let mut interner = Interner::new();
+        assert!(interner.is_empty());
+
+        let fly = interner.intern("fly");
+        let sun = interner.intern("sun");
+        assert_eq!(interner.intern("fly"), fly);
+        assert_eq!((fly.id(), sun.id()), (0, 1));
+        assert_eq!(interner.get("sun"), Some(sun));
+        assert_eq!(interner.get("cat"), None);
+        assert_eq!(interner.resolve(sun), Some("sun"));
+        assert_eq!(interner.resolve(Symbol(2)), None);
+        assert_eq!(interner.len(), 2);
+        assert_eq!(
+            interner.stems().iter().collect::<Vec<_>>(),
+            ["fly", "sun"]
+        );
+    }
+
+    #[test]
+    fn test_stem_all_interned() {
+        let mut interner = Interner::new();
+        let words = ["flies", "suns", "fly", "Suns"];
+        let symbols = S::stem_all_interned(&words, &mut interner);
+        let more =
+            Algorithm::S.stem_all_interned(&["cats", "sun"], &mut interner);
+
+        let stems: Vec<_> = symbols
+            .iter()
+            .chain(&more)
+            .map(|&symbol| interner.resolve(symbol).unwrap())
+            .collect();
+        assert_eq!(stems, ["fly", "sun", "fly", "sun", "cat", "sun"]);
+        assert_eq!(interner.len(), 3);
+    }
+}
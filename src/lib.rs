@@ -7,761 +7,1372 @@
 //!
 //! A collection of common stemming algorithms.
 
-use std::str;
-
+use std::borrow::Cow;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt;
+use std::str::{self, FromStr};
+
+#[cfg(feature = "arrow")]
+use arrow_array::builder::GenericStringBuilder;
+#[cfg(feature = "arrow")]
+use arrow_array::{Array, GenericStringArray, OffsetSizeTrait};
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+mod batch;
+mod bounds;
+mod cache;
+#[cfg(feature = "capi")]
+pub mod capi;
+mod chain;
+mod conflation;
+mod decompound;
+mod error;
+pub mod eval;
+#[cfg(feature = "tokenize")]
+mod fields;
+#[cfg(feature = "fst")]
+mod fst_lemmas;
+mod hooks;
+#[cfg(feature = "hunspell")]
+mod hunspell;
+mod info;
+mod intern;
+mod markup;
+mod multi;
+mod numbers;
+mod options;
+#[cfg(feature = "tokenize")]
+mod pipeline;
+#[cfg(feature = "porter")]
+mod porter;
+mod registry;
+#[cfg(feature = "porter")]
+pub mod rust_stemmers;
+#[cfg(feature = "s")]
+mod s;
+mod script;
+#[cfg(feature = "serve")]
+pub mod serve;
+#[cfg(feature = "snowball")]
+mod snowball;
+mod spelling;
+mod stem;
+mod stopwords;
+mod stream;
+#[cfg(feature = "porter")]
+mod suffix;
+#[cfg(all(feature = "porter", feature = "simd"))]
+mod swar;
+mod term_stats;
+#[cfg(feature = "tokenize")]
+pub mod tokenize;
+
+pub use batch::{Stems, StemsIter};
+pub use bounds::{Bounded, LengthBounds};
+pub use cache::{CacheStats, Cached, SharedCached};
+pub use chain::Chain;
+pub use conflation::{ConflationIndex, ConflationMap, Forms};
+pub use decompound::{Decompounder, Overlap};
+#[cfg(any(feature = "toml", feature = "yaml"))]
+pub use error::ConfigError;
+pub use error::{
+    BufTooSmall, DictionaryError, GoldError, ParseAlgorithmError, StemError,
+};
+#[cfg(feature = "tokenize")]
+pub use fields::FieldAnalyzers;
+#[cfg(feature = "fst")]
+pub use fst_lemmas::{FstLemmatizer, FstLemmatizerBuilder};
+pub use hooks::{Hook, Hooked};
+#[cfg(feature = "hunspell")]
+pub use hunspell::Hunspell;
+pub use info::{Aggressiveness, StemmerInfo};
+pub use intern::{Interner, Symbol};
+pub use markup::strip_markup;
+pub use multi::MultiStemmer;
+pub use numbers::normalize_number;
+pub use options::{
+    normalize_quotes, strip_possessive, Case, Configured, Hyphens, StemOptions,
+};
+#[cfg(feature = "tokenize")]
+pub use pipeline::{Pipeline, Profile, Profiles};
+#[cfg(feature = "porter")]
+pub use porter::{Porter, Rule};
+pub use registry::{by_name, register_stemmer, stemmer_names, StemmerFactory};
+#[cfg(feature = "s")]
+pub use s::S;
+pub use script::{is_mixed_script, Script};
+#[cfg(feature = "snowball")]
+pub use snowball::Snowball;
+pub use spelling::americanize;
+pub use stem::{same_stem, SameStem, Stem};
+pub use stopwords::{is_stopword, Stopwords};
+#[cfg(feature = "tokio")]
+pub use stream::StemChunks;
+pub use stream::{StreamStats, Streaming};
+pub use term_stats::{TermCounts, TermStats};
+
+/// A stemming algorithm.
+///
+/// The word can be any string type, such as `&str`, `String` or `Cow<str>`.
+///
+/// Every stemmer follows the same policy for short input: the empty string
+/// stems to the empty string, and words of one or two characters are
+/// returned unchanged.
 pub trait Stemmer {
-    fn stem(word: &str) -> String;
-}
+    /// The name, language and rule version of the stemmer.
+    ///
+    /// Required since version 0.5, so stemmers implemented outside this
+    /// crate must now describe themselves.
+    const INFO: StemmerInfo;
 
-pub struct S;
+    fn stem<W: AsRef<str>>(word: W) -> String;
+
+    /// Stem `word`, returning an error for input the stemmer can not handle
+    /// instead of a mangled stem.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polystem::{Porter, StemError, Stemmer};
+    ///
+    /// assert_eq!(Ok(String::from("rust")), Porter::try_stem("rusted"));
+    /// assert_eq!(Err(StemError::Empty), Porter::try_stem(""));
+    /// ```
+    fn try_stem<W: AsRef<str>>(word: W) -> Result<String, StemError> {
+        let word = word.as_ref();
+        StemError::check(word)?;
+        Ok(Self::stem(word))
+    }
 
-impl Stemmer for S {
-    /// A simple stemmer that strips `ies`, `es` and `s` from terms. Derived
-    /// from the s-stemmer in the [Atire](http://atire.org) search engine.
+    /// Return every plausible stem of `word`, most likely first.
+    ///
+    /// Some stemmers, such as dictionary based ones, can find more than one
+    /// stem for a word. The default returns the single result of `stem`.
     ///
     /// # Examples
     ///
     /// ```
     /// use polystem::{Stemmer, S};
     ///
-    /// let term = "flies";
-    /// let stem = S::stem(&term);
+    /// assert_eq!(vec!["fly"], S::stem_candidates("flies"));
+    /// ```
+    fn stem_candidates<W: AsRef<str>>(word: W) -> Vec<String> {
+        vec![Self::stem(word)]
+    }
+
+    /// Stem `word` given its part of speech.
+    ///
+    /// Lemmatizers use the hint to pick the rules for the word class, while
+    /// the algorithmic stemmers ignore it and return the result of `stem`.
+    ///
+    /// # Examples
     ///
-    /// assert_eq!("fly", stem);
     /// ```
-    fn stem(word: &str) -> String {
-        let mut stem = word.to_ascii_lowercase();
+    /// use polystem::{Porter, Pos, Stemmer};
+    ///
+    /// assert_eq!("rust", Porter::stem_with_pos("rusted", Pos::Verb));
+    /// ```
+    fn stem_with_pos<W: AsRef<str>>(word: W, pos: Pos) -> String {
+        let _ = pos;
+        Self::stem(word)
+    }
 
-        if stem.ends_with("ies") {
-            stem.truncate(stem.len() - 3);
-            stem.push('y');
-        } else if stem.ends_with("es") {
-            stem.truncate(stem.len() - 2);
-        } else if stem.ends_with("s") {
-            stem.truncate(stem.len() - 1);
-        }
+    /// Stem `word` and return the stem with a confidence in `[0, 1]`.
+    ///
+    /// Statistical and dictionary stemmers report how certain they are of a
+    /// conflation, so that rankers can weight uncertain stems differently.
+    /// Rule based stemmers always return a confidence of `1.0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polystem::{Porter, Stemmer};
+    ///
+    /// assert_eq!((String::from("rust"), 1.0), Porter::stem_scored("rusted"));
+    /// ```
+    fn stem_scored<W: AsRef<str>>(word: W) -> (String, f32) {
+        (Self::stem(word), 1.0)
+    }
 
-        stem
+    /// Stem `word` repeatedly until the stem no longer changes, or for at
+    /// most [`FIXPOINT_ROUNDS`] rounds.
+    ///
+    /// Stemmers such as Porter are not idempotent, so a stem that is stemmed
+    /// again can change. Stemming to the fixpoint gives the same key for a
+    /// word and its stem in pipelines that stem text more than once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polystem::{Porter, Stemmer};
+    ///
+    /// assert_eq!("univers", Porter::stem("university"));
+    /// assert_eq!("univ", Porter::stem("univers"));
+    /// assert_eq!("univ", Porter::stem_fixpoint("university"));
+    /// ```
+    fn stem_fixpoint<W: AsRef<str>>(word: W) -> String {
+        fixpoint(Self::stem(word), |w| Self::stem(w))
     }
-}
 
-pub struct Porter {
-    buf: Vec<u8>,
-    k: usize,
-    j: usize,
-}
+    /// Stem every word of `words`, in order.
+    ///
+    /// Stemmers override this to share state across the batch, such as the
+    /// buffer of the Porter stemmer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polystem::{Stemmer, S};
+    ///
+    /// assert_eq!(vec!["fly", "sun"], S::stem_all(&["flies", "suns"]));
+    /// ```
+    fn stem_all<W: AsRef<str>>(words: &[W]) -> Vec<String> {
+        words.iter().map(Self::stem).collect()
+    }
+
+    /// Stem every word of `words` across the threads of the rayon thread
+    /// pool, in order. Each thread stems its chunk of the words with
+    /// `stem_all`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polystem::{Porter, Stemmer};
+    ///
+    /// let words = vec!["rusted"; 10_000];
+    ///
+    /// assert_eq!(vec!["rust"; 10_000], Porter::stem_all_parallel(&words));
+    /// ```
+    #[cfg(feature = "rayon")]
+    fn stem_all_parallel<W: AsRef<str> + Sync>(words: &[W]) -> Vec<String> {
+        words
+            .par_chunks(PARALLEL_CHUNK)
+            .flat_map_iter(Self::stem_all)
+            .collect()
+    }
 
-impl Porter {
-    // Construct new `Porter`.
-    //
-    // The end index `k` starts counting from 1. The index `j` is a general
-    // index used during the stemming process.
-    fn new(word: &str) -> Porter {
-        Porter {
-            buf: word.to_ascii_lowercase().into_bytes(),
-            k: word.len(),
-            j: 0,
-        }
-    }
-
-    // Check if byte at index `i` is a consonant or not.
-    #[inline]
-    fn is_consonant(&self, i: usize) -> bool {
-        match self.buf[i] {
-            b'a' | b'e' | b'i' | b'o' | b'u' => false,
-            b'y' => {
-                if 0 == i {
-                    true
-                } else {
-                    !self.is_consonant(i - 1)
+    /// Stem every word of `words`, in order, stemming each distinct word only
+    /// once.
+    ///
+    /// The result is the same as [`Stemmer::stem_all`], and is faster for
+    /// natural text, whose tokens are mostly repeats of a small vocabulary.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polystem::{Porter, Stemmer};
+    ///
+    /// let words = ["the", "cats", "and", "the", "dogs", "and", "the", "cats"];
+    /// let stems = Porter::stem_all_dedup(&words);
+    ///
+    /// assert_eq!(Porter::stem_all(&words), stems);
+    /// ```
+    fn stem_all_dedup<W: AsRef<str>>(words: &[W]) -> Vec<String> {
+        let (distinct, positions) = dedup(words);
+        let mut stems = Stems::with_capacity(distinct.len(), 0);
+        Self::stem_all_into(&distinct, &mut stems);
+        positions.iter().map(|&id| stems[id].to_owned()).collect()
+    }
+
+    /// Stem every word of `words`, in order, and return the symbol of each
+    /// stem in `interner` instead of the stem itself.
+    ///
+    /// Each distinct word is stemmed once, and the only allocations are
+    /// for the result and for the stems new to `interner`. The stems are
+    /// resolved with [`Interner::resolve`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polystem::{Interner, S, Stemmer};
+    ///
+    /// let mut interner = Interner::new();
+    /// let symbols = S::stem_all_interned(&["flies", "fly"], &mut interner);
+    ///
+    /// assert_eq!(symbols[0], symbols[1]);
+    /// assert_eq!(Some("fly"), interner.resolve(symbols[0]));
+    /// ```
+    fn stem_all_interned<W: AsRef<str>>(
+        words: &[W],
+        interner: &mut Interner,
+    ) -> Vec<Symbol> {
+        let (distinct, positions) = dedup(words);
+        let mut stems = Stems::with_capacity(distinct.len(), 0);
+        Self::stem_all_into(&distinct, &mut stems);
+        let symbols: Vec<Symbol> =
+            stems.iter().map(|stem| interner.intern(stem)).collect();
+        positions.iter().map(|&id| symbols[id]).collect()
+    }
+
+    /// Append the stem of every word of `words` to `stems`, in order.
+    ///
+    /// The stems are copied into the single buffer of `stems` from a
+    /// scratch buffer, see [`Stemmer::stem_to_buf`], so a whole document is
+    /// stemmed with a handful of allocations instead of one per word.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polystem::{S, Stemmer, Stems};
+    ///
+    /// let mut stems = Stems::new();
+    /// S::stem_all_into(&["flies", "suns"], &mut stems);
+    ///
+    /// assert_eq!(vec!["fly", "sun"], stems.iter().collect::<Vec<_>>());
+    /// ```
+    fn stem_all_into<W: AsRef<str>>(words: &[W], stems: &mut Stems) {
+        let mut buf = Vec::new();
+        for word in words {
+            let word = word.as_ref();
+            if buf.len() < word.len() {
+                buf.resize(word.len(), 0);
+            }
+            loop {
+                match Self::stem_to_buf(word, &mut buf) {
+                    Ok(stem) => {
+                        stems.push(stem);
+                        break;
+                    }
+                    Err(e) => buf.resize(e.required(), 0),
                 }
             }
-            _ => true,
         }
     }
 
-    // Determines if the current stem contains a vowel between indexes
-    // `[0, j)`.
-    #[inline]
-    fn has_vowel(&self) -> bool {
-        for i in 0..self.j {
-            if !self.is_consonant(i) {
-                return true;
+    /// Stem every string of the Arrow `array`, a `StringArray` or a
+    /// `LargeStringArray`, into a new array of the same type. Null entries
+    /// stay null.
+    ///
+    /// Each distinct string is stemmed once, into a single buffer, see
+    /// [`Stemmer::stem_all_into`], and the result is built with one
+    /// allocation for its offsets and one for its bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arrow_array::{Array, StringArray};
+    /// use polystem::{Porter, Stemmer};
+    ///
+    /// let words = StringArray::from(vec![Some("rusted"), None, Some("cats")]);
+    /// let stems = Porter::stem_array(&words);
+    ///
+    /// assert_eq!("rust", stems.value(0));
+    /// assert!(stems.is_null(1));
+    /// assert_eq!("cat", stems.value(2));
+    /// ```
+    #[cfg(feature = "arrow")]
+    fn stem_array<O: OffsetSizeTrait>(
+        array: &GenericStringArray<O>,
+    ) -> GenericStringArray<O> {
+        let words: Vec<&str> =
+            array.iter().map(|word| word.unwrap_or("")).collect();
+        let (distinct, positions) = dedup(&words);
+        let mut stems = Stems::with_capacity(distinct.len(), 0);
+        Self::stem_all_into(&distinct, &mut stems);
+
+        let bytes = positions.iter().map(|&id| stems[id].len()).sum();
+        let mut builder =
+            GenericStringBuilder::<O>::with_capacity(array.len(), bytes);
+        for (i, &id) in positions.iter().enumerate() {
+            if array.is_null(i) {
+                builder.append_null();
+            } else {
+                builder.append_value(&stems[id]);
             }
         }
 
+        builder.finish()
+    }
+
+    /// Return `true` if a cheap check shows that `word` is its own stem, so
+    /// that stemming it would only copy it.
+    ///
+    /// The check may miss words that are their own stem, it never claims a
+    /// word that is not. The default implementation never claims any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polystem::{Porter, Stemmer};
+    ///
+    /// assert!(Porter::is_unchanged("stop"));
+    /// assert!(!Porter::is_unchanged("Stop"));
+    /// assert!(!Porter::is_unchanged("stopping"));
+    /// ```
+    fn is_unchanged(word: &str) -> bool {
+        let _ = word;
         false
     }
 
-    // Returns the number of consonant sequences within `[0, j)`.
-    fn count(&self) -> usize {
-        let mut i = 0;
-        let mut n = 0;
-        let j = self.j;
-        loop {
-            if i >= j {
-                return n;
-            }
-            if !self.is_consonant(i) {
-                break;
-            }
-            i += 1;
+    /// Stem `word`, borrowing it instead of allocating when
+    /// [`Stemmer::is_unchanged`] shows it is its own stem.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polystem::{S, Stemmer};
+    /// use std::borrow::Cow;
+    ///
+    /// assert!(matches!(S::stem_cow("fly"), Cow::Borrowed("fly")));
+    /// assert_eq!("fly", S::stem_cow("flies"));
+    /// ```
+    fn stem_cow(word: &str) -> Cow<'_, str> {
+        if Self::is_unchanged(word) {
+            return Cow::Borrowed(word);
         }
-        i += 1;
-        loop {
-            loop {
-                if i >= j {
-                    return n;
-                }
-                if self.is_consonant(i) {
-                    break;
-                }
-                i += 1;
-            }
+        Cow::Owned(Self::stem(word))
+    }
+
+    /// Stem the ASCII `word` into `out`, replacing its contents, without
+    /// validating UTF-8 or building a `String`.
+    ///
+    /// The word must be ASCII. Other input is stemmed safely but the stem
+    /// is unspecified: the Porter and s-stemmers treat every byte as a
+    /// character. The default implementation stems `word` with `stem` when
+    /// it is UTF-8, and copies it otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polystem::{Porter, Stemmer};
+    ///
+    /// let mut out = Vec::new();
+    /// Porter::stem_bytes(b"rusted", &mut out);
+    ///
+    /// assert_eq!(b"rust", &out[..]);
+    /// ```
+    fn stem_bytes(word: &[u8], out: &mut Vec<u8>) {
+        out.clear();
+        match str::from_utf8(word) {
+            Ok(word) => out.extend_from_slice(Self::stem(word).as_bytes()),
+            Err(_) => out.extend_from_slice(word),
+        }
+    }
+
+    /// Stem `word` into `buf` and return the stem, without allocating.
+    ///
+    /// The Porter and s-stemmers stem the word in place, so `buf` must be at
+    /// least as long as `word`. The default implementation copies the
+    /// result of `stem` into `buf`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polystem::{Porter, Stemmer};
+    ///
+    /// let mut buf = [0; 16];
+    ///
+    /// assert_eq!(Ok("rust"), Porter::stem_to_buf("rusted", &mut buf));
+    /// assert!(Porter::stem_to_buf("rusted", &mut buf[..4]).is_err());
+    /// ```
+    fn stem_to_buf<W: AsRef<str>>(
+        word: W,
+        buf: &mut [u8],
+    ) -> Result<&str, BufTooSmall> {
+        copy_to_buf(&Self::stem(word), buf)
+    }
 
-            i += 1;
-            n += 1;
+    /// Stem `word` in a buffer kept by the thread and pass the stem to `f`,
+    /// without allocating once the buffer has grown to fit the longest
+    /// word.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polystem::{Porter, Stemmer};
+    ///
+    /// let len = Porter::with_stem("connections", |stem| stem.len());
+    ///
+    /// assert_eq!(7, len);
+    /// ```
+    fn with_stem<W, F, R>(word: W, f: F) -> R
+    where
+        W: AsRef<str>,
+        F: FnOnce(&str) -> R,
+    {
+        let word = word.as_ref();
+        with_pooled_buf(|buf| {
+            if buf.len() < word.len() {
+                buf.resize(word.len(), 0);
+            }
             loop {
-                if i >= j {
-                    return n;
-                }
-                if !self.is_consonant(i) {
-                    break;
+                match Self::stem_to_buf(word, buf) {
+                    Ok(stem) => return f(stem),
+                    Err(e) => buf.resize(e.required(), 0),
                 }
-                i += 1;
             }
+        })
+    }
+}
 
-            i += 1;
-        }
+thread_local! {
+    // The buffer lent by `with_pooled_buf`.
+    static POOLED_BUF: RefCell<Vec<u8>> = const { RefCell::new(Vec::new()) };
+}
+
+// Call `f` with the buffer of the thread, or with a new buffer when it is
+// already lent, as when `f` stems another word itself.
+pub(crate) fn with_pooled_buf<F, R>(f: F) -> R
+where
+    F: FnOnce(&mut Vec<u8>) -> R,
+{
+    let mut f = Some(f);
+    let pooled = POOLED_BUF.try_with(|buf| {
+        buf.try_borrow_mut()
+            .ok()
+            .map(|mut buf| (f.take().expect("f is called once"))(&mut buf))
+    });
+    match pooled {
+        Ok(Some(result)) => result,
+        _ => (f.take().expect("f is called once"))(&mut Vec::new()),
     }
+}
 
-    // Return `true` if `[j, j-1]` contains a double consonant, `false`
-    // otherwise.
-    #[inline]
-    fn double_consonant(&self, index: usize) -> bool {
-        if index < 1 || index > self.k - 1 {
-            return false;
-        }
+// Copy `s` to the start of `buf` and return the copy.
+pub(crate) fn copy_to_buf<'b>(
+    s: &str,
+    buf: &'b mut [u8],
+) -> Result<&'b str, BufTooSmall> {
+    let out = load_buf(s, buf)?;
+    Ok(str::from_utf8(out).expect("copy of a str is valid UTF-8"))
+}
+
+// Return the distinct words of `words` in order of first appearance, and
+// the index among them of each word of `words`.
+fn dedup<W: AsRef<str>>(words: &[W]) -> (Vec<&str>, Vec<usize>) {
+    let mut ids = HashMap::new();
+    let mut distinct = Vec::new();
+    let positions = words
+        .iter()
+        .map(|word| {
+            let word = word.as_ref();
+            *ids.entry(word).or_insert_with(|| {
+                distinct.push(word);
+                distinct.len() - 1
+            })
+        })
+        .collect();
+
+    (distinct, positions)
+}
+
+// Copy `word` to the start of `buf` and return the bytes of the copy.
+pub(crate) fn load_buf<'b>(
+    word: &str,
+    buf: &'b mut [u8],
+) -> Result<&'b mut [u8], BufTooSmall> {
+    let out = buf
+        .get_mut(..word.len())
+        .ok_or_else(|| BufTooSmall::new(word.len()))?;
+    out.copy_from_slice(word.as_bytes());
+    Ok(out)
+}
 
-        if self.buf[index] != self.buf[index - 1] {
-            return false;
+// The number of words stemmed by a thread at a time in
+// `Stemmer::stem_all_parallel`.
+#[cfg(feature = "rayon")]
+const PARALLEL_CHUNK: usize = 1024;
+
+/// The maximum number of rounds of [`Stemmer::stem_fixpoint`].
+pub const FIXPOINT_ROUNDS: usize = 8;
+
+// Apply `stem` to `word`, the result of a first round of stemming, until it
+// no longer changes.
+fn fixpoint<F>(mut word: String, mut stem: F) -> String
+where
+    F: FnMut(&str) -> String,
+{
+    for _ in 1..FIXPOINT_ROUNDS {
+        let next = stem(&word);
+        if next == word {
+            break;
         }
+        word = next;
+    }
+
+    word
+}
+
+/// A stemmer that reuses its internal buffers from one word to the next.
+///
+/// The returned stem borrows from the stemmer and is valid until the next
+/// call, which avoids allocating a new `String` for every word.
+pub trait StemmerMut {
+    fn stem<W: AsRef<str>>(&mut self, word: W) -> &str;
+}
+
+// Return `true` if `word` is too short to be stemmed, that is it has at most
+// two characters.
+#[cfg(any(feature = "porter", feature = "s", feature = "snowball"))]
+pub(crate) fn is_short(word: &str) -> bool {
+    word.chars().nth(2).is_none()
+}
+
+/// An object safe counterpart to `Stemmer`, for choosing a stemmer at
+/// runtime.
+///
+/// Every `Stemmer` implements `DynStemmer`, as do `Algorithm`,
+/// `Box<dyn DynStemmer>` and `Box<dyn DynStemmer + Send + Sync>`.
+pub trait DynStemmer {
+    /// Stem `word`, see [`Stemmer::stem`].
+    fn stem_word(&self, word: &str) -> String;
+
+    /// Stem `word`, see [`Stemmer::try_stem`].
+    fn try_stem_word(&self, word: &str) -> Result<String, StemError>;
+
+    /// Return every plausible stem of `word`, see
+    /// [`Stemmer::stem_candidates`].
+    fn stem_candidates_word(&self, word: &str) -> Vec<String> {
+        vec![self.stem_word(word)]
+    }
+
+    /// Stem `word` given its part of speech, see [`Stemmer::stem_with_pos`].
+    fn stem_with_pos_word(&self, word: &str, pos: Pos) -> String {
+        let _ = pos;
+        self.stem_word(word)
+    }
 
-        self.is_consonant(index)
+    /// Stem `word` with a confidence, see [`Stemmer::stem_scored`].
+    fn stem_scored_word(&self, word: &str) -> (String, f32) {
+        (self.stem_word(word), 1.0)
     }
 
-    // Consonant - vowel - consonant. Returns `true` when the sequence `index -
-    // 2`, `index - 1`, `index` is of the form consonant, vowel, consonant. The
-    // second consonant in the sequence can not be an 'w', 'x' or 'y'.
-    #[inline]
-    fn cvc(&self, index: usize) -> bool {
-        if index < 2 || index > self.k - 1 {
-            return false;
+    /// Stem `word` until the stem no longer changes, see
+    /// [`Stemmer::stem_fixpoint`].
+    fn stem_fixpoint_word(&self, word: &str) -> String {
+        fixpoint(self.stem_word(word), |w| self.stem_word(w))
+    }
+
+    /// Stem `word` into `buf`, see [`Stemmer::stem_to_buf`].
+    fn stem_to_buf_word<'b>(
+        &self,
+        word: &str,
+        buf: &'b mut [u8],
+    ) -> Result<&'b str, BufTooSmall> {
+        copy_to_buf(&self.stem_word(word), buf)
+    }
+
+    /// Stem `word`, borrowing it when it is its own stem, see
+    /// [`Stemmer::stem_cow`].
+    fn stem_cow_word<'a>(&self, word: &'a str) -> Cow<'a, str> {
+        Cow::Owned(self.stem_word(word))
+    }
+
+    /// Stem the ASCII `word` into `out`, see [`Stemmer::stem_bytes`].
+    fn stem_bytes_word(&self, word: &[u8], out: &mut Vec<u8>) {
+        out.clear();
+        match str::from_utf8(word) {
+            Ok(word) => out.extend_from_slice(self.stem_word(word).as_bytes()),
+            Err(_) => out.extend_from_slice(word),
         }
+    }
+}
+
+impl<T: Stemmer> DynStemmer for T {
+    fn stem_word(&self, word: &str) -> String {
+        T::stem(word)
+    }
+
+    fn try_stem_word(&self, word: &str) -> Result<String, StemError> {
+        T::try_stem(word)
+    }
+
+    fn stem_candidates_word(&self, word: &str) -> Vec<String> {
+        T::stem_candidates(word)
+    }
+
+    fn stem_with_pos_word(&self, word: &str, pos: Pos) -> String {
+        T::stem_with_pos(word, pos)
+    }
+
+    fn stem_scored_word(&self, word: &str) -> (String, f32) {
+        T::stem_scored(word)
+    }
+
+    fn stem_to_buf_word<'b>(
+        &self,
+        word: &str,
+        buf: &'b mut [u8],
+    ) -> Result<&'b str, BufTooSmall> {
+        T::stem_to_buf(word, buf)
+    }
 
-        if !self.is_consonant(index)
-            || self.is_consonant(index - 1)
-            || !self.is_consonant(index - 2)
-        {
-            return false;
+    fn stem_cow_word<'a>(&self, word: &'a str) -> Cow<'a, str> {
+        T::stem_cow(word)
+    }
+
+    fn stem_bytes_word(&self, word: &[u8], out: &mut Vec<u8>) {
+        T::stem_bytes(word, out)
+    }
+}
+
+/// The stemming algorithms available in this crate.
+///
+/// `Algorithm` names a stemmer at runtime, for example when it is read from
+/// a configuration file. Only the variants of the algorithms enabled through
+/// Cargo features are present. With the `serde` feature enabled it serializes
+/// to the lowercase algorithm name (`"s"`, `"porter"`).
+///
+/// # Examples
+///
+/// ```
+/// use polystem::Algorithm;
+///
+/// let algorithm = Algorithm::Porter;
+///
+/// assert_eq!("rust", algorithm.stem("rusted"));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum Algorithm {
+    #[cfg(feature = "s")]
+    S,
+    #[cfg(feature = "porter")]
+    Porter,
+}
+
+#[cfg_attr(not(any(feature = "s", feature = "porter")), allow(unused))]
+impl Algorithm {
+    /// All of the algorithms compiled into the crate.
+    pub const ALL: &'static [Algorithm] = &[
+        #[cfg(feature = "s")]
+        Algorithm::S,
+        #[cfg(feature = "porter")]
+        Algorithm::Porter,
+    ];
+
+    /// Return the lowercase name of the algorithm, as accepted by
+    /// `Algorithm::from_str`.
+    pub fn name(self) -> &'static str {
+        match self {
+            #[cfg(feature = "s")]
+            Algorithm::S => "s",
+            #[cfg(feature = "porter")]
+            Algorithm::Porter => "porter",
         }
+    }
 
-        match self.buf[index] {
-            b'w' | b'x' | b'y' => false,
-            _ => true,
+    /// Return the name, language and rule version of the algorithm.
+    pub fn info(self) -> StemmerInfo {
+        match self {
+            #[cfg(feature = "s")]
+            Algorithm::S => S::INFO,
+            #[cfg(feature = "porter")]
+            Algorithm::Porter => Porter::INFO,
         }
     }
 
-    // Return `true` if the current buffer `self.buf` ends with the string `s`
-    // and update the index `self.j`. Return false otherwise.
-    fn ends_with(&mut self, s: &str) -> bool {
-        let end_bytes = s.as_bytes();
-        let len = end_bytes.len();
+    /// Stem `word` with the algorithm.
+    pub fn stem<W: AsRef<str>>(self, word: W) -> String {
+        match self {
+            #[cfg(feature = "s")]
+            Algorithm::S => S::stem(word),
+            #[cfg(feature = "porter")]
+            Algorithm::Porter => <Porter as Stemmer>::stem(word),
+        }
+    }
 
-        if len > self.k {
-            return false;
+    /// Stem `word` with the algorithm, see [`Stemmer::try_stem`].
+    pub fn try_stem<W: AsRef<str>>(
+        self,
+        word: W,
+    ) -> Result<String, StemError> {
+        match self {
+            #[cfg(feature = "s")]
+            Algorithm::S => S::try_stem(word),
+            #[cfg(feature = "porter")]
+            Algorithm::Porter => Porter::try_stem(word),
         }
+    }
 
-        let a: &[u8] = &self.buf[self.k - len..self.k];
-        if a != end_bytes {
-            return false;
+    /// Stem every word of `words` with the algorithm, see
+    /// [`Stemmer::stem_all`].
+    pub fn stem_all<W: AsRef<str>>(self, words: &[W]) -> Vec<String> {
+        match self {
+            #[cfg(feature = "s")]
+            Algorithm::S => S::stem_all(words),
+            #[cfg(feature = "porter")]
+            Algorithm::Porter => Porter::stem_all(words),
         }
+    }
 
-        self.j = self.k - len;
-        true
+    /// Stem every word of `words` with the algorithm, stemming each distinct
+    /// word only once, see [`Stemmer::stem_all_dedup`].
+    pub fn stem_all_dedup<W: AsRef<str>>(self, words: &[W]) -> Vec<String> {
+        match self {
+            #[cfg(feature = "s")]
+            Algorithm::S => S::stem_all_dedup(words),
+            #[cfg(feature = "porter")]
+            Algorithm::Porter => Porter::stem_all_dedup(words),
+        }
     }
 
-    // Replace characters in `[j, k)` to the string `s` and update `k`.
-    #[inline]
-    fn replace(&mut self, s: &str) {
-        let s_buf = s.as_bytes();
-        let len = s.len();
+    /// Stem every word of `words` with the algorithm and return the symbols
+    /// of the stems in `interner`, see [`Stemmer::stem_all_interned`].
+    pub fn stem_all_interned<W: AsRef<str>>(
+        self,
+        words: &[W],
+        interner: &mut Interner,
+    ) -> Vec<Symbol> {
+        match self {
+            #[cfg(feature = "s")]
+            Algorithm::S => S::stem_all_interned(words, interner),
+            #[cfg(feature = "porter")]
+            Algorithm::Porter => Porter::stem_all_interned(words, interner),
+        }
+    }
 
-        for i in 0..(len) {
-            self.buf[self.j + i] = s_buf[i];
+    /// Append the stem of every word of `words` to `stems` with the
+    /// algorithm, see [`Stemmer::stem_all_into`].
+    pub fn stem_all_into<W: AsRef<str>>(self, words: &[W], stems: &mut Stems) {
+        match self {
+            #[cfg(feature = "s")]
+            Algorithm::S => S::stem_all_into(words, stems),
+            #[cfg(feature = "porter")]
+            Algorithm::Porter => Porter::stem_all_into(words, stems),
         }
-        self.k = self.j + len;
     }
 
-    #[inline]
-    fn r(&mut self, s: &str) {
-        if self.count() > 0 {
-            self.replace(s);
+    /// Stem every string of the Arrow `array` with the algorithm, see
+    /// [`Stemmer::stem_array`].
+    #[cfg(feature = "arrow")]
+    pub fn stem_array<O: OffsetSizeTrait>(
+        self,
+        array: &GenericStringArray<O>,
+    ) -> GenericStringArray<O> {
+        match self {
+            #[cfg(feature = "s")]
+            Algorithm::S => S::stem_array(array),
+            #[cfg(feature = "porter")]
+            Algorithm::Porter => Porter::stem_array(array),
         }
     }
 
-    // Strip plurals and 'ed' or 'ing'.
-    //
-    // The following are examples of the operations performed:
-    //
-    // ```notrust
-    // caresses  ->  caress
-    // ponies    ->  poni
-    // ties      ->  ti
-    // caress    ->  caress
-    // cats      ->  cat
-    //
-    // feed      ->  feed
-    // agreed    ->  agree
-    // disabled  ->  disable
-    //
-    // matting   ->  mat
-    // mating    ->  mate
-    // meeting   ->  meet
-    // milling   ->  mill
-    // messing   ->  mess
-    //
-    // meetings  ->  meet
-    // ```
-    #[inline]
-    fn step1ab(&mut self) {
-        if b's' == self.buf[self.k - 1] {
-            if self.ends_with("sses") {
-                self.k -= 2;
-            } else if self.ends_with("ies") {
-                self.replace("i");
-            } else if b's' != self.buf[self.k - 2] {
-                self.k -= 1;
-            }
+    /// Stem every word of `words` in parallel with the algorithm, see
+    /// [`Stemmer::stem_all_parallel`].
+    #[cfg(feature = "rayon")]
+    pub fn stem_all_parallel<W: AsRef<str> + Sync>(
+        self,
+        words: &[W],
+    ) -> Vec<String> {
+        match self {
+            #[cfg(feature = "s")]
+            Algorithm::S => S::stem_all_parallel(words),
+            #[cfg(feature = "porter")]
+            Algorithm::Porter => Porter::stem_all_parallel(words),
         }
+    }
 
-        if self.ends_with("eed") {
-            if self.count() > 0 {
-                self.k -= 1;
-            }
-        } else if (self.ends_with("ed") || self.ends_with("ing"))
-            && self.has_vowel()
-        {
-            self.k = self.j;
-            if self.ends_with("at") {
-                self.replace("ate");
-            } else if self.ends_with("bl") {
-                self.replace("ble");
-            } else if self.ends_with("iz") {
-                self.replace("ize");
-            } else if self.double_consonant(self.k - 1) {
-                self.k -= 1;
-                match self.buf[self.k - 1] {
-                    b'l' | b's' | b'z' => self.k += 1,
-                    _ => (),
-                }
-            } else if 1 == self.count() && self.cvc(self.k - 1) {
-                self.replace("e");
-            }
+    /// Stem `word` with the algorithm, borrowing it when it is its own
+    /// stem, see [`Stemmer::stem_cow`].
+    pub fn stem_cow(self, word: &str) -> Cow<'_, str> {
+        match self {
+            #[cfg(feature = "s")]
+            Algorithm::S => S::stem_cow(word),
+            #[cfg(feature = "porter")]
+            Algorithm::Porter => Porter::stem_cow(word),
         }
     }
 
-    // Replace a terminal 'y' with an 'i' when there is another vowel in the
-    // stem.
-    #[inline]
-    fn step1c(&mut self) {
-        if self.ends_with("y") && self.has_vowel() {
-            self.buf[self.k - 1] = b'i';
+    /// Stem `word` with the algorithm and pass the stem to `f`, see
+    /// [`Stemmer::with_stem`].
+    pub fn with_stem<F, R>(self, word: &str, f: F) -> R
+    where
+        F: FnOnce(&str) -> R,
+    {
+        match self {
+            #[cfg(feature = "s")]
+            Algorithm::S => S::with_stem(word, f),
+            #[cfg(feature = "porter")]
+            Algorithm::Porter => Porter::with_stem(word, f),
         }
     }
 
-    // Convert double suffices into their single counterparts. For example the
-    // suffix 'ization' becomes 'ize' (also 'ation' becomes 'ate', etc). Note
-    // the string before the suffix must have a consonant-vowel-consonant
-    // greater than `0`, hence the use of `self.r`.
-    #[inline]
-    fn step2(&mut self) {
-        match self.buf[self.k - 2] {
-            b'a' => {
-                if self.ends_with("ational") {
-                    self.r("ate");
-                    return;
-                }
-                if self.ends_with("tional") {
-                    self.r("tion");
-                    return;
-                }
-            }
-            b'c' => {
-                if self.ends_with("enci") {
-                    self.r("ence");
-                    return;
-                }
-                if self.ends_with("anci") {
-                    self.r("ance");
-                    return;
-                }
-            }
-            b'e' => {
-                if self.ends_with("izer") {
-                    self.r("ize");
-                    return;
-                }
-            }
-            b'l' => {
-                if self.ends_with("bli") {
-                    self.r("ble");
-                    return;
-                }
-                if self.ends_with("alli") {
-                    self.r("al");
-                    return;
-                }
-                if self.ends_with("entli") {
-                    self.r("ent");
-                    return;
-                }
-                if self.ends_with("eli") {
-                    self.r("e");
-                    return;
-                }
-                if self.ends_with("ousli") {
-                    self.r("ous");
-                    return;
-                }
-            }
-            b'o' => {
-                if self.ends_with("ization") {
-                    self.r("ize");
-                    return;
-                }
-                if self.ends_with("ation") {
-                    self.r("ate");
-                    return;
-                }
-                if self.ends_with("ator") {
-                    self.r("ate");
-                    return;
-                }
-            }
-            b's' => {
-                if self.ends_with("alism") {
-                    self.r("al");
-                    return;
-                }
-                if self.ends_with("iveness") {
-                    self.r("ive");
-                    return;
-                }
-                if self.ends_with("fulness") {
-                    self.r("ful");
-                    return;
-                }
-                if self.ends_with("ousness") {
-                    self.r("ous");
-                    return;
-                }
-            }
-            b't' => {
-                if self.ends_with("aliti") {
-                    self.r("al");
-                    return;
-                }
-                if self.ends_with("iviti") {
-                    self.r("ive");
-                    return;
-                }
-                if self.ends_with("biliti") {
-                    self.r("ble");
-                    return;
-                }
-            }
-            b'g' => {
-                if self.ends_with("logi") {
-                    self.r("log");
-                    return;
-                }
-            }
-            _ => (),
+    /// Stem the ASCII `word` into `out` with the algorithm, see
+    /// [`Stemmer::stem_bytes`].
+    #[cfg_attr(
+        not(any(feature = "s", feature = "porter")),
+        allow(clippy::ptr_arg)
+    )]
+    pub fn stem_bytes(self, word: &[u8], out: &mut Vec<u8>) {
+        match self {
+            #[cfg(feature = "s")]
+            Algorithm::S => S::stem_bytes(word, out),
+            #[cfg(feature = "porter")]
+            Algorithm::Porter => Porter::stem_bytes(word, out),
         }
     }
 
-    // Convert double suffices into single form. Similar to `step2`, convert
-    // 'ic', 'full', 'ness', etc.
-    #[inline]
-    fn step3(&mut self) {
-        match self.buf[self.k - 1] {
-            b'e' => {
-                if self.ends_with("icate") {
-                    self.r("ic");
-                    return;
-                }
-                if self.ends_with("ative") {
-                    self.r("");
-                    return;
-                }
-                if self.ends_with("alize") {
-                    self.r("al");
-                    return;
-                }
-            }
-            b'i' => {
-                if self.ends_with("iciti") {
-                    self.r("ic");
-                    return;
-                }
-            }
-            b'l' => {
-                if self.ends_with("ical") {
-                    self.r("ic");
-                    return;
-                }
-                if self.ends_with("ful") {
-                    self.r("");
-                    return;
-                }
-            }
-            b's' => {
-                if self.ends_with("ness") {
-                    self.r("");
-                    return;
-                }
-            }
-            _ => (),
+    /// Stem `word` into `buf` with the algorithm, see
+    /// [`Stemmer::stem_to_buf`].
+    pub fn stem_to_buf<W: AsRef<str>>(
+        self,
+        word: W,
+        buf: &mut [u8],
+    ) -> Result<&str, BufTooSmall> {
+        match self {
+            #[cfg(feature = "s")]
+            Algorithm::S => S::stem_to_buf(word, buf),
+            #[cfg(feature = "porter")]
+            Algorithm::Porter => Porter::stem_to_buf(word, buf),
         }
     }
+}
 
-    // Remove 'ant', 'ence', etc when in the context '<c>vcvc<v>', where 'c' is
-    // a consonant and 'v' is a vowel, and '<.>' indicates arbitrary presence.
-    #[inline]
-    fn step4(&mut self) {
-        match self.buf[self.k - 2] {
-            b'a' => {
-                if self.ends_with("al") {
-                    // fall through
-                } else {
-                    return;
-                }
-            }
-            b'c' => {
-                if self.ends_with("ance") {
-                    // fall through
-                } else if self.ends_with("ence") {
-                    // fall through
-                } else {
-                    return;
-                }
-            }
-            b'e' => {
-                if self.ends_with("er") {
-                    // fall through
-                } else {
-                    return;
-                }
-            }
-            b'i' => {
-                if self.ends_with("ic") {
-                    // fall through
-                } else {
-                    return;
+impl fmt::Display for Algorithm {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for Algorithm {
+    type Err = ParseAlgorithmError;
+
+    fn from_str(name: &str) -> Result<Algorithm, ParseAlgorithmError> {
+        Algorithm::ALL
+            .iter()
+            .find(|a| a.name().eq_ignore_ascii_case(name))
+            .copied()
+            .ok_or_else(|| ParseAlgorithmError::new(name))
+    }
+}
+
+impl DynStemmer for Algorithm {
+    fn stem_word(&self, word: &str) -> String {
+        self.stem(word)
+    }
+
+    fn try_stem_word(&self, word: &str) -> Result<String, StemError> {
+        self.try_stem(word)
+    }
+
+    fn stem_to_buf_word<'b>(
+        &self,
+        word: &str,
+        buf: &'b mut [u8],
+    ) -> Result<&'b str, BufTooSmall> {
+        self.stem_to_buf(word, buf)
+    }
+
+    fn stem_cow_word<'a>(&self, word: &'a str) -> Cow<'a, str> {
+        self.stem_cow(word)
+    }
+
+    fn stem_bytes_word(&self, word: &[u8], out: &mut Vec<u8>) {
+        self.stem_bytes(word, out)
+    }
+}
+
+// Forward the methods of `DynStemmer` to the boxed stemmer.
+macro_rules! impl_dyn_stemmer_for_box {
+    ($($boxed:ty),*) => {
+        $(
+            impl DynStemmer for $boxed {
+                fn stem_word(&self, word: &str) -> String {
+                    (**self).stem_word(word)
                 }
-            }
-            b'l' => {
-                if self.ends_with("able") {
-                    // fall through
-                } else if self.ends_with("ible") {
-                    // fall through
-                } else {
-                    return;
+
+                fn try_stem_word(
+                    &self,
+                    word: &str,
+                ) -> Result<String, StemError> {
+                    (**self).try_stem_word(word)
                 }
-            }
-            b'n' => {
-                if self.ends_with("ant") {
-                    // fall through
-                } else if self.ends_with("ement") {
-                    // fall through
-                } else if self.ends_with("ment") {
-                    // fall through
-                } else if self.ends_with("ent") {
-                    // fall through
-                } else {
-                    return;
+
+                fn stem_candidates_word(&self, word: &str) -> Vec<String> {
+                    (**self).stem_candidates_word(word)
                 }
-            }
-            b'o' => {
-                if self.ends_with("ion")
-                    && (b's' == self.buf[self.j - 1]
-                        || b't' == self.buf[self.j - 1])
-                {
-                    // fall through
-                } else if self.ends_with("ou") {
-                    // fall through
-                } else {
-                    return;
+
+                fn stem_with_pos_word(&self, word: &str, pos: Pos) -> String {
+                    (**self).stem_with_pos_word(word, pos)
                 }
-            }
-            b's' => {
-                if self.ends_with("ism") {
-                    // fall through
-                } else {
-                    return;
+
+                fn stem_scored_word(&self, word: &str) -> (String, f32) {
+                    (**self).stem_scored_word(word)
                 }
-            }
-            b't' => {
-                if self.ends_with("ate") {
-                    // fall through
-                } else if self.ends_with("iti") {
-                    // fall through
-                } else {
-                    return;
+
+                fn stem_fixpoint_word(&self, word: &str) -> String {
+                    (**self).stem_fixpoint_word(word)
                 }
-            }
-            b'u' => {
-                if self.ends_with("ous") {
-                    // fall through
-                } else {
-                    return;
+
+                fn stem_to_buf_word<'b>(
+                    &self,
+                    word: &str,
+                    buf: &'b mut [u8],
+                ) -> Result<&'b str, BufTooSmall> {
+                    (**self).stem_to_buf_word(word, buf)
                 }
-            }
-            b'v' => {
-                if self.ends_with("ive") {
-                    // fall through
-                } else {
-                    return;
+
+                fn stem_cow_word<'a>(&self, word: &'a str) -> Cow<'a, str> {
+                    (**self).stem_cow_word(word)
                 }
-            }
-            b'z' => {
-                if self.ends_with("ize") {
-                    // fall through
-                } else {
-                    return;
+
+                fn stem_bytes_word(&self, word: &[u8], out: &mut Vec<u8>) {
+                    (**self).stem_bytes_word(word, out)
                 }
             }
-            _ => return,
-        }
+        )*
+    };
+}
 
-        if self.count() > 1 {
-            self.k = self.j;
-        }
-    }
+impl_dyn_stemmer_for_box!(
+    Box<dyn DynStemmer>,
+    Box<dyn DynStemmer + Send + Sync>
+);
+
+/// The part of speech of a word, used as a hint by
+/// [`Stemmer::stem_with_pos`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum Pos {
+    Noun,
+    Verb,
+    Adj,
+    Adv,
+}
 
-    // Remove terminal 'e' if `count() > 1`; and map 'll' to 'l' if
-    // `count() > 1`.
-    #[inline]
-    fn step5(&mut self) {
-        self.j = self.k;
-        if b'e' == self.buf[self.k - 1] {
-            let c = self.count();
-            if c > 1 || c == 1 && !self.cvc(self.k - 2) {
-                self.k -= 1;
-            }
-        }
+/// The languages supported by the stemmers in this crate.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+#[non_exhaustive]
+pub enum Language {
+    #[cfg(any(feature = "porter", feature = "s"))]
+    English,
+}
 
-        if b'l' == self.buf[self.k - 1]
-            && self.double_consonant(self.k - 1)
-            && self.count() > 1
-        {
-            self.k -= 1;
+impl Language {
+    /// Every language compiled into the crate.
+    pub const ALL: &'static [Language] = &[
+        #[cfg(any(feature = "porter", feature = "s"))]
+        Language::English,
+    ];
+
+    /// Return the lowercase English name of the language, the name it is
+    /// serialized to with the `serde` feature.
+    pub fn name(self) -> &'static str {
+        match self {
+            #[cfg(any(feature = "porter", feature = "s"))]
+            Language::English => "english",
         }
     }
 
-    // Return the resulting stem as a `String`.
-    fn _stem(&self) -> String {
-        unsafe { str::from_utf8_unchecked(&self.buf[..self.k]).to_owned() }
+    /// Return the two letter ISO 639-1 code of the language, such as
+    /// `"en"`, which begins the language tags of documents in it.
+    pub fn code(self) -> &'static str {
+        match self {
+            #[cfg(any(feature = "porter", feature = "s"))]
+            Language::English => "en",
+        }
     }
-}
-
-impl Stemmer for Porter {
-    /// Porter stemming algorithm.
-    ///
-    /// This version was derived from the C version published at
-    /// [tartarus.org/martin/PorterStemmer][tartarus]
-    ///
-    /// >Porter, 1980, An algorithm for suffix stripping, Program, Vol. 14,
-    /// >No. 3, pp 130-137
-    ///
-    /// [tartarus]: https://tartarus.org/martin/PorterStemmer/
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use polystem::{Stemmer, Porter};
-    ///
-    /// let term = "rusted";
-    /// let stem = Porter::stem(&term);
-    ///
-    /// assert_eq!("rust", stem);
-    /// ```
-    fn stem(word: &str) -> String {
-        if word.len() > 2 {
-            let mut porter = Porter::new(word);
-            porter.step1ab();
-            porter.step1c();
-            porter.step2();
-            porter.step3();
-            porter.step4();
-            porter.step5();
 
-            return porter._stem();
+    /// The algorithm used for the language by [`stemmer_for`].
+    pub fn algorithm(self) -> Algorithm {
+        match self {
+            #[cfg(feature = "porter")]
+            Language::English => Algorithm::Porter,
+            #[cfg(all(feature = "s", not(feature = "porter")))]
+            Language::English => Algorithm::S,
         }
-
-        String::from(word)
     }
 }
 
+/// Return the stemmer for `lang`.
+///
+/// # Examples
+///
+/// ```
+/// use polystem::{stemmer_for, Language};
+///
+/// let stemmer = stemmer_for(Language::English);
+///
+/// assert_eq!("rust", stemmer.stem_word("rusted"));
+/// ```
+#[cfg_attr(
+    not(any(feature = "s", feature = "porter")),
+    allow(unreachable_code)
+)]
+pub fn stemmer_for(lang: Language) -> Box<dyn DynStemmer> {
+    Box::new(lang.algorithm())
+}
+
+// The helpers a build uses depend on the stemmers its features compile in.
 #[cfg(test)]
+#[allow(dead_code)]
 mod fixture_test;
 
-#[cfg(test)]
+#[cfg(all(test, feature = "english"))]
 mod tests {
     use super::*;
-    use fixture_test::*;
+    use std::borrow::Cow;
 
     #[test]
-    fn test_s_stem() {
-        for (i, _) in S_WORDS.iter().enumerate() {
-            let word = S_WORDS[i];
-            let expected = S_STEMS[i];
+    fn test_algorithm_stem() {
+        assert_eq!(Algorithm::S.stem("flies"), S::stem("flies"));
+        assert_eq!(
+            Algorithm::Porter.stem("flies"),
+            <Porter as Stemmer>::stem("flies")
+        );
+    }
 
-            assert_eq!(S::stem(&word), expected);
+    #[test]
+    fn test_algorithm_from_str() {
+        for algorithm in Algorithm::ALL {
+            assert_eq!(algorithm.name().parse(), Ok(*algorithm));
         }
+        assert_eq!("Porter".parse(), Ok(Algorithm::Porter));
+        assert!("lovins".parse::<Algorithm>().is_err());
     }
 
     #[test]
-    fn test_is_consonant() {
-        let p = Porter::new("y");
-        assert_eq!(p.is_consonant(0), true);
-
-        let p = Porter::new("ey");
-        assert_eq!(p.is_consonant(1), true);
-
-        let p = Porter::new("ly");
-        assert_eq!(p.is_consonant(1), false);
-
-        let p = Porter::new("aeiou");
-        for (i, _) in p.buf.iter().enumerate() {
-            assert_eq!(p.is_consonant(i), false);
-        }
-        let p = Porter::new("bcdfghjklmnpqrstvwxz");
-        for (i, _) in p.buf.iter().enumerate() {
-            assert_eq!(p.is_consonant(i), true);
+    fn test_algorithm_info() {
+        for algorithm in Algorithm::ALL {
+            let info = algorithm.info();
+            assert_eq!(info.name, algorithm.name());
+            assert_eq!(info.language, Language::English);
         }
+        assert!(!S::INFO.is_compatible(&Porter::INFO));
     }
 
     #[test]
-    fn test_has_vowel() {
-        let mut p = Porter::new("follow");
-        p.j = 2;
-        assert_eq!(p.has_vowel(), true);
+    fn test_stemmer_for() {
+        let stemmers: Vec<Box<dyn DynStemmer>> =
+            vec![Box::new(S), stemmer_for(Language::English)];
 
-        let p = Porter::new("fllw");
-        assert_eq!(p.has_vowel(), false);
+        assert_eq!(stemmers[0].stem_word("flies"), "fly");
+        assert_eq!(stemmers[1].stem_word("flies"), "fli");
+        assert_eq!(stemmers[1].try_stem_word(""), Err(StemError::Empty));
     }
 
     #[test]
-    fn test_count() {
-        let p = Porter::new("be");
-        assert_eq!(p.count(), 0);
-
-        let mut p = Porter::new("beb");
-        p.j = 3;
-        assert_eq!(p.count(), 1);
-
-        let mut p = Porter::new("bebebe");
-        p.j = 6;
-        assert_eq!(p.count(), 2);
-
-        let mut p = Porter::new("bebebebe");
-        p.j = 8;
-        assert_eq!(p.count(), 3);
+    fn test_try_stem() {
+        assert_eq!(S::try_stem("flies"), Ok(String::from("fly")));
+        assert_eq!(Porter::try_stem(""), Err(StemError::Empty));
+        assert_eq!(Porter::try_stem("naïve"), Err(StemError::NonAscii));
+        assert_eq!(
+            Algorithm::S.try_stem("γάτες"),
+            Err(StemError::UnsupportedScript)
+        );
     }
 
     #[test]
-    fn test_double_consonant() {
-        let p = Porter::new("be");
-        assert_eq!(p.double_consonant(0), false);
-
-        let p = Porter::new("bbee");
-        assert_eq!(p.double_consonant(1), true);
+    fn test_stem_candidates() {
+        assert_eq!(Porter::stem_candidates("rusted"), vec!["rust"]);
 
-        let p = Porter::new("bbee");
-        assert_eq!(p.double_consonant(2), false);
-
-        let p = Porter::new("bbee");
-        assert_eq!(p.double_consonant(3), false);
+        let stemmer = stemmer_for(Language::English);
+        assert_eq!(stemmer.stem_candidates_word("rusted"), vec!["rust"]);
+    }
 
-        let p = Porter::new("bbee");
-        assert_eq!(p.double_consonant(4), false);
+    #[test]
+    fn test_stem_with_pos() {
+        for pos in &[Pos::Noun, Pos::Verb, Pos::Adj, Pos::Adv] {
+            assert_eq!(S::stem_with_pos("flies", *pos), "fly");
+            assert_eq!(S.stem_with_pos_word("flies", *pos), "fly");
+        }
     }
 
     #[test]
-    fn test_cvc() {
-        let p = Porter::new("bab");
-        assert_eq!(p.cvc(0), false);
+    fn test_stem_scored() {
+        assert_eq!(S::stem_scored("flies"), (String::from("fly"), 1.0));
 
-        let p = Porter::new("bab");
-        assert_eq!(p.cvc(1), false);
+        let stemmer = stemmer_for(Language::English);
+        assert_eq!(stemmer.stem_scored_word("flies"), ("fli".into(), 1.0));
+    }
 
-        let p = Porter::new("bab");
-        assert_eq!(p.cvc(2), true);
+    #[test]
+    fn test_vocabularies() {
+        let names = fixture_test::names();
+        assert!(names.iter().any(|name| name == "porter"));
+        for name in names {
+            let stemmer = by_name(&name).unwrap_or_else(|_| {
+                panic!("no stemmer for the vocabulary `{}`", name)
+            });
+            let vocabulary = fixture_test::Vocabulary::load(&name);
+            vocabulary.check(|w| stemmer.stem_word(w), |_, _| false);
+        }
+    }
 
-        let p = Porter::new("bab");
-        assert_eq!(p.cvc(3), false);
+    #[test]
+    fn test_stem_fixpoint() {
+        for word in fixture_test::Vocabulary::load("porter").words() {
+            let stem = Porter::stem_fixpoint(word);
+            assert_eq!(<Porter as Stemmer>::stem(&stem), stem);
+            assert_eq!(Algorithm::Porter.stem_fixpoint_word(word), stem);
+        }
+        assert_eq!(Porter::stem_fixpoint("agreed"), "agr");
+        assert_eq!(S::stem_fixpoint("flies"), "fly");
+        assert_eq!(
+            fixpoint(String::from("a"), |w| format!("{}a", w)).len(),
+            8
+        );
+    }
 
-        let p = Porter::new("cave");
-        assert_eq!(p.cvc(2), true);
+    #[test]
+    fn test_stem_all() {
+        let words = vec![String::from("Flies"), String::from("rusted")];
+        for algorithm in Algorithm::ALL {
+            let stems: Vec<String> =
+                words.iter().map(|w| algorithm.stem(w)).collect();
+            assert_eq!(algorithm.stem_all(&words), stems);
+
+            let mut packed = Stems::new();
+            algorithm.stem_all_into(&words, &mut packed);
+            assert_eq!(packed.iter().collect::<Vec<_>>(), stems);
+
+            let repeated: Vec<&String> =
+                words.iter().cycle().take(7).collect();
+            assert_eq!(
+                algorithm.stem_all_dedup(&repeated),
+                algorithm.stem_all(&repeated)
+            );
+        }
+        assert!(S::stem_all::<&str>(&[]).is_empty());
+    }
 
-        let p = Porter::new("lov");
-        assert_eq!(p.cvc(2), true);
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_stem_all_parallel() {
+        let porter = fixture_test::Vocabulary::load("porter");
+        let words: Vec<&str> = porter
+            .words()
+            .into_iter()
+            .cycle()
+            .take(3 * PARALLEL_CHUNK + 7)
+            .collect();
+        for algorithm in Algorithm::ALL {
+            assert_eq!(
+                algorithm.stem_all_parallel(&words),
+                algorithm.stem_all(&words)
+            );
+        }
+    }
 
-        let p = Porter::new("hop");
-        assert_eq!(p.cvc(2), true);
+    #[cfg(feature = "arrow")]
+    #[test]
+    fn test_stem_array() {
+        use arrow_array::{LargeStringArray, StringArray};
+
+        let words = vec![Some("Flies"), None, Some(""), Some("flies"), None];
+        for algorithm in Algorithm::ALL {
+            let expected: Vec<_> = words
+                .iter()
+                .map(|word| word.map(|word| algorithm.stem(word)))
+                .collect();
+
+            let stems =
+                algorithm.stem_array(&StringArray::from(words.clone()));
+            let stems: Vec<_> =
+                stems.iter().map(|stem| stem.map(str::to_owned)).collect();
+            assert_eq!(stems, expected);
+
+            let large = LargeStringArray::from(words.clone());
+            let stems = algorithm.stem_array(&large);
+            assert_eq!(stems.null_count(), 2);
+            assert_eq!(stems.value(3), expected[3].as_deref().unwrap());
+        }
+    }
 
-        let p = Porter::new("crim");
-        assert_eq!(p.cvc(3), true);
+    #[test]
+    fn test_stem_to_buf() {
+        let mut buf = [0; 32];
+        for algorithm in Algorithm::ALL {
+            for word in &["Flies", "generalizations", "cafés", "is", ""] {
+                let stem = algorithm.stem(word);
+                assert_eq!(algorithm.stem_to_buf(word, &mut buf), Ok(&*stem));
+                assert_eq!(
+                    algorithm.stem_to_buf_word(word, &mut buf),
+                    Ok(&*stem)
+                );
+            }
+            assert_eq!(
+                algorithm.stem_to_buf("flies", &mut buf[..4]),
+                Err(BufTooSmall::new(5))
+            );
+        }
 
-        let p = Porter::new("snow");
-        assert_eq!(p.cvc(3), false);
+        let stemmer = Configured::new(S, StemOptions::default());
+        assert_eq!(stemmer.stem_to_buf_word("Flies", &mut buf), Ok("fly"));
+        assert_eq!(
+            stemmer.stem_to_buf_word("Flies", &mut buf[..2]),
+            Err(BufTooSmall::new(3))
+        );
+    }
 
-        let p = Porter::new("box");
-        assert_eq!(p.cvc(2), false);
+    #[test]
+    fn test_with_stem() {
+        for algorithm in Algorithm::ALL {
+            let long = "internationalizations".repeat(4);
+            for word in &["Flies", "is", "", &long] {
+                let stem = algorithm.stem(word);
+                assert_eq!(algorithm.with_stem(word, str::to_owned), stem);
+            }
+        }
 
-        let p = Porter::new("tray");
-        assert_eq!(p.cvc(3), false);
+        // A stem taken while the buffer of the thread is lent.
+        let stems = Porter::with_stem("rusted", |outer| {
+            S::with_stem("flies", |inner| format!("{} {}", outer, inner))
+        });
+        assert_eq!(stems, "rust fly");
     }
 
     #[test]
-    fn test_ends_with() {
-        let mut p = Porter::new("session");
-        assert_eq!(p.ends_with("ion"), true);
+    fn test_stem_bytes() {
+        let mut out = Vec::new();
+        for algorithm in Algorithm::ALL {
+            for word in &["Flies", "generalizations", "is", ""] {
+                let stem = algorithm.stem(word);
+                algorithm.stem_bytes(word.as_bytes(), &mut out);
+                assert_eq!(out, stem.as_bytes());
+                algorithm.stem_bytes_word(word.as_bytes(), &mut out);
+                assert_eq!(out, stem.as_bytes());
+            }
+        }
 
-        let mut p = Porter::new("session");
-        assert_eq!(p.ends_with("ions"), false);
+        let stemmer = Configured::new(S, StemOptions::default());
+        stemmer.stem_bytes_word(b"Flies", &mut out);
+        assert_eq!(out, b"fly");
+        stemmer.stem_bytes_word(b"\xffs", &mut out);
+        assert_eq!(out, b"\xffs");
+    }
 
-        let mut p = Porter::new("s");
-        assert_eq!(p.ends_with("s"), true);
+    #[test]
+    fn test_short_words() {
+        let letters = ["", "a", "s", "A", "S", "é", "É", "ß", "я", "'"];
+        for a in letters.iter() {
+            for b in letters.iter() {
+                let word = format!("{}{}", a, b);
+                for algorithm in Algorithm::ALL {
+                    assert_eq!(algorithm.stem(&word), word);
+                }
+            }
+        }
+        assert_eq!(S::try_stem(""), Err(StemError::Empty));
+        assert_eq!(S::stem("ies"), "y");
     }
 
     #[test]
-    fn test_replace() {
-        let mut p = Porter::new("session");
-        p.j = 4;
-        p.replace("bar");
-        assert_eq!(p.buf, b"sessbar");
+    fn test_stem_as_ref() {
+        let word = String::from("flies");
+        let cow: Cow<str> = Cow::Borrowed("flies");
+
+        assert_eq!(S::stem(&word), "fly");
+        assert_eq!(S::stem(cow), "fly");
+        assert_eq!(Algorithm::Porter.stem(word), "fli");
     }
 
+    #[cfg(feature = "serde")]
     #[test]
-    fn test_porter_stem() {
-        for (i, _) in PORTER_WORDS.iter().enumerate() {
-            let word = PORTER_WORDS[i];
-            let expected = PORTER_STEMS[i];
+    fn test_algorithm_serde() {
+        let json = serde_json::to_string(&Algorithm::Porter).unwrap();
+        assert_eq!(json, "\"porter\"");
 
-            assert_eq!(Porter::stem(&word), expected);
-        }
+        let algorithm: Algorithm = serde_json::from_str("\"s\"").unwrap();
+        assert_eq!(algorithm, Algorithm::S);
     }
 }
@@ -0,0 +1,337 @@
+// Copyright 2019 The Polystem authors.
+//
+// For the full copyright and license information, please view the LICENSE file
+// that was distributed with this source code.
+
+use std::borrow::Cow;
+
+// The elements whose tags are removed without separating the text around
+// them, since they mark up parts of words as often as whole words.
+const INLINE: &[&str] = &[
+    "a", "abbr", "b", "bdi", "bdo", "cite", "code", "data", "dfn", "em",
+    "font", "i", "kbd", "mark", "q", "s", "samp", "small", "span", "strong",
+    "sub", "sup", "time", "tt", "u", "var", "wbr",
+];
+
+// The elements whose content is not text.
+const RAW: &[&str] = &["script", "style"];
+
+// The named character references decoded, with their characters.
+const ENTITIES: &[(&str, &str)] = &[
+    ("amp", "&"),
+    ("lt", "<"),
+    ("gt", ">"),
+    ("quot", "\""),
+    ("apos", "'"),
+    ("nbsp", "\u{a0}"),
+    ("shy", ""),
+    ("ndash", "\u{2013}"),
+    ("mdash", "\u{2014}"),
+    ("hellip", "\u{2026}"),
+    ("lsquo", "\u{2018}"),
+    ("rsquo", "\u{2019}"),
+    ("ldquo", "\u{201c}"),
+    ("rdquo", "\u{201d}"),
+    ("laquo", "\u{ab}"),
+    ("raquo", "\u{bb}"),
+    ("bull", "\u{2022}"),
+    ("middot", "\u{b7}"),
+    ("copy", "\u{a9}"),
+    ("reg", "\u{ae}"),
+    ("trade", "\u{2122}"),
+    ("deg", "\u{b0}"),
+    ("times", "\u{d7}"),
+    ("euro", "\u{20ac}"),
+    ("pound", "\u{a3}"),
+    ("aacute", "á"),
+    ("agrave", "à"),
+    ("acirc", "â"),
+    ("auml", "ä"),
+    ("aring", "å"),
+    ("ccedil", "ç"),
+    ("eacute", "é"),
+    ("egrave", "è"),
+    ("ecirc", "ê"),
+    ("euml", "ë"),
+    ("iacute", "í"),
+    ("icirc", "î"),
+    ("iuml", "ï"),
+    ("ntilde", "ñ"),
+    ("oacute", "ó"),
+    ("ocirc", "ô"),
+    ("ouml", "ö"),
+    ("oslash", "ø"),
+    ("uacute", "ú"),
+    ("ucirc", "û"),
+    ("uuml", "ü"),
+    ("szlig", "ß"),
+];
+
+/// Remove the HTML or XML tags of `text` and decode its character
+/// references, leaving the text a reader sees.
+///
+/// Tags become spaces, so that the words of adjacent paragraphs or cells
+/// stay apart, except for those of inline elements such as `<b>` and
+/// `<span>`, which are removed. Comments and the content of `<script>` and
+/// `<style>` are removed, and the content of CDATA sections kept. Named
+/// references for the markup characters, punctuation and common accented
+/// letters are decoded, as are numeric references, and others are left as
+/// they are. A `<` that does not start a tag, as in `"a < b"`, is text.
+///
+/// # Examples
+///
+/// ```
+/// use polystem::strip_markup;
+///
+/// assert_eq!(
+///     " Caf\u{e9}s  &amp; bars ",
+///     strip_markup("<p>Caf&eacute;s</p><p>&amp;amp; <b>b</b>ars</p>")
+/// );
+/// assert_eq!("1 < 2 ", strip_markup("1 < 2<!-- note -->"));
+/// ```
+pub fn strip_markup(text: &str) -> Cow<'_, str> {
+    if !text.contains(['<', '&']) {
+        return Cow::Borrowed(text);
+    }
+    Cow::Owned(Stripped::new(text).text)
+}
+
+// A piece of stripped text and the markup it was made from.
+#[derive(Debug)]
+#[cfg_attr(not(feature = "tokenize"), allow(dead_code))]
+struct Segment {
+    // The offset of the piece in the stripped text.
+    out: usize,
+    // The offset and length of the markup.
+    src: usize,
+    src_len: usize,
+    // Whether the piece is a copy of the markup, byte for byte.
+    verbatim: bool,
+}
+
+// Text stripped of its markup, with the offsets of the markup each piece of
+// it came from.
+#[derive(Debug)]
+pub(crate) struct Stripped {
+    pub(crate) text: String,
+    segments: Vec<Segment>,
+}
+
+impl Stripped {
+    // Strip `markup` as by `strip_markup`.
+    pub(crate) fn new(markup: &str) -> Stripped {
+        let mut stripped = Stripped {
+            text: String::with_capacity(markup.len()),
+            segments: Vec::new(),
+        };
+        let mut i = 0;
+        while i < markup.len() {
+            let rest = &markup[i..];
+            if let Some(content) = rest.strip_prefix("<![CDATA[") {
+                let (len, end) = match content.find("]]>") {
+                    Some(len) => (len, len + 3),
+                    None => (content.len(), content.len()),
+                };
+                stripped.push(&content[..len], i + 9, len, true);
+                i += 9 + end;
+                continue;
+            }
+            let (len, out, verbatim) = match rest.as_bytes()[0] {
+                b'<' => match tag(rest) {
+                    Some((len, out)) => (len, Cow::Borrowed(out), false),
+                    None => (1, Cow::Borrowed("<"), true),
+                },
+                b'&' => match reference(rest) {
+                    Some((len, out)) => (len, out, false),
+                    None => (1, Cow::Borrowed("&"), true),
+                },
+                _ => {
+                    let len = rest.find(['<', '&']).unwrap_or(rest.len());
+                    (len, Cow::Borrowed(&rest[..len]), true)
+                }
+            };
+            stripped.push(&out, i, len, verbatim);
+            i += len;
+        }
+        stripped
+    }
+
+    // Append `out`, made from the `len` bytes of markup at `src`.
+    fn push(&mut self, out: &str, src: usize, len: usize, verbatim: bool) {
+        if out.is_empty() {
+            return;
+        }
+        self.segments.push(Segment {
+            out: self.text.len(),
+            src,
+            src_len: len,
+            verbatim,
+        });
+        self.text.push_str(out);
+    }
+
+    // Return the offset in the markup of the start of a token starting at
+    // `pos` in the stripped text.
+    #[cfg_attr(not(feature = "tokenize"), allow(dead_code))]
+    pub(crate) fn start(&self, pos: usize) -> usize {
+        let i = self.segments.partition_point(|s| s.out <= pos);
+        match self.segments.get(i.wrapping_sub(1)) {
+            Some(s) if s.verbatim => s.src + pos - s.out,
+            Some(s) => s.src,
+            None => pos,
+        }
+    }
+
+    // Return the offset in the markup of the end of a token ending at `pos`
+    // in the stripped text.
+    #[cfg_attr(not(feature = "tokenize"), allow(dead_code))]
+    pub(crate) fn end(&self, pos: usize) -> usize {
+        let i = self.segments.partition_point(|s| s.out < pos);
+        match self.segments.get(i.wrapping_sub(1)) {
+            Some(s) if s.verbatim => s.src + pos - s.out,
+            Some(s) => s.src + s.src_len,
+            None => pos,
+        }
+    }
+}
+
+// Return the length of the comment or tag that `markup` starts with, and
+// the text it leaves.
+fn tag(markup: &str) -> Option<(usize, &'static str)> {
+    if let Some(rest) = markup.strip_prefix("<!--") {
+        let len = rest.find("-->").map_or(rest.len(), |end| end + 3);
+        return Some((4 + len, " "));
+    }
+
+    let body = &markup[1..];
+    let closing = body.starts_with('/');
+    let name = body.trim_start_matches('/');
+    if !name
+        .starts_with(|c: char| c.is_ascii_alphabetic() || c == '!' || c == '?')
+    {
+        return None;
+    }
+    let len = tag_len(markup)?;
+    let name_len = name
+        .find(|c: char| !c.is_ascii_alphanumeric() && c != '-' && c != ':')
+        .unwrap_or(name.len());
+    let name = name[..name_len].to_ascii_lowercase();
+
+    let self_closing = markup[..len].ends_with("/>");
+    if !closing && !self_closing && RAW.contains(&name.as_str()) {
+        // Skip to the end of the closing tag of the element.
+        let end = format!("</{}", name);
+        let content = &markup[len..];
+        let close = content
+            .char_indices()
+            .find(|&(i, _)| {
+                content[i..]
+                    .get(..end.len())
+                    .is_some_and(|tag| tag.eq_ignore_ascii_case(&end))
+            })
+            .map(|(i, _)| i);
+        let skipped = match close {
+            Some(i) => i + tag_len(&content[i..]).unwrap_or(content.len() - i),
+            None => content.len(),
+        };
+        return Some((len + skipped, " "));
+    }
+    let out = if INLINE.contains(&name.as_str()) {
+        ""
+    } else {
+        " "
+    };
+    Some((len, out))
+}
+
+// Return the length of the tag that `markup` starts with, up to its `>`
+// outside quoted attribute values.
+fn tag_len(markup: &str) -> Option<usize> {
+    let mut quote = None;
+    for (i, c) in markup.char_indices() {
+        match (quote, c) {
+            (None, '>') => return Some(i + 1),
+            (None, '"' | '\'') => quote = Some(c),
+            (Some(q), c) if c == q => quote = None,
+            _ => {}
+        }
+    }
+    None
+}
+
+// Return the length of the character reference that `markup` starts with,
+// and its characters. Invalid numeric references decode to the replacement
+// character, as in HTML.
+fn reference(markup: &str) -> Option<(usize, Cow<'static, str>)> {
+    let end = markup
+        .char_indices()
+        .skip(1)
+        .take(32)
+        .find(|&(_, c)| !c.is_ascii_alphanumeric() && c != '#')
+        .filter(|&(_, c)| c == ';')?
+        .0;
+    let name = &markup[1..end];
+    if let Some(number) = name.strip_prefix('#') {
+        let code = match number.strip_prefix(['x', 'X']) {
+            Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+            None => number.parse().ok()?,
+        };
+        let c = char::from_u32(code).filter(|&c| c != '\0');
+        let c = c.unwrap_or('\u{fffd}');
+        return Some((end + 1, Cow::Owned(c.to_string())));
+    }
+    ENTITIES
+        .iter()
+        .find(|(entity, _)| *entity == name)
+        .map(|&(_, text)| (end + 1, Cow::Borrowed(text)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_strip_markup() {
+        let cases = [
+            ("plain", "plain"),
+            ("<p>a</p><p>b</p>", " a  b "),
+            ("un<em>believ</em>able", "unbelievable"),
+            ("<a href='x>y'>link</a>", "link"),
+            ("<br/>x<img src=\"a.png\" />", " x "),
+            ("a<!-- <p>hidden</p> -->b", "a b"),
+            ("<![CDATA[x < y]]>", "x < y"),
+            ("<script>if (a<b) {}</script>text", " text"),
+            ("<STYLE type=\"text/css\">p {}</Style >x", " x"),
+            ("<!DOCTYPE html><?xml?>x", "  x"),
+            ("1 < 2 and 3 <= 4 </ 5", "1 < 2 and 3 <= 4 </ 5"),
+            ("<p unclosed", "<p unclosed"),
+            ("&lt;b&gt; &amp;amp; &#65;&#x42;&#X43;", "<b> &amp; ABC"),
+            ("hy&shy;phen", "hyphen"),
+            (
+                "&unknown; & &amp &#xzz; &#0;",
+                "&unknown; & &amp &#xzz; \u{fffd}",
+            ),
+            ("caf&eacute; na&iuml;ve", "café naïve"),
+        ];
+        for &(markup, text) in &cases {
+            assert_eq!(strip_markup(markup), text, "{}", markup);
+        }
+        assert!(matches!(strip_markup("a > b"), Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn test_offsets() {
+        let markup = "<p>caf&eacute; <b>bar</b>s</p>";
+        let stripped = Stripped::new(markup);
+        assert_eq!(stripped.text, " café bars ");
+        // "café" starts at 1 and ends at 6 in the stripped text.
+        assert_eq!(&markup[stripped.start(1)..stripped.end(6)], "caf&eacute;");
+        assert_eq!(&markup[stripped.start(1)..stripped.end(4)], "caf");
+        let (start, end) = (stripped.start(7), stripped.end(11));
+        assert_eq!(&markup[start..end], "bar</b>s");
+
+        let stripped = Stripped::new("<x><![CDATA[a b]]>");
+        assert_eq!(stripped.text, " a b");
+        assert_eq!((stripped.start(3), stripped.end(4)), (14, 15));
+    }
+}
@@ -0,0 +1,165 @@
+// Copyright 2019 The Polystem authors.
+//
+// For the full copyright and license information, please view the LICENSE file
+// that was distributed with this source code.
+
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::{DynStemmer, Language};
+
+type BoxedStemmer = Box<dyn DynStemmer + Send + Sync>;
+
+/// A stemmer for multilingual text, holding a stemmer for each language and
+/// stemming each word or document with the stemmer of its language tag.
+///
+/// Tags are compared without regard to ASCII case, and a tag with no
+/// stemmer of its own, such as `"en-GB"`, falls back to its primary
+/// language, `"en"`. Words of a language with no stemmer are stemmed by the
+/// [`fallback`](MultiStemmer::fallback) stemmer, or else returned
+/// unchanged.
+///
+/// # Examples
+///
+/// ```
+/// use polystem::{Algorithm, MultiStemmer};
+///
+/// let mut stemmer = MultiStemmer::builtin();
+/// stemmer.insert("en-x-light", Algorithm::S);
+///
+/// assert_eq!("rust", stemmer.stem_word("en-US", "rusted"));
+/// assert_eq!("pony", stemmer.stem_word("en-x-light", "ponies"));
+/// assert_eq!("chevaux", stemmer.stem_word("fr", "chevaux"));
+/// ```
+#[derive(Default)]
+pub struct MultiStemmer {
+    stemmers: HashMap<String, BoxedStemmer>,
+    fallback: Option<BoxedStemmer>,
+}
+
+impl MultiStemmer {
+    /// Construct a `MultiStemmer` with no languages.
+    pub fn new() -> MultiStemmer {
+        MultiStemmer::default()
+    }
+
+    /// Construct a `MultiStemmer` with the algorithm of each [`Language`]
+    /// compiled into the crate, under its [`code`](Language::code).
+    #[cfg_attr(
+        not(any(feature = "s", feature = "porter")),
+        allow(unreachable_code)
+    )]
+    pub fn builtin() -> MultiStemmer {
+        let mut stemmer = MultiStemmer::new();
+        for &language in Language::ALL {
+            stemmer.insert(language.code(), language.algorithm());
+        }
+        stemmer
+    }
+
+    /// Stem the words of languages with no stemmer with `stemmer`.
+    pub fn fallback<D>(mut self, stemmer: D) -> MultiStemmer
+    where
+        D: DynStemmer + Send + Sync + 'static,
+    {
+        self.fallback = Some(Box::new(stemmer));
+        self
+    }
+
+    /// Stem the words tagged `tag` with `stemmer`, replacing an earlier
+    /// stemmer of `tag`.
+    pub fn insert<D>(&mut self, tag: &str, stemmer: D)
+    where
+        D: DynStemmer + Send + Sync + 'static,
+    {
+        self.stemmers
+            .insert(tag.to_ascii_lowercase(), Box::new(stemmer));
+    }
+
+    /// Return the stemmer of the language tagged `tag`, which is the
+    /// fallback stemmer if the language has none of its own.
+    pub fn stemmer(
+        &self,
+        tag: &str,
+    ) -> Option<&(dyn DynStemmer + Send + Sync)> {
+        let tag = tag.to_ascii_lowercase();
+        let primary = tag.split(['-', '_']).next();
+        self.stemmers
+            .get(&tag)
+            .or_else(|| self.stemmers.get(primary?))
+            .or(self.fallback.as_ref())
+            .map(|stemmer| &**stemmer)
+    }
+
+    /// Stem `word`, which is in the language tagged `tag`.
+    pub fn stem_word(&self, tag: &str, word: &str) -> String {
+        match self.stemmer(tag) {
+            Some(stemmer) => stemmer.stem_word(word),
+            None => String::from(word),
+        }
+    }
+
+    /// Stem `words`, the words of a document in the language tagged `tag`,
+    /// looking up the stemmer of the language once.
+    pub fn stem_document<I>(&self, tag: &str, words: I) -> Vec<String>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let stemmer = self.stemmer(tag);
+        words
+            .into_iter()
+            .map(|word| match stemmer {
+                Some(stemmer) => stemmer.stem_word(word.as_ref()),
+                None => String::from(word.as_ref()),
+            })
+            .collect()
+    }
+}
+
+impl fmt::Debug for MultiStemmer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut tags: Vec<_> = self.stemmers.keys().collect();
+        tags.sort();
+        f.debug_struct("MultiStemmer")
+            .field("tags", &tags)
+            .field("fallback", &self.fallback.is_some())
+            .finish()
+    }
+}
+
+#[cfg(all(test, feature = "english"))]
+mod tests {
+    use super::*;
+    use crate::Algorithm;
+
+    #[test]
+    fn test_route() {
+        let mut stemmer = MultiStemmer::new();
+        stemmer.insert("EN", Algorithm::Porter);
+        stemmer.insert("en-x-s", Algorithm::S);
+        assert_eq!(stemmer.stem_word("en", "ponies"), "poni");
+        assert_eq!(stemmer.stem_word("en_AU", "ponies"), "poni");
+        assert_eq!(stemmer.stem_word("EN-X-S", "ponies"), "pony");
+        assert_eq!(stemmer.stem_word("de", "ponies"), "ponies");
+        assert_eq!(stemmer.stem_word("", "ponies"), "ponies");
+        assert!(stemmer.stemmer("de").is_none());
+
+        let stemmer = stemmer.fallback(Algorithm::S);
+        assert_eq!(stemmer.stem_word("de", "ponies"), "pony");
+        assert_eq!(
+            format!("{:?}", stemmer),
+            r#"MultiStemmer { tags: ["en", "en-x-s"], fallback: true }"#
+        );
+    }
+
+    #[test]
+    fn test_stem_document() {
+        let stemmer = MultiStemmer::builtin();
+        assert_eq!(
+            stemmer.stem_document("en", &["ponies", "rusted"]),
+            vec!["poni", "rust"]
+        );
+        assert_eq!(stemmer.stem_document("fr", vec!["chevaux"]), ["chevaux"]);
+    }
+}
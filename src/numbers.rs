@@ -0,0 +1,161 @@
+// Copyright 2019 The Polystem authors.
+//
+// For the full copyright and license information, please view the LICENSE file
+// that was distributed with this source code.
+
+use std::borrow::Cow;
+
+// The English ordinal suffixes, as in `"3rd"`.
+const ORDINALS: &[&str] = &["st", "nd", "rd", "th"];
+
+// Return `true` if `c` only ever separates groups of thousands.
+fn is_group_separator(c: char) -> bool {
+    matches!(c, '\'' | '\u{2019}' | '_' | '\u{a0}' | '\u{202f}')
+}
+
+/// Normalize the number `word`, so that the ways of writing a number index
+/// alike and the stemmers do not take its digits or suffix for a word.
+///
+/// Separators of thousands are removed, the decimal separator becomes a
+/// period and an English ordinal suffix is removed: `"1,000,000"` becomes
+/// `"1000000"`, `"1.234,5"` becomes `"1234.5"` and `"3rd"` becomes `"3"`.
+/// A comma followed by three digits, as in `"1,000"`, separates thousands,
+/// and a lone period is a decimal separator. A word that is not a number
+/// is returned unchanged.
+///
+/// # Examples
+///
+/// ```
+/// use polystem::normalize_number;
+///
+/// assert_eq!("1234567", normalize_number("1,234,567"));
+/// assert_eq!("1234.56", normalize_number("1.234,56"));
+/// assert_eq!("3.14", normalize_number("3.14"));
+/// assert_eq!("21", normalize_number("21st"));
+/// assert_eq!("1,23,4", normalize_number("1,23,4"));
+/// ```
+pub fn normalize_number(word: &str) -> Cow<'_, str> {
+    match parse(word) {
+        Some(number) if number != word => Cow::Owned(number),
+        _ => Cow::Borrowed(word),
+    }
+}
+
+// Return the normalized form of `word` if it is a number.
+fn parse(word: &str) -> Option<String> {
+    let body = ORDINALS
+        .iter()
+        .find_map(|suffix| {
+            let split = word.len().checked_sub(suffix.len())?;
+            let (body, tail) = (word.get(..split)?, word.get(split..)?);
+            Some(body).filter(|_| tail.eq_ignore_ascii_case(suffix))
+        })
+        .unwrap_or(word);
+    let is_digit = |c: char| c.is_ascii_digit();
+    if !body.starts_with(is_digit) || !body.ends_with(is_digit) {
+        return None;
+    }
+    if body.len() < word.len() {
+        // Ordinals are whole numbers.
+        let whole = body.bytes().all(|b| b.is_ascii_digit());
+        return Some(body.to_owned()).filter(|_| whole);
+    }
+
+    // Split the number into its runs of digits and the separators between.
+    let mut groups = vec![String::new()];
+    let mut separators = Vec::new();
+    for c in body.chars() {
+        if c.is_ascii_digit() {
+            groups.last_mut().unwrap().push(c);
+        } else if c == ',' || c == '.' || is_group_separator(c) {
+            if groups.last().unwrap().is_empty() {
+                return None;
+            }
+            separators.push(c);
+            groups.push(String::new());
+        } else {
+            return None;
+        }
+    }
+    if separators.is_empty() {
+        return Some(body.to_owned());
+    }
+
+    // The last separator is a decimal separator if it differs from the
+    // others, or if it is alone and does not look like a separator of
+    // thousands.
+    let last = *separators.last().unwrap();
+    let decimal = if is_group_separator(last) {
+        false
+    } else if separators.len() > 1 {
+        separators[..separators.len() - 1]
+            .iter()
+            .all(|&c| c != last)
+    } else {
+        last == '.' || groups[1].len() != 3
+    };
+    let (whole, fraction) = if decimal {
+        let fraction = groups.pop();
+        separators.pop();
+        (groups, fraction)
+    } else {
+        (groups, None)
+    };
+
+    // The separators of thousands must agree, and split the digits in
+    // groups of three after the first.
+    if separators.windows(2).any(|pair| pair[0] != pair[1])
+        || whole[0].len() > 3 && whole.len() > 1
+        || whole[1..].iter().any(|group| group.len() != 3)
+    {
+        return None;
+    }
+    let mut out = whole.concat();
+    if let Some(fraction) = fraction {
+        out.push('.');
+        out.push_str(&fraction);
+    }
+    Some(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_number() {
+        let cases = [
+            ("1000", "1000"),
+            ("1,000", "1000"),
+            ("1,000,000", "1000000"),
+            ("1.000.000", "1000000"),
+            ("1'000'000", "1000000"),
+            ("1,234.5", "1234.5"),
+            ("1.234,5", "1234.5"),
+            ("1,5", "1.5"),
+            ("3.14", "3.14"),
+            ("1.000", "1.000"),
+            ("1st", "1"),
+            ("22ND", "22"),
+            ("103rd", "103"),
+            ("4th", "4"),
+            ("1,000th", "1,000th"),
+            ("1,00,000", "1,00,000"),
+            ("12,34", "12.34"),
+            ("1234,567", "1234,567"),
+            ("1,234,5", "1,234,5"),
+            ("1.234.5", "1.234.5"),
+            (",5", ",5"),
+            ("5,", "5,"),
+            ("1,,000", "1,,000"),
+            ("3D", "3D"),
+            ("rd", "rd"),
+            ("th", "th"),
+            ("", ""),
+            ("ES2024", "ES2024"),
+        ];
+        for &(word, normalized) in &cases {
+            assert_eq!(normalize_number(word), normalized, "{}", word);
+        }
+    }
+}
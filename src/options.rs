@@ -0,0 +1,629 @@
+// Copyright 2019 The Polystem authors.
+//
+// For the full copyright and license information, please view the LICENSE file
+// that was distributed with this source code.
+
+use std::borrow::Cow;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{americanize, is_mixed_script, DynStemmer, Pos, StemError};
+
+/// How words are lowercased before they are stemmed.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum Case {
+    /// Leave lowercasing to the stemmer, which only lowercases ASCII
+    /// letters.
+    Ascii,
+    /// Lowercase every letter with the Unicode lowercase mapping of
+    /// [`str::to_lowercase`], so that `"É"` and `"é"` are stemmed
+    /// identically. This is lowercasing rather than full case folding:
+    /// `"ß"` is not expanded to `"ss"`.
+    #[default]
+    Lowercase,
+    /// Lowercase with the Turkish and Azerbaijani mapping of the dotted and
+    /// dotless i, where `"I"` lowercases to `"ı"` and `"İ"` to `"i"`.
+    Turkish,
+}
+
+impl Case {
+    /// Lowercase `word`.
+    pub fn fold<'a>(self, word: &'a str) -> Cow<'a, str> {
+        match self {
+            Case::Ascii => Cow::Borrowed(word),
+            Case::Lowercase if !word.chars().any(char::is_uppercase) => {
+                Cow::Borrowed(word)
+            }
+            Case::Lowercase => Cow::Owned(word.to_lowercase()),
+            Case::Turkish if !word.chars().any(char::is_uppercase) => {
+                Cow::Borrowed(word)
+            }
+            Case::Turkish => {
+                let mut out = String::with_capacity(word.len());
+                for c in word.chars() {
+                    match c {
+                        'I' => out.push('ı'),
+                        'İ' => out.push('i'),
+                        _ => out.extend(c.to_lowercase()),
+                    }
+                }
+                Cow::Owned(out)
+            }
+        }
+    }
+}
+
+/// How hyphenated words such as `"self-organizing"` are stemmed.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum Hyphens {
+    /// Stem the word as a whole, hyphens included.
+    #[default]
+    Whole,
+    /// Stem each segment independently: `"self-organ"`.
+    Segments,
+    /// Stem only the last segment: `"self-organ"`, `"state-of-the-art"`.
+    Last,
+    /// Do not stem hyphenated words.
+    Keep,
+}
+
+/// Strip an English possessive suffix from `word`.
+///
+/// A trailing `'s` is removed, as is a bare trailing apostrophe such as in
+/// `"dogs'"`. The typographic apostrophe `’` is recognized as well.
+///
+/// # Examples
+///
+/// ```
+/// use polystem::strip_possessive;
+///
+/// assert_eq!("dog", strip_possessive("dog's"));
+/// assert_eq!("dogs", strip_possessive("dogs'"));
+/// assert_eq!("James", strip_possessive("James’"));
+/// ```
+pub fn strip_possessive(word: &str) -> &str {
+    let mut chars = word.char_indices().rev();
+    match (chars.next(), chars.next()) {
+        (Some((_, 's')), Some((i, c))) | (Some((_, 'S')), Some((i, c)))
+            if is_apostrophe(c) =>
+        {
+            &word[..i]
+        }
+        (Some((i, c)), _) if is_apostrophe(c) => &word[..i],
+        _ => word,
+    }
+}
+
+/// Replace typographic apostrophes and quotation marks in `word` with their
+/// ASCII counterparts `'` and `"`.
+///
+/// # Examples
+///
+/// ```
+/// use polystem::normalize_quotes;
+///
+/// assert_eq!("don't", normalize_quotes("don’t"));
+/// assert_eq!("\"quoted\"", normalize_quotes("“quoted”"));
+/// ```
+pub fn normalize_quotes(word: &str) -> Cow<'_, str> {
+    if word.is_ascii() || !word.chars().any(|c| ascii_quote(c).is_some()) {
+        return Cow::Borrowed(word);
+    }
+
+    Cow::Owned(word.chars().map(|c| ascii_quote(c).unwrap_or(c)).collect())
+}
+
+// Return the ASCII quote for the typographic quote `c`.
+fn ascii_quote(c: char) -> Option<char> {
+    match c {
+        '\u{2018}' | '\u{2019}' | '\u{201a}' | '\u{201b}' | '\u{2032}'
+        | '\u{02bc}' | '\u{ff07}' => Some('\''),
+        '\u{201c}' | '\u{201d}' | '\u{201e}' | '\u{201f}' | '\u{2033}'
+        | '\u{ff02}' => Some('"'),
+        _ => None,
+    }
+}
+
+// Return `true` if `c` is one of the apostrophes found in English text.
+fn is_apostrophe(c: char) -> bool {
+    matches!(c, '\'' | '\u{2019}' | '\u{ff07}')
+}
+
+// Return `true` if `word` contains a digit or a symbol other than a hyphen
+// or an apostrophe, as in `"ES2024s"` or `"files.tar"`. The combining marks
+// of a decomposed letter, as in `"cafe\u{301}"`, are not symbols.
+fn has_symbols(word: &str) -> bool {
+    word.chars().any(|c| {
+        !c.is_alphabetic() && !is_mark(c) && c != '-' && !is_apostrophe(c)
+    })
+}
+
+// Return `true` if `c` is one of the combining marks placed over or under
+// the letter before it.
+fn is_mark(c: char) -> bool {
+    matches!(
+        c,
+        '\u{0300}'..='\u{036f}'
+            | '\u{1ab0}'..='\u{1aff}'
+            | '\u{1dc0}'..='\u{1dff}'
+            | '\u{20d0}'..='\u{20ff}'
+            | '\u{fe20}'..='\u{fe2f}'
+    )
+}
+
+// Return `true` if `word` is an acronym such as `"NASA"`: every character
+// is an uppercase letter and there are at most `max_len` of them.
+fn is_acronym(word: &str, max_len: usize) -> bool {
+    let mut len = 0;
+    for c in word.chars() {
+        len += 1;
+        if len > max_len || !c.is_uppercase() {
+            return false;
+        }
+    }
+    len > 0
+}
+
+// Apply `stem`, the stem of `word` lowercased with `case`, to `word` itself,
+// so that the letters kept from `word` keep their case: `"Running"` becomes
+// `"Run"`.
+fn restore_case(word: &str, stem: &str, case: Case) -> String {
+    let mut out = String::with_capacity(stem.len());
+    let mut rest = stem;
+    let mut buf = [0; 4];
+    for c in word.chars() {
+        // The stemmers lowercase ASCII letters themselves.
+        let tail = rest
+            .strip_prefix(c)
+            .or_else(|| rest.strip_prefix(c.to_ascii_lowercase()))
+            .or_else(|| {
+                rest.strip_prefix(&*case.fold(c.encode_utf8(&mut buf)))
+            });
+        match tail {
+            Some(tail) => rest = tail,
+            None => break,
+        }
+        out.push(c);
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Options controlling how a [`Configured`] stemmer prepares each word.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct StemOptions {
+    /// How words are lowercased, Unicode lowercasing by default.
+    pub case: Case,
+    /// Strip English possessives before stemming, see [`strip_possessive`].
+    pub possessives: bool,
+    /// How hyphenated words are stemmed.
+    pub hyphens: Hyphens,
+    /// Return words containing digits or symbols, such as product codes and
+    /// file names, unchanged. Enabled by default.
+    pub passthrough_symbols: bool,
+    /// Stem the lowercased word but keep the case of the original letters in
+    /// the stem, so that `"Running"` stems to `"Run"` for display.
+    pub preserve_case: bool,
+    /// Return all uppercase words of at most this many characters, such as
+    /// `"NASA"`, unchanged. Zero, the default, stems acronyms like any other
+    /// word.
+    pub max_acronym_len: usize,
+    /// Replace typographic apostrophes and quotes with ASCII ones, see
+    /// [`normalize_quotes`]. Enabled by default.
+    pub ascii_quotes: bool,
+    /// Return words mixing letters of several scripts, see
+    /// [`is_mixed_script`], unchanged. Enabled by default.
+    pub passthrough_mixed_scripts: bool,
+    /// Rewrite British spellings to American ones before stemming, see
+    /// [`americanize`], so that `"colour"` and `"color"` are conflated.
+    pub american_spelling: bool,
+}
+
+impl Default for StemOptions {
+    fn default() -> StemOptions {
+        StemOptions {
+            case: Case::default(),
+            possessives: false,
+            hyphens: Hyphens::default(),
+            passthrough_symbols: true,
+            preserve_case: false,
+            max_acronym_len: 0,
+            ascii_quotes: true,
+            passthrough_mixed_scripts: true,
+            american_spelling: false,
+        }
+    }
+}
+
+/// A stemmer wrapper that prepares each word according to [`StemOptions`]
+/// before it is stemmed.
+///
+/// # Examples
+///
+/// ```
+/// use polystem::{Algorithm, Configured, DynStemmer, StemOptions};
+///
+/// let stemmer = Configured::new(Algorithm::Porter, StemOptions::default());
+///
+/// assert_eq!("élan", stemmer.stem_word("ÉLAN"));
+/// ```
+#[derive(Clone, Debug)]
+pub struct Configured<D> {
+    stemmer: D,
+    options: StemOptions,
+}
+
+impl<D: DynStemmer> Configured<D> {
+    /// Construct a new `Configured` wrapping `stemmer`.
+    pub fn new(stemmer: D, options: StemOptions) -> Configured<D> {
+        Configured { stemmer, options }
+    }
+
+    /// Return the options of the stemmer.
+    pub fn options(&self) -> &StemOptions {
+        &self.options
+    }
+
+    // Lowercase `piece` and pass it to `stem`, restoring the case of the
+    // stem when asked to.
+    fn stem_piece<E>(
+        &self,
+        piece: &str,
+        stem: &mut dyn FnMut(&str) -> Result<String, E>,
+    ) -> Result<String, E> {
+        let folded = self.options.case.fold(piece);
+        let out = if self.options.american_spelling {
+            stem(&americanize(&folded))?
+        } else {
+            stem(&folded)?
+        };
+        if self.options.preserve_case {
+            return Ok(restore_case(piece, &out, self.options.case));
+        }
+        Ok(out)
+    }
+
+    // Return `piece`, which is not stemmed, in the case of the output.
+    fn keep_piece<'a>(&self, piece: &'a str) -> Cow<'a, str> {
+        if self.options.preserve_case {
+            return Cow::Borrowed(piece);
+        }
+        self.options.case.fold(piece)
+    }
+
+    // Prepare `word` and pass it, or its segments, to `stem`.
+    fn run<E>(
+        &self,
+        word: &str,
+        stem: &mut dyn FnMut(&str) -> Result<String, E>,
+    ) -> Result<String, E> {
+        // The quotes are normalized first, so that a typographic apostrophe
+        // is not taken for a symbol.
+        let word = if self.options.ascii_quotes {
+            normalize_quotes(word)
+        } else {
+            Cow::Borrowed(word)
+        };
+        let mut word = &*word;
+        if self.options.passthrough_symbols && has_symbols(word)
+            || self.options.passthrough_mixed_scripts && is_mixed_script(word)
+        {
+            return Ok(String::from(word));
+        }
+
+        if self.options.possessives {
+            word = strip_possessive(word);
+        }
+        if is_acronym(word, self.options.max_acronym_len) {
+            return Ok(String::from(word));
+        }
+        if !word.contains('-') {
+            return self.stem_piece(word, stem);
+        }
+
+        match self.options.hyphens {
+            Hyphens::Whole => self.stem_piece(word, stem),
+            Hyphens::Keep => Ok(self.keep_piece(word).into_owned()),
+            Hyphens::Last => {
+                let (head, last) = word.split_at(word.rfind('-').unwrap() + 1);
+                let head = self.keep_piece(head).into_owned();
+                if last.is_empty() {
+                    return Ok(head);
+                }
+                Ok(head + &self.stem_piece(last, stem)?)
+            }
+            Hyphens::Segments => {
+                let mut out = String::with_capacity(word.len());
+                for (i, segment) in word.split('-').enumerate() {
+                    if i > 0 {
+                        out.push('-');
+                    }
+                    if !segment.is_empty() {
+                        out.push_str(&self.stem_piece(segment, stem)?);
+                    }
+                }
+                Ok(out)
+            }
+        }
+    }
+
+    // Run `stem`, which can not fail, over `word`.
+    fn run_infallible<F>(&self, word: &str, mut stem: F) -> String
+    where
+        F: FnMut(&str) -> String,
+    {
+        match self.run::<()>(word, &mut |w| Ok(stem(w))) {
+            Ok(stem) => stem,
+            Err(()) => unreachable!(),
+        }
+    }
+}
+
+impl<D: DynStemmer> DynStemmer for Configured<D> {
+    fn stem_word(&self, word: &str) -> String {
+        self.run_infallible(word, |w| self.stemmer.stem_word(w))
+    }
+
+    fn try_stem_word(&self, word: &str) -> Result<String, StemError> {
+        self.run(word, &mut |w| self.stemmer.try_stem_word(w))
+    }
+
+    fn stem_candidates_word(&self, word: &str) -> Vec<String> {
+        let mut calls = Vec::new();
+        let stem = self.run_infallible(word, |w| {
+            let candidates = self.stemmer.stem_candidates_word(w);
+            let stem = candidates.first().cloned().unwrap_or_default();
+            calls.push(candidates);
+            stem
+        });
+
+        // Alternatives are only reported when the word was not split.
+        match calls.pop() {
+            Some(candidates) if calls.is_empty() => candidates
+                .into_iter()
+                .map(|c| self.run_infallible(word, |_| c.clone()))
+                .collect(),
+            _ => vec![stem],
+        }
+    }
+
+    fn stem_with_pos_word(&self, word: &str, pos: Pos) -> String {
+        self.run_infallible(word, |w| self.stemmer.stem_with_pos_word(w, pos))
+    }
+
+    fn stem_scored_word(&self, word: &str) -> (String, f32) {
+        let mut confidence: f32 = 1.0;
+        let stem = self.run_infallible(word, |w| {
+            let (stem, c) = self.stemmer.stem_scored_word(w);
+            confidence = confidence.min(c);
+            stem
+        });
+
+        (stem, confidence)
+    }
+}
+
+#[cfg(all(test, feature = "s"))]
+mod tests {
+    use super::*;
+    use crate::S;
+
+    #[test]
+    fn test_case_fold() {
+        assert_eq!(Case::Ascii.fold("ÉTÉS"), "ÉTÉS");
+        assert_eq!(Case::Lowercase.fold("ÉTÉS"), "étés");
+        assert_eq!(Case::Lowercase.fold("ΟΔΟΣ"), "οδος");
+        assert!(matches!(Case::Lowercase.fold("flies"), Cow::Borrowed(_)));
+        assert_eq!(Case::Lowercase.fold("STRAẞE"), "straße");
+        assert_eq!(Case::Turkish.fold("ISPARTA"), "ısparta");
+        assert_eq!(Case::Turkish.fold("İSTANBUL"), "istanbul");
+        assert_eq!(Case::Turkish.fold("DİYARBAKIR"), "diyarbakır");
+        assert!(matches!(Case::Turkish.fold("ılık"), Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn test_normalize_quotes() {
+        assert_eq!(normalize_quotes("‘tis"), "'tis");
+        assert_eq!(normalize_quotes("„so“"), "\"so\"");
+        assert_eq!(normalize_quotes("rock ʼnʼ roll"), "rock 'n' roll");
+        assert!(matches!(normalize_quotes("café"), Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn test_configured_quotes() {
+        let stemmer = Configured::new(S, StemOptions::default());
+        assert_eq!(stemmer.stem_word("don’ts"), stemmer.stem_word("don'ts"));
+        assert_eq!(stemmer.stem_word("don’ts"), "don't");
+        assert_eq!(stemmer.stem_word("‘Tis"), stemmer.stem_word("'Tis"));
+        assert_eq!(stemmer.stem_word("‘Tis"), "'ti");
+
+        let options = StemOptions {
+            ascii_quotes: false,
+            ..StemOptions::default()
+        };
+        let stemmer = Configured::new(S, options);
+        assert_eq!(stemmer.stem_word("don’ts"), "don’t");
+    }
+
+    #[test]
+    fn test_strip_possessive() {
+        assert_eq!(strip_possessive("DOG'S"), "DOG");
+        assert_eq!(strip_possessive("dog’s"), "dog");
+        assert_eq!(strip_possessive("dogs"), "dogs");
+        assert_eq!(strip_possessive("'s"), "");
+        assert_eq!(strip_possessive("'"), "");
+        assert_eq!(strip_possessive(""), "");
+        assert_eq!(strip_possessive("it's'"), "it's");
+    }
+
+    #[test]
+    fn test_configured_possessives() {
+        let stemmer = Configured::new(S, StemOptions::default());
+        assert_eq!(stemmer.stem_word("dog's"), "dog'");
+
+        let options = StemOptions {
+            possessives: true,
+            ..StemOptions::default()
+        };
+        let stemmer = Configured::new(S, options);
+        assert_eq!(stemmer.stem_word("dog's"), "dog");
+        assert_eq!(stemmer.stem_word("Dogs'"), "dog");
+    }
+
+    #[test]
+    fn test_configured_hyphens() {
+        let mut options = StemOptions::default();
+        let word = "Self-Stems-";
+
+        let stemmer = Configured::new(S, options.clone());
+        assert_eq!(stemmer.stem_word(word), "self-stems-");
+
+        options.hyphens = Hyphens::Segments;
+        let stemmer = Configured::new(S, options.clone());
+        assert_eq!(stemmer.stem_word(word), "self-stem-");
+        assert_eq!(stemmer.stem_word("suns-flies"), "sun-fly");
+        assert_eq!(stemmer.stem_word("--"), "--");
+        assert_eq!(
+            stemmer.stem_candidates_word("suns-flies"),
+            vec!["sun-fly"]
+        );
+        assert_eq!(stemmer.try_stem_word("suns-flies"), Ok("sun-fly".into()));
+        assert_eq!(stemmer.try_stem_word("a-é"), Err(StemError::NonAscii));
+
+        options.hyphens = Hyphens::Last;
+        let stemmer = Configured::new(S, options.clone());
+        assert_eq!(stemmer.stem_word("suns-flies"), "suns-fly");
+        assert_eq!(stemmer.stem_word(word), "self-stems-");
+
+        options.hyphens = Hyphens::Keep;
+        let stemmer = Configured::new(S, options);
+        assert_eq!(stemmer.stem_word("Suns-Flies"), "suns-flies");
+        assert_eq!(stemmer.stem_word("Suns"), "sun");
+        assert_eq!(stemmer.stem_scored_word("suns"), ("sun".into(), 1.0));
+    }
+
+    #[test]
+    fn test_configured_symbols() {
+        let stemmer = Configured::new(S, StemOptions::default());
+        assert_eq!(stemmer.stem_word("ES2024s"), "ES2024s");
+        assert_eq!(stemmer.stem_word("files.tar"), "files.tar");
+        assert_eq!(stemmer.stem_word("files"), "fil");
+        assert_eq!(stemmer.stem_word("it's"), "it'");
+        assert_eq!(stemmer.stem_word("Cafe\u{301}s"), "cafe\u{301}");
+
+        let options = StemOptions {
+            passthrough_symbols: false,
+            ..StemOptions::default()
+        };
+        let stemmer = Configured::new(S, options);
+        assert_eq!(stemmer.stem_word("ES2024s"), "es2024");
+    }
+
+    #[test]
+    fn test_configured_mixed_scripts() {
+        let stemmer = Configured::new(S, StemOptions::default());
+        assert_eq!(stemmer.stem_word("Москваcities"), "Москваcities");
+        assert_eq!(stemmer.stem_word("Cities"), "city");
+
+        let options = StemOptions {
+            passthrough_mixed_scripts: false,
+            ..StemOptions::default()
+        };
+        let stemmer = Configured::new(S, options);
+        assert_eq!(stemmer.stem_word("Москваcities"), "москваcity");
+    }
+
+    #[test]
+    fn test_configured_american_spelling() {
+        let options = StemOptions {
+            american_spelling: true,
+            preserve_case: true,
+            ..StemOptions::default()
+        };
+        let stemmer = Configured::new(S, options);
+        assert_eq!(stemmer.stem_word("colours"), "color");
+        assert_eq!(stemmer.stem_word("Colours"), "Color");
+        assert_eq!(stemmer.stem_word("Centres"), "Center");
+        assert_eq!(stemmer.stem_word("flies"), "fly");
+    }
+
+    #[test]
+    fn test_configured_case() {
+        let options = StemOptions {
+            case: Case::Ascii,
+            ..StemOptions::default()
+        };
+        let stemmer = Configured::new(S, options);
+        assert_eq!(stemmer.stem_word("CAFÉS"), "cafÉ");
+
+        let stemmer = Configured::new(S, StemOptions::default());
+        assert_eq!(stemmer.stem_word("CAFÉS"), "café");
+        assert_eq!(stemmer.stem_word("Café"), stemmer.stem_word("CAFÉ"));
+    }
+
+    #[test]
+    fn test_is_acronym() {
+        assert!(is_acronym("NASA", 4));
+        assert!(is_acronym("ÉTÉ", 4));
+        assert!(!is_acronym("ITEMS", 4));
+        assert!(!is_acronym("NaSA", 4));
+        assert!(!is_acronym("NASA", 0));
+        assert!(!is_acronym("", 4));
+    }
+
+    #[test]
+    fn test_configured_acronyms() {
+        let stemmer = Configured::new(S, StemOptions::default());
+        assert_eq!(stemmer.stem_word("NASA"), "nasa");
+
+        let options = StemOptions {
+            possessives: true,
+            max_acronym_len: 4,
+            ..StemOptions::default()
+        };
+        let stemmer = Configured::new(S, options);
+        assert_eq!(stemmer.stem_word("NASA"), "NASA");
+        assert_eq!(stemmer.stem_word("NASA's"), "NASA");
+        assert_eq!(stemmer.stem_word("ITEMS"), "item");
+        assert_eq!(stemmer.stem_word("Cats"), "cat");
+    }
+
+    #[test]
+    fn test_restore_case() {
+        let case = Case::Lowercase;
+        assert_eq!(restore_case("Flies", "fly", case), "Fly");
+        assert_eq!(restore_case("CAFÉS", "café", case), "CAFÉ");
+        assert_eq!(restore_case("CAFÉS", "cafÉ", Case::Ascii), "CAFÉ");
+        assert_eq!(restore_case("İS", "i\u{307}", case), "İ");
+        assert_eq!(restore_case("Ies", "y", case), "y");
+        assert_eq!(restore_case("", "", case), "");
+        assert_eq!(restore_case("KIZLAR", "kız", Case::Turkish), "KIZ");
+    }
+
+    #[test]
+    fn test_configured_preserve_case() {
+        let mut options = StemOptions {
+            preserve_case: true,
+            ..StemOptions::default()
+        };
+        let stemmer = Configured::new(S, options.clone());
+        assert_eq!(stemmer.stem_word("Flies"), "Fly");
+        assert_eq!(stemmer.stem_word("ÉTÉS"), "ÉTÉ");
+        assert_eq!(stemmer.stem_word("Dog's"), "Dog'");
+
+        options.hyphens = Hyphens::Segments;
+        let stemmer = Configured::new(S, options.clone());
+        assert_eq!(stemmer.stem_word("Suns-Flies"), "Sun-Fly");
+
+        options.hyphens = Hyphens::Last;
+        let stemmer = Configured::new(S, options);
+        assert_eq!(stemmer.stem_word("Suns-Flies"), "Suns-Fly");
+    }
+}
@@ -0,0 +1,1255 @@
+// Copyright 2019 The Polystem authors.
+//
+// For the full copyright and license information, please view the LICENSE file
+// that was distributed with this source code.
+
+use std::borrow::Cow;
+use std::cmp::Reverse;
+use std::collections::HashMap;
+use std::fmt;
+use std::io::{self, BufRead};
+use std::sync::Arc;
+
+#[cfg(feature = "regex")]
+use regex::Regex;
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::markup::Stripped;
+use crate::tokenize::{
+    special_tokens, SentenceSplitter, Token, TokenClass, TokenKind,
+};
+use crate::{
+    normalize_number, strip_possessive, Case, Decompounder, DynStemmer,
+    Stopwords,
+};
+
+#[cfg(any(feature = "toml", feature = "yaml"))]
+mod config;
+
+// A step of a `Pipeline`, applied to each word in turn.
+#[derive(Clone)]
+enum Stage {
+    Lowercase,
+    StripPossessives,
+    Numbers,
+    Stopwords(Stopwords),
+    Decompound(Decompounder),
+    Stem(Arc<dyn DynStemmer + Send + Sync>),
+    #[cfg(feature = "regex")]
+    Keep(Regex),
+    #[cfg(feature = "regex")]
+    Drop(Regex),
+    #[cfg(feature = "regex")]
+    Replace(Regex, String),
+}
+
+impl Stage {
+    // Pass the words the stage makes of `word` to `emit`: none if it is
+    // dropped, and several if it is split.
+    fn apply(&self, word: String, emit: &mut dyn FnMut(String)) {
+        match self {
+            Stage::Lowercase => match Case::Lowercase.fold(&word) {
+                Cow::Borrowed(_) => emit(word),
+                lower => emit(lower.into_owned()),
+            },
+            Stage::StripPossessives => {
+                let len = strip_possessive(&word).len();
+                let mut word = word;
+                word.truncate(len);
+                emit(word)
+            }
+            Stage::Numbers => match normalize_number(&word) {
+                Cow::Borrowed(_) => emit(word),
+                number => emit(number.into_owned()),
+            },
+            Stage::Stopwords(stopwords) if stopwords.contains(&word) => {}
+            Stage::Stopwords(_) => emit(word),
+            Stage::Decompound(decompounder) => {
+                let parts = decompounder.split(&word);
+                if parts.is_empty() {
+                    return emit(word);
+                }
+                parts.into_iter().for_each(emit);
+            }
+            Stage::Stem(stemmer) => emit(stemmer.stem_word(&word)),
+            #[cfg(feature = "regex")]
+            Stage::Keep(pattern) if !pattern.is_match(&word) => {}
+            #[cfg(feature = "regex")]
+            Stage::Drop(pattern) if pattern.is_match(&word) => {}
+            #[cfg(feature = "regex")]
+            Stage::Keep(_) | Stage::Drop(_) => emit(word),
+            #[cfg(feature = "regex")]
+            Stage::Replace(pattern, replacement) => {
+                match pattern.replace_all(&word, replacement.as_str()) {
+                    Cow::Borrowed(_) => emit(word),
+                    replaced => emit(replaced.into_owned()),
+                }
+            }
+        }
+    }
+}
+
+impl fmt::Debug for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Stage::Lowercase => "Lowercase",
+            Stage::StripPossessives => "StripPossessives",
+            Stage::Numbers => "Numbers",
+            Stage::Stopwords(_) => "Stopwords",
+            Stage::Decompound(_) => "Decompound",
+            Stage::Stem(_) => "Stem",
+            #[cfg(feature = "regex")]
+            Stage::Keep(_) => "Keep",
+            #[cfg(feature = "regex")]
+            Stage::Drop(_) => "Drop",
+            #[cfg(feature = "regex")]
+            Stage::Replace(..) => "Replace",
+        })
+    }
+}
+
+/// A reusable analyzer that splits text into words and passes each word
+/// through a sequence of stages, such as lowercasing and stemming.
+///
+/// Words are found as by [`tokenize::words`](crate::tokenize::words), and
+/// the stages run in the order they were added. A word that a stage reduces
+/// to the empty string, such as a bare `"'s"`, is dropped, as are
+/// stopwords. The analyzed words are returned as [`Token`]s, whose `term`
+/// is the output of the last stage.
+///
+/// # Examples
+///
+/// ```
+/// use polystem::{Algorithm, Pipeline};
+///
+/// let pipeline = Pipeline::new()
+///     .lowercase()
+///     .strip_possessives()
+///     .stem(Algorithm::Porter);
+///
+/// let terms = pipeline.terms("The Dog's bones, rusted.");
+/// assert_eq!(vec!["the", "dog", "bone", "rust"], terms);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Pipeline {
+    stages: Vec<Stage>,
+    keep_original: bool,
+    ngrams: Option<NGrams>,
+    // The protected phrases, split into lowercase words and listed under
+    // their first word, longest first.
+    phrases: HashMap<String, Vec<Vec<String>>>,
+    // The classes of tokens that are not words, and whether each is kept.
+    classes: Vec<(TokenClass, bool)>,
+    markup: bool,
+}
+
+// The n-grams a `Pipeline` makes of its terms, with the smallest and the
+// largest n.
+#[derive(Clone, Copy, Debug)]
+enum NGrams {
+    Words(usize, usize),
+    Chars(usize, usize),
+}
+
+// Check the bounds of the n-grams given to a `Pipeline`.
+fn check_ngrams(min: usize, max: usize) {
+    assert!(min > 0, "n-grams must be at least one long");
+    assert!(min <= max, "the minimum n must not exceed the maximum");
+}
+
+impl Pipeline {
+    /// Construct an empty `Pipeline`, which splits text into words and
+    /// returns them unchanged.
+    pub fn new() -> Pipeline {
+        Pipeline::default()
+    }
+
+    /// Remove the HTML or XML markup of the text before splitting it into
+    /// words, as by [`strip_markup`](crate::strip_markup), for analyzing
+    /// web pages. The offsets of the tokens are those of the words in the
+    /// markup, and a word whose characters are written as references, such
+    /// as `"caf&eacute;"`, spans them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polystem::{Algorithm, Pipeline};
+    ///
+    /// let pipeline = Pipeline::new().strip_markup().stem(Algorithm::Porter);
+    /// let html = "<p>Ponies&nbsp;<b>rust</b>ed</p><script>x()</script>";
+    /// let tokens = pipeline.analyze(html);
+    ///
+    /// assert_eq!(vec!["poni", "rust"], pipeline.terms(html));
+    /// assert_eq!("rust</b>ed", &html[tokens[1].range()]);
+    /// ```
+    pub fn strip_markup(mut self) -> Pipeline {
+        self.markup = true;
+        self
+    }
+
+    /// Add a stage lowercasing each word with the Unicode lowercase
+    /// mapping, as [`Case::Lowercase`].
+    pub fn lowercase(mut self) -> Pipeline {
+        self.stages.push(Stage::Lowercase);
+        self
+    }
+
+    /// Add a stage stripping English possessives, see [`strip_possessive`].
+    pub fn strip_possessives(mut self) -> Pipeline {
+        self.stages.push(Stage::StripPossessives);
+        self
+    }
+
+    /// Add a stage normalizing numbers, see [`normalize_number`], so that
+    /// `"1,000"` and `"1000"` are one term and `"3rd"` is not stemmed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polystem::{Algorithm, Pipeline};
+    ///
+    /// let pipeline = Pipeline::new()
+    ///     .normalize_numbers()
+    ///     .stem(Algorithm::Porter);
+    ///
+    /// assert_eq!(
+    ///     vec!["1000000", "visitor", "on", "the", "3", "dai"],
+    ///     pipeline.terms("1,000,000 visitors on the 3rd day")
+    /// );
+    /// ```
+    pub fn normalize_numbers(mut self) -> Pipeline {
+        self.stages.push(Stage::Numbers);
+        self
+    }
+
+    /// Add a stage dropping the words in `stopwords`.
+    ///
+    /// Added before [`stem`](Pipeline::stem), the stage drops the words as
+    /// they are written, and added after it, the stems, which are matched
+    /// against the stems of the stopwords given by [`Stopwords::stemmed`].
+    /// Which suits a language depends on how its stopwords stem.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polystem::{Algorithm, Pipeline, Stopwords};
+    ///
+    /// let before = Pipeline::new()
+    ///     .stopwords(Stopwords::english())
+    ///     .stem(Algorithm::Porter);
+    /// assert_eq!(vec!["poni"], before.terms("Was the pony"));
+    ///
+    /// let stems = Stopwords::english().stemmed(&Algorithm::Porter);
+    /// let after = Pipeline::new().stem(Algorithm::Porter).stopwords(stems);
+    /// assert_eq!(vec!["poni"], after.terms("Was the ponies"));
+    /// ```
+    pub fn stopwords(mut self, stopwords: Stopwords) -> Pipeline {
+        self.stages.push(Stage::Stopwords(stopwords));
+        self
+    }
+
+    /// Add a stage splitting compounds into their parts with
+    /// `decompounder`. The parts replace the compound and have its offsets.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polystem::{Algorithm, Decompounder, Pipeline};
+    ///
+    /// let vocabulary = vec!["sun", "flowers"];
+    /// let pipeline = Pipeline::new()
+    ///     .decompound(Decompounder::new(vocabulary))
+    ///     .stem(Algorithm::S);
+    ///
+    /// assert_eq!(vec!["sun", "flower"], pipeline.terms("Sunflowers"));
+    /// ```
+    pub fn decompound(mut self, decompounder: Decompounder) -> Pipeline {
+        self.stages.push(Stage::Decompound(decompounder));
+        self
+    }
+
+    /// Add a stage stemming each word with `stemmer`.
+    pub fn stem<D>(mut self, stemmer: D) -> Pipeline
+    where
+        D: DynStemmer + Send + Sync + 'static,
+    {
+        self.stages.push(Stage::Stem(Arc::new(stemmer)));
+        self
+    }
+
+    /// Add a stage keeping only the words that `pattern` matches, anywhere
+    /// in the word unless it is anchored with `^` and `$`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polystem::{Algorithm, Pipeline};
+    /// use regex::Regex;
+    ///
+    /// let pipeline = Pipeline::new()
+    ///     .keep_matching(Regex::new(r"^\p{Alphabetic}+$").unwrap())
+    ///     .stem(Algorithm::Porter);
+    ///
+    /// let terms = pipeline.terms("ponies 42 rusted a1");
+    /// assert_eq!(vec!["poni", "rust"], terms);
+    /// ```
+    #[cfg(feature = "regex")]
+    pub fn keep_matching(mut self, pattern: Regex) -> Pipeline {
+        self.stages.push(Stage::Keep(pattern));
+        self
+    }
+
+    /// Add a stage dropping the words that `pattern` matches, anywhere in
+    /// the word unless it is anchored with `^` and `$`.
+    #[cfg(feature = "regex")]
+    pub fn drop_matching(mut self, pattern: Regex) -> Pipeline {
+        self.stages.push(Stage::Drop(pattern));
+        self
+    }
+
+    /// Add a stage replacing every match of `pattern` in each word with
+    /// `replacement`, which may refer to the groups of the match as `$1` or
+    /// `${name}`, see [`Regex::replace_all`]. A word replaced by the empty
+    /// string is dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polystem::Pipeline;
+    /// use regex::Regex;
+    ///
+    /// let pipeline = Pipeline::new()
+    ///     .lowercase()
+    ///     .replace_matching(Regex::new(r"^v(\d+)$").unwrap(), "version$1")
+    ///     .replace_matching(Regex::new(r"^x+$").unwrap(), "");
+    ///
+    /// assert_eq!(vec!["version2", "of"], pipeline.terms("V2 of XXX"));
+    /// ```
+    #[cfg(feature = "regex")]
+    pub fn replace_matching(
+        mut self,
+        pattern: Regex,
+        replacement: &str,
+    ) -> Pipeline {
+        let replacement = replacement.to_owned();
+        self.stages.push(Stage::Replace(pattern, replacement));
+        self
+    }
+
+    /// Emit each word as it is before the first stemming stage alongside
+    /// its stem, as a token of the [`Original`](TokenKind::Original) kind
+    /// at the same offsets, unless the two are equal. Indexing both lets
+    /// exact matches be boosted over a stemmed index without a second field.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polystem::tokenize::TokenKind;
+    /// use polystem::{Algorithm, Pipeline};
+    ///
+    /// let pipeline = Pipeline::new()
+    ///     .lowercase()
+    ///     .stem(Algorithm::Porter)
+    ///     .keep_original();
+    /// let tokens = pipeline.analyze("Ponies run");
+    ///
+    /// assert_eq!(3, tokens.len());
+    /// assert_eq!("ponies", tokens[0].term);
+    /// assert_eq!(TokenKind::Original, tokens[0].kind);
+    /// assert_eq!("poni", tokens[1].term);
+    /// assert_eq!(TokenKind::Stem, tokens[1].kind);
+    /// assert_eq!(tokens[0].range(), tokens[1].range());
+    /// ```
+    pub fn keep_original(mut self) -> Pipeline {
+        self.keep_original = true;
+        self
+    }
+
+    /// Pass the sequences of words in `phrases`, such as `"new york
+    /// times"`, through as single tokens, instead of passing their words
+    /// through the stages one by one.
+    ///
+    /// The words of a phrase are matched without regard to case and
+    /// whatever separates them, and the longest phrase at a word wins. The
+    /// term of a phrase is its lowercase words joined by spaces, and spans
+    /// the offsets of its first and last word. A phrase of one word
+    /// protects that word from the stages. A line break ends a phrase in
+    /// [`analyze_reader`](Pipeline::analyze_reader), which reads a line at
+    /// a time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polystem::{Algorithm, Pipeline};
+    ///
+    /// let pipeline = Pipeline::new()
+    ///     .lowercase()
+    ///     .stem(Algorithm::Porter)
+    ///     .protect_phrases(vec!["new york times", "machine learning"]);
+    ///
+    /// assert_eq!(
+    ///     vec!["new york times", "machine learning", "model"],
+    ///     pipeline.terms("New York Times machine-learning models")
+    /// );
+    /// ```
+    pub fn protect_phrases<I>(mut self, phrases: I) -> Pipeline
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        for phrase in phrases {
+            let words: Vec<_> = phrase
+                .as_ref()
+                .unicode_words()
+                .map(|word| Case::Lowercase.fold(word).into_owned())
+                .collect();
+            let first = match words.first() {
+                Some(first) => first.clone(),
+                None => continue,
+            };
+            let listed = self.phrases.entry(first).or_default();
+            if !listed.contains(&words) {
+                listed.push(words);
+                listed.sort_by_key(|words| Reverse(words.len()));
+            }
+        }
+        self
+    }
+
+    /// Pass the tokens of `class`, such as URLs, through as they are
+    /// written, instead of splitting them into words and stemming those.
+    /// Replaces an earlier [`strip`](Pipeline::strip) of the class.
+    ///
+    /// The tokens are found as by
+    /// [`special_tokens`](crate::tokenize::special_tokens).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polystem::tokenize::TokenClass;
+    /// use polystem::{Algorithm, Pipeline};
+    ///
+    /// let pipeline = Pipeline::new()
+    ///     .stem(Algorithm::Porter)
+    ///     .pass_through(TokenClass::Url)
+    ///     .strip(TokenClass::Hashtag);
+    ///
+    /// assert_eq!(
+    ///     vec!["see", "github.com/users", "rust"],
+    ///     pipeline.terms("see github.com/users, rusted #rustlang")
+    /// );
+    /// ```
+    pub fn pass_through(self, class: TokenClass) -> Pipeline {
+        self.set_class(class, true)
+    }
+
+    /// Drop the tokens of `class`, such as email addresses, and their
+    /// words. Replaces an earlier [`pass_through`](Pipeline::pass_through)
+    /// of the class.
+    pub fn strip(self, class: TokenClass) -> Pipeline {
+        self.set_class(class, false)
+    }
+
+    // Keep the tokens of `class` whole if `keep` is true, or drop them.
+    fn set_class(mut self, class: TokenClass, keep: bool) -> Pipeline {
+        self.classes.retain(|&(c, _)| c != class);
+        self.classes.push((class, keep));
+        self
+    }
+
+    /// Replace the terms with the word n-grams, or shingles, of `min` to
+    /// `max` consecutive terms, joined by spaces, for matching phrases.
+    ///
+    /// The n-grams are made after the stages, from the terms of the whole
+    /// text, and span the offsets of their first and last word. Words kept
+    /// by [`keep_original`](Pipeline::keep_original) are not part of them.
+    /// Replaces any earlier [`char_ngrams`](Pipeline::char_ngrams).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min` is zero or greater than `max`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polystem::{Algorithm, Pipeline};
+    ///
+    /// let pipeline = Pipeline::new().stem(Algorithm::S).shingles(1, 2);
+    ///
+    /// assert_eq!(
+    ///     vec!["red", "red cat", "cat", "cat nap", "nap"],
+    ///     pipeline.terms("red cats naps")
+    /// );
+    /// ```
+    pub fn shingles(mut self, min: usize, max: usize) -> Pipeline {
+        check_ngrams(min, max);
+        self.ngrams = Some(NGrams::Words(min, max));
+        self
+    }
+
+    /// Replace each term with its character n-grams of `min` to `max`
+    /// characters, for fuzzy matching and retrieval without a stemmer of
+    /// the language. A term shorter than `min` is kept whole.
+    ///
+    /// The n-grams are made after the stages and have the offsets of their
+    /// word. Replaces any earlier [`shingles`](Pipeline::shingles).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min` is zero or greater than `max`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polystem::{Algorithm, Pipeline};
+    ///
+    /// let pipeline = Pipeline::new().stem(Algorithm::S).char_ngrams(2, 3);
+    ///
+    /// assert_eq!(
+    ///     vec!["ca", "cat", "at", "a"],
+    ///     pipeline.terms("cats a")
+    /// );
+    /// ```
+    pub fn char_ngrams(mut self, min: usize, max: usize) -> Pipeline {
+        check_ngrams(min, max);
+        self.ngrams = Some(NGrams::Chars(min, max));
+        self
+    }
+
+    /// Pass a single `word` through the stages, returning its terms: none
+    /// if it is dropped, and several if it is split into parts.
+    pub fn analyze_word(&self, word: &str) -> Vec<String> {
+        self.run(word).into_iter().map(|(term, _)| term).collect()
+    }
+
+    // Pass `word` through the stages, returning its terms and, if originals
+    // are kept, the word each was before the first stemming stage.
+    fn run(&self, word: &str) -> Vec<(String, Option<String>)> {
+        let mut words = vec![(String::from(word), None)];
+        let mut next = Vec::new();
+        for stage in &self.stages {
+            let keep = self.keep_original && matches!(stage, Stage::Stem(_));
+            for (word, mut original) in words.drain(..) {
+                if keep && original.is_none() {
+                    original = Some(word.clone());
+                }
+                stage.apply(word, &mut |word| {
+                    next.push((word, original.clone()))
+                });
+            }
+            std::mem::swap(&mut words, &mut next);
+        }
+        words.retain(|(word, _)| !word.is_empty());
+        words
+    }
+
+    /// Split `text` into words and analyze them, returning the tokens with
+    /// their byte offsets in `text`.
+    pub fn analyze(&self, text: &str) -> Vec<Token> {
+        let mut tokens = Vec::new();
+        self.analyze_into(text, 0, &mut tokens);
+        self.make_ngrams(tokens)
+    }
+
+    /// Split `text` into words and analyze them, returning only the
+    /// analyzed words.
+    pub fn terms(&self, text: &str) -> Vec<String> {
+        self.analyze(text).into_iter().map(|t| t.term).collect()
+    }
+
+    /// Split `text` into sentences with `splitter` and analyze each on its
+    /// own, returning the tokens of each sentence with their byte offsets
+    /// in `text`. N-grams do not span sentences. Sentences without tokens
+    /// are kept, so that the sentences stay in step with those of
+    /// `splitter`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polystem::tokenize::SentenceSplitter;
+    /// use polystem::{Algorithm, Pipeline};
+    ///
+    /// let pipeline =
+    ///     Pipeline::new().lowercase().stem(Algorithm::Porter).shingles(2, 2);
+    /// let text = "Mr. Ed rusted. Ponies ran!";
+    /// let splitter = SentenceSplitter::new();
+    /// let sentences = pipeline.analyze_sentences(&splitter, text);
+    /// let terms: Vec<Vec<_>> = sentences
+    ///     .iter()
+    ///     .map(|tokens| tokens.iter().map(|t| t.term.as_str()).collect())
+    ///     .collect();
+    ///
+    /// assert_eq!(vec![vec!["mr ed", "ed rust"], vec!["poni ran"]], terms);
+    /// assert_eq!(15..25, sentences[1][0].range());
+    /// ```
+    pub fn analyze_sentences(
+        &self,
+        splitter: &SentenceSplitter,
+        text: &str,
+    ) -> Vec<Vec<Token>> {
+        let stripped = self.markup.then(|| Stripped::new(text));
+        let plain = stripped.as_ref().map_or(text, |s| &s.text);
+        let mut sentences = Vec::new();
+        for (offset, sentence) in splitter.split(plain) {
+            let mut tokens = Vec::new();
+            self.analyze_text_into(sentence, offset, &mut tokens);
+            if let Some(stripped) = &stripped {
+                for token in &mut tokens {
+                    token.start = stripped.start(token.start);
+                    token.end = stripped.end(token.end);
+                }
+            }
+            sentences.push(self.make_ngrams(tokens));
+        }
+        sentences
+    }
+
+    /// Analyze `words`, which are already split, such as the words of a
+    /// document from another tokenizer. Only the terms of the stages are
+    /// returned, without the originals of
+    /// [`keep_original`](Pipeline::keep_original) or any n-grams.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polystem::{Algorithm, Pipeline};
+    ///
+    /// let pipeline = Pipeline::new().lowercase().stem(Algorithm::S);
+    /// let terms: Vec<_> =
+    ///     pipeline.analyze_words(vec!["Ponies", "cats"]).collect();
+    ///
+    /// assert_eq!(vec!["pony", "cat"], terms);
+    /// ```
+    pub fn analyze_words<'a, I>(
+        &'a self,
+        words: I,
+    ) -> impl Iterator<Item = String> + 'a
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+        I::IntoIter: 'a,
+    {
+        words
+            .into_iter()
+            .flat_map(move |word| self.analyze_word(word.as_ref()))
+    }
+
+    /// Read the text of `reader` a line at a time and analyze it, returning
+    /// the tokens with their byte offsets in the whole text. With
+    /// [`strip_markup`](Pipeline::strip_markup), whose tags may span lines,
+    /// the whole text is read first.
+    ///
+    /// An error is returned if the text is not valid UTF-8.
+    pub fn analyze_reader<R: BufRead>(
+        &self,
+        mut reader: R,
+    ) -> io::Result<Vec<Token>> {
+        if self.markup {
+            let mut text = String::new();
+            reader.read_to_string(&mut text)?;
+            return Ok(self.analyze(&text));
+        }
+        let mut tokens = Vec::new();
+        let mut line = String::new();
+        let mut offset = 0;
+        while reader.read_line(&mut line)? > 0 {
+            self.analyze_into(&line, offset, &mut tokens);
+            offset += line.len();
+            line.clear();
+        }
+        Ok(self.make_ngrams(tokens))
+    }
+
+    // Replace the terms of `tokens` with their n-grams, if any.
+    fn make_ngrams(&self, tokens: Vec<Token>) -> Vec<Token> {
+        match self.ngrams {
+            None => tokens,
+            Some(NGrams::Words(min, max)) => shingles(&tokens, min, max),
+            Some(NGrams::Chars(min, max)) => char_ngrams(tokens, min, max),
+        }
+    }
+
+    // Return the longest protected phrase that `words` start with.
+    fn phrase_at(&self, words: &[(usize, &str)]) -> Option<&[String]> {
+        if self.phrases.is_empty() {
+            return None;
+        }
+        let phrases = self.phrases.get(&*Case::Lowercase.fold(words[0].1))?;
+        let phrase = phrases.iter().find(|phrase| {
+            phrase.len() <= words.len()
+                && phrase[1..]
+                    .iter()
+                    .zip(&words[1..])
+                    .all(|(p, (_, word))| *p == Case::Lowercase.fold(word))
+        })?;
+        Some(phrase)
+    }
+
+    // Analyze `text`, which starts at `offset`, into `tokens`.
+    fn analyze_into(
+        &self,
+        text: &str,
+        offset: usize,
+        tokens: &mut Vec<Token>,
+    ) {
+        if !self.markup {
+            return self.analyze_text_into(text, offset, tokens);
+        }
+        let stripped = Stripped::new(text);
+        let from = tokens.len();
+        self.analyze_text_into(&stripped.text, 0, tokens);
+        for token in &mut tokens[from..] {
+            token.start = offset + stripped.start(token.start);
+            token.end = offset + stripped.end(token.end);
+        }
+    }
+
+    // Analyze `text`, stripped of any markup, as by `analyze_into`.
+    fn analyze_text_into(
+        &self,
+        text: &str,
+        offset: usize,
+        tokens: &mut Vec<Token>,
+    ) {
+        if self.classes.is_empty() {
+            return self.analyze_words_into(text, offset, tokens);
+        }
+        let mut at = 0;
+        for (start, token, class) in special_tokens(text) {
+            let keep = match self.classes.iter().find(|&&(c, _)| c == class) {
+                Some(&(_, keep)) => keep,
+                None => continue,
+            };
+            self.analyze_words_into(&text[at..start], offset + at, tokens);
+            at = start + token.len();
+            if keep {
+                tokens.push(Token {
+                    term: token.to_owned(),
+                    kind: TokenKind::Stem,
+                    start: offset + start,
+                    end: offset + at,
+                });
+            }
+        }
+        self.analyze_words_into(&text[at..], offset + at, tokens);
+    }
+
+    // Analyze the words of `text`, which starts at `offset`, into `tokens`.
+    fn analyze_words_into(
+        &self,
+        text: &str,
+        offset: usize,
+        tokens: &mut Vec<Token>,
+    ) {
+        let words: Vec<_> = text.unicode_word_indices().collect();
+        let mut i = 0;
+        while i < words.len() {
+            let (start, word) = words[i];
+            if let Some(phrase) = self.phrase_at(&words[i..]) {
+                let (last, last_word) = words[i + phrase.len() - 1];
+                tokens.push(Token {
+                    term: phrase.join(" "),
+                    kind: TokenKind::Stem,
+                    start: offset + start,
+                    end: offset + last + last_word.len(),
+                });
+                i += phrase.len();
+                continue;
+            }
+            i += 1;
+
+            let start = offset + start;
+            let end = start + word.len();
+            for (term, original) in self.run(word) {
+                if let Some(original) = original {
+                    if !original.is_empty() && original != term {
+                        tokens.push(Token {
+                            term: original,
+                            kind: TokenKind::Original,
+                            start,
+                            end,
+                        });
+                    }
+                }
+                tokens.push(Token {
+                    term,
+                    kind: TokenKind::Stem,
+                    start,
+                    end,
+                });
+            }
+        }
+    }
+}
+
+// Return the shingles of `min` to `max` stems of `tokens`, in order of
+// their first stem, with the originals in their place.
+fn shingles(tokens: &[Token], min: usize, max: usize) -> Vec<Token> {
+    let stems: Vec<_> = tokens
+        .iter()
+        .filter(|token| token.kind == TokenKind::Stem)
+        .collect();
+    let mut out = Vec::new();
+    let mut i = 0;
+    for token in tokens {
+        if token.kind == TokenKind::Original {
+            out.push(token.clone());
+            continue;
+        }
+        for n in min..=max.min(stems.len() - i) {
+            let words = &stems[i..i + n];
+            let terms: Vec<_> =
+                words.iter().map(|t| t.term.as_str()).collect();
+            out.push(Token {
+                term: terms.join(" "),
+                kind: TokenKind::Stem,
+                start: token.start,
+                end: words[n - 1].end,
+            });
+        }
+        i += 1;
+    }
+    out
+}
+
+// Return the character n-grams of `min` to `max` characters of the stems of
+// `tokens`, keeping whole the stems shorter than `min`.
+fn char_ngrams(tokens: Vec<Token>, min: usize, max: usize) -> Vec<Token> {
+    let mut out = Vec::new();
+    for token in tokens {
+        let chars: Vec<_> = token.term.char_indices().collect();
+        if token.kind == TokenKind::Original || chars.len() < min {
+            out.push(token);
+            continue;
+        }
+        for (i, &(from, _)) in chars.iter().enumerate() {
+            for n in min..=max.min(chars.len() - i) {
+                let to = chars.get(i + n).map_or(token.term.len(), |c| c.0);
+                out.push(Token {
+                    term: token.term[from..to].to_owned(),
+                    ..token.clone()
+                });
+            }
+        }
+    }
+    out
+}
+
+/// The two uses of an analyzer in a search engine.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum Profile {
+    /// Analyzing documents to index them.
+    Index,
+    /// Analyzing queries to search the index.
+    Query,
+}
+
+/// A pair of analyzers for indexing documents and for analyzing queries,
+/// made from one [`Pipeline`] so that they agree.
+///
+/// The two profiles share the stages of the pipeline and can differ only in
+/// ways that keep them compatible: every term of a query analyzed with the
+/// query profile is a term the index profile makes of the same text, so a
+/// query never looks for a term the index cannot hold.
+///
+/// # Examples
+///
+/// ```
+/// use polystem::{Algorithm, Pipeline, Profile, Profiles, Stopwords};
+///
+/// let pipeline = Pipeline::new().lowercase().stem(Algorithm::Porter);
+/// let profiles =
+///     Profiles::new(pipeline).query_stopwords(Stopwords::english());
+///
+/// let text = "The rusted ponies";
+/// assert_eq!(vec!["the", "rust", "poni"], profiles.index().terms(text));
+/// assert_eq!(vec!["rust", "poni"], profiles.query().terms(text));
+/// assert_eq!(
+///     profiles.get(Profile::Query).terms(text),
+///     profiles.query().terms(text)
+/// );
+/// ```
+#[derive(Clone, Debug)]
+pub struct Profiles {
+    index: Pipeline,
+    query: Pipeline,
+}
+
+impl Profiles {
+    /// Construct the profiles of `pipeline`, which are both `pipeline`
+    /// until they are configured otherwise.
+    pub fn new(pipeline: Pipeline) -> Profiles {
+        Profiles {
+            index: pipeline.clone(),
+            query: pipeline,
+        }
+    }
+
+    /// Index the words before stemming alongside their stems, see
+    /// [`Pipeline::keep_original`], while queries are stemmed only.
+    pub fn keep_originals(mut self) -> Profiles {
+        self.index.keep_original = true;
+        self
+    }
+
+    /// Drop `stopwords` from queries, but not from documents, so that an
+    /// index can still answer phrase queries containing them.
+    ///
+    /// The stopwords are matched as written, before the first stemming
+    /// stage of the pipeline.
+    pub fn query_stopwords(mut self, stopwords: Stopwords) -> Profiles {
+        let stages = &mut self.query.stages;
+        let at = stages
+            .iter()
+            .position(|stage| matches!(stage, Stage::Stem(_)))
+            .unwrap_or(stages.len());
+        stages.insert(at, Stage::Stopwords(stopwords));
+        self
+    }
+
+    /// Return the analyzer of documents.
+    pub fn index(&self) -> &Pipeline {
+        &self.index
+    }
+
+    /// Return the analyzer of queries.
+    pub fn query(&self) -> &Pipeline {
+        &self.query
+    }
+
+    /// Return the analyzer of `profile`.
+    pub fn get(&self, profile: Profile) -> &Pipeline {
+        match profile {
+            Profile::Index => &self.index,
+            Profile::Query => &self.query,
+        }
+    }
+}
+
+#[cfg(all(test, feature = "english"))]
+mod tests {
+    use super::*;
+    use crate::Algorithm;
+
+    #[test]
+    fn test_stages() {
+        let pipeline = Pipeline::new();
+        assert_eq!(pipeline.terms("The Dog's"), vec!["The", "Dog's"]);
+
+        let pipeline = Pipeline::new().lowercase().strip_possessives();
+        assert_eq!(
+            pipeline.terms("The DOG'S ÉLAN"),
+            vec!["the", "dog", "élan"]
+        );
+        assert!(pipeline.analyze_word("'s").is_empty());
+
+        // The stages run in order, so the possessive is stemmed here.
+        let pipeline = Pipeline::new().stem(Algorithm::S).strip_possessives();
+        assert_eq!(pipeline.analyze_word("ponies'"), ["ponies"]);
+    }
+
+    #[test]
+    fn test_stopwords() {
+        let stopwords: Stopwords = vec!["is", "the"].into_iter().collect();
+        let pipeline = Pipeline::new()
+            .lowercase()
+            .stopwords(stopwords)
+            .stem(Algorithm::Porter);
+        let text = "The pony is THE rustiest";
+        let tokens = pipeline.analyze(text);
+        assert_eq!(tokens.len(), 2);
+        assert_eq!(&text[tokens[1].range()], "rustiest");
+
+        // Porter stems `this` to `thi`, which only the stemmed list drops.
+        let stopwords = Stopwords::english();
+        let pipeline = Pipeline::new()
+            .stem(Algorithm::Porter)
+            .stopwords(stopwords.clone());
+        assert_eq!(pipeline.terms("this pony"), vec!["thi", "poni"]);
+        let pipeline = Pipeline::new()
+            .stem(Algorithm::Porter)
+            .stopwords(stopwords.stemmed(&Algorithm::Porter));
+        assert_eq!(pipeline.terms("this pony"), vec!["poni"]);
+    }
+
+    #[test]
+    fn test_analyze() {
+        let pipeline = Pipeline::new().lowercase().stem(Algorithm::Porter);
+        let text = "«Ponies» rusted";
+        let tokens = pipeline.analyze(text);
+        assert_eq!(tokens.len(), 2);
+        assert_eq!(tokens[0].term, "poni");
+        assert_eq!(&text[tokens[0].range()], "Ponies");
+        assert_eq!(tokens[1].range(), 11..17);
+    }
+
+    #[test]
+    fn test_keep_original() {
+        let pipeline = Pipeline::new()
+            .lowercase()
+            .stem(Algorithm::Porter)
+            .keep_original();
+        let tokens = pipeline.analyze("The Ponies");
+        let terms: Vec<_> = tokens
+            .iter()
+            .map(|t| (t.term.as_str(), t.kind, t.start))
+            .collect();
+        assert_eq!(
+            terms,
+            vec![
+                ("the", TokenKind::Stem, 0),
+                ("ponies", TokenKind::Original, 4),
+                ("poni", TokenKind::Stem, 4),
+            ]
+        );
+        assert_eq!(pipeline.analyze_word("Ponies"), ["poni"]);
+
+        // Without a stemming stage there is no original to keep.
+        let pipeline = Pipeline::new().lowercase().keep_original();
+        assert_eq!(pipeline.terms("Ponies"), vec!["ponies"]);
+    }
+
+    #[test]
+    fn test_protect_phrases() {
+        let pipeline = Pipeline::new()
+            .stem(Algorithm::Porter)
+            .protect_phrases(vec!["New York", "new york times", ""])
+            .protect_phrases(vec!["news"]);
+        let text = "new YORK, times news. New York rusted";
+        let tokens = pipeline.analyze(text);
+        let terms: Vec<_> = tokens.iter().map(|t| t.term.as_str()).collect();
+        assert_eq!(terms, vec!["new york times", "news", "new york", "rust"]);
+        assert_eq!(&text[tokens[0].range()], "new YORK, times");
+        assert_eq!(pipeline.terms("new yorkers"), vec!["new", "yorker"]);
+        assert_eq!(pipeline.terms("new"), vec!["new"]);
+
+        let text = "New\nYork";
+        let tokens = pipeline.analyze_reader(text.as_bytes()).unwrap();
+        assert_eq!(tokens.len(), 2);
+    }
+
+    #[test]
+    fn test_normalize_numbers() {
+        let pipeline = Pipeline::new().normalize_numbers();
+        let text = "It cost 1.234,50 on 22nd June, not 3.14";
+        let tokens = pipeline.analyze(text);
+        let terms: Vec<_> = tokens.iter().map(|t| t.term.as_str()).collect();
+        assert_eq!(
+            terms,
+            vec!["It", "cost", "1234.50", "on", "22", "June", "not", "3.14"]
+        );
+        assert_eq!(&text[tokens[2].range()], "1.234,50");
+    }
+
+    #[test]
+    fn test_classes() {
+        let pipeline = Pipeline::new()
+            .lowercase()
+            .stem(Algorithm::Porter)
+            .protect_phrases(vec!["see me"])
+            .strip(TokenClass::Url)
+            .pass_through(TokenClass::Email)
+            .pass_through(TokenClass::Handle)
+            .strip(TokenClass::Handle);
+        let text = "See Me@Example.com, see www.example.com/ponies me";
+        let tokens = pipeline.analyze(text);
+        let terms: Vec<_> = tokens.iter().map(|t| t.term.as_str()).collect();
+        assert_eq!(terms, vec!["see", "Me@Example.com", "see", "me"]);
+        assert_eq!(tokens[1].range(), 4..18);
+        assert_eq!(pipeline.terms("@handle #tag"), vec!["tag"]);
+
+        let pipeline = Pipeline::new().stem(Algorithm::Porter);
+        assert_eq!(pipeline.terms("@ponies"), vec!["poni"]);
+    }
+
+    #[test]
+    fn test_term_stats() {
+        let pipeline = Pipeline::new().lowercase().stem(Algorithm::S);
+        let mut stats = crate::TermStats::new();
+        stats.add_document(pipeline.analyze("Cats and cats"));
+        stats.add_document(pipeline.analyze("a cat"));
+        assert_eq!(stats.get("cat").unwrap().frequency, 3);
+        assert_eq!(stats.get("cat").unwrap().documents, 2);
+    }
+
+    #[test]
+    fn test_decompound() {
+        let decompounder = Decompounder::new(vec!["wind", "kraft", "mills"]);
+        let pipeline = Pipeline::new()
+            .decompound(decompounder)
+            .stopwords(vec!["kraft"].into_iter().collect())
+            .stem(Algorithm::S)
+            .keep_original();
+        let text = "Windkraftmills";
+        let tokens = pipeline.analyze(text);
+        let terms: Vec<_> = tokens
+            .iter()
+            .map(|t| (t.term.as_str(), t.kind, t.range()))
+            .collect();
+        assert_eq!(
+            terms,
+            vec![
+                ("wind", TokenKind::Stem, 0..14),
+                ("mills", TokenKind::Original, 0..14),
+                ("mill", TokenKind::Stem, 0..14),
+            ]
+        );
+        assert_eq!(pipeline.analyze_word("Windrad"), ["windrad"]);
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn test_regex() {
+        let pipeline = Pipeline::new()
+            .drop_matching(Regex::new(r"^\d+$").unwrap())
+            .replace_matching(Regex::new(r"(\w)-(\w)").unwrap(), "$1$2")
+            .keep_matching(Regex::new(r"^[a-z]").unwrap())
+            .stem(Algorithm::Porter);
+        assert_eq!(pipeline.analyze_word("2024"), Vec::<String>::new());
+        assert_eq!(pipeline.analyze_word("e-mails"), ["email"]);
+        assert_eq!(pipeline.analyze_word("Ponies"), Vec::<String>::new());
+        assert_eq!(pipeline.analyze_word("ponies"), ["poni"]);
+
+        let pipeline = Pipeline::new()
+            .replace_matching(Regex::new("^(?i)tmp.*").unwrap(), "");
+        assert_eq!(pipeline.terms("tmp123 kept TMPX"), ["kept"]);
+        assert_eq!(
+            format!("{:?}", pipeline),
+            "Pipeline { stages: [Replace], keep_original: false, ngrams: \
+             None, phrases: {}, classes: [], markup: false }"
+        );
+    }
+
+    #[test]
+    fn test_strip_markup() {
+        let pipeline = Pipeline::new()
+            .strip_markup()
+            .lowercase()
+            .protect_phrases(vec!["new york"])
+            .pass_through(TokenClass::Url);
+        let html = "<h1>New <i>York</i></h1><p>caf&eacute;s at \
+                    <a href=\"/x\">example.com/menu</a></p>";
+        let tokens = pipeline.analyze(html);
+        let terms: Vec<_> = tokens
+            .iter()
+            .map(|t| (t.term.as_str(), &html[t.range()]))
+            .collect();
+        assert_eq!(
+            terms,
+            vec![
+                ("new york", "New <i>York"),
+                ("cafés", "caf&eacute;s"),
+                ("at", "at"),
+                ("example.com/menu", "example.com/menu"),
+            ]
+        );
+
+        let text = "<p>a\nb</p>\n<p\nclass=x>c</p>";
+        let tokens = pipeline.analyze_reader(text.as_bytes()).unwrap();
+        assert_eq!(tokens, pipeline.analyze(text));
+        assert_eq!(tokens.len(), 3);
+        assert_eq!(&text[tokens[2].range()], "c");
+    }
+
+    #[test]
+    fn test_analyze_sentences() {
+        let splitter = SentenceSplitter::new();
+        let pipeline = Pipeline::new().lowercase().strip_markup();
+        let html = "<p>Dr. Who?</p><p>No.</p><p>!</p><p>Caf&eacute;s.</p>";
+        let sentences = pipeline.analyze_sentences(&splitter, html);
+        let sentences: Vec<Vec<_>> = sentences
+            .iter()
+            .map(|tokens| {
+                tokens
+                    .iter()
+                    .map(|t| (t.term.as_str(), &html[t.range()]))
+                    .collect()
+            })
+            .collect();
+        assert_eq!(
+            sentences,
+            vec![
+                vec![("dr", "Dr"), ("who", "Who")],
+                vec![("no", "No")],
+                vec![],
+                vec![("cafés", "Caf&eacute;s")],
+            ]
+        );
+        assert!(pipeline.analyze_sentences(&splitter, " ").is_empty());
+    }
+
+    #[test]
+    fn test_shingles() {
+        let pipeline = Pipeline::new().lowercase().shingles(2, 3);
+        let text = "New York\ntimes";
+        let tokens = pipeline.analyze_reader(text.as_bytes()).unwrap();
+        let terms: Vec<_> = tokens.iter().map(|t| t.term.as_str()).collect();
+        assert_eq!(terms, vec!["new york", "new york times", "york times"]);
+        assert_eq!(&text[tokens[1].range()], "New York\ntimes");
+        assert!(pipeline.analyze("alone").is_empty());
+
+        let pipeline = Pipeline::new()
+            .stem(Algorithm::S)
+            .keep_original()
+            .shingles(1, 2);
+        assert_eq!(
+            pipeline.terms("cats run"),
+            vec!["cats", "cat", "cat run", "run"]
+        );
+    }
+
+    #[test]
+    fn test_char_ngrams() {
+        let pipeline = Pipeline::new().char_ngrams(3, 3);
+        let tokens = pipeline.analyze("a café");
+        let terms: Vec<_> = tokens.iter().map(|t| t.term.as_str()).collect();
+        assert_eq!(terms, vec!["a", "caf", "afé"]);
+        assert_eq!(tokens[2].range(), 2..7);
+
+        let pipeline = pipeline.shingles(1, 1);
+        assert_eq!(pipeline.terms("a café"), vec!["a", "café"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "the minimum n must not exceed the maximum")]
+    fn test_ngrams_bounds() {
+        let _ = Pipeline::new().char_ngrams(3, 2);
+    }
+
+    #[test]
+    fn test_profiles() {
+        let stopwords: Stopwords = vec!["was"].into_iter().collect();
+        let pipeline = Pipeline::new().lowercase().stem(Algorithm::Porter);
+        let profiles = Profiles::new(pipeline).query_stopwords(stopwords);
+        // Porter stems `was` to `wa`, so the stopword is matched before
+        // stemming.
+        let text = "It WAS rusted";
+        assert_eq!(profiles.index().terms(text), vec!["it", "wa", "rust"]);
+        assert_eq!(profiles.query().terms(text), vec!["it", "rust"]);
+
+        let profiles = Profiles::new(Pipeline::new())
+            .query_stopwords(vec!["the"].into_iter().collect());
+        assert_eq!(profiles.get(Profile::Query).terms("The end"), ["end"]);
+        assert_eq!(profiles.get(Profile::Index).terms("The end").len(), 2);
+
+        let pipeline = Pipeline::new().stem(Algorithm::S);
+        let profiles = Profiles::new(pipeline).keep_originals();
+        assert_eq!(profiles.index().terms("cats"), vec!["cats", "cat"]);
+        assert_eq!(profiles.query().terms("cats"), vec!["cat"]);
+    }
+
+    #[test]
+    fn test_analyze_reader() {
+        let pipeline = Pipeline::new().lowercase().stem(Algorithm::S);
+        let text = "Ponies\n  cats, dogs\n";
+        let tokens = pipeline.analyze_reader(text.as_bytes()).unwrap();
+        let stems: Vec<_> = tokens.iter().map(|t| t.term.as_str()).collect();
+        assert_eq!(stems, vec!["pony", "cat", "dog"]);
+        assert_eq!(&text[tokens[2].range()], "dogs");
+
+        let err = pipeline.analyze_reader(&b"caf\xe9"[..]).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    }
+}
@@ -0,0 +1,616 @@
+// Copyright 2019 The Polystem authors.
+//
+// For the full copyright and license information, please view the LICENSE file
+// that was distributed with this source code.
+
+// The configuration files of a `Pipeline`, read with `Pipeline::from_toml`
+// and `Pipeline::from_yaml`, and of `FieldAnalyzers`.
+
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+
+#[cfg(feature = "regex")]
+use regex::Regex;
+use serde::Deserialize;
+
+use super::{Pipeline, Stage};
+use crate::tokenize::TokenClass;
+use crate::{
+    by_name, ConfigError, Configured, Decompounder, DynStemmer,
+    FieldAnalyzers, Overlap, StemError, StemOptions, Stopwords,
+};
+
+// The analyzers of the fields of records, as written in a configuration
+// file.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct FieldsConfig {
+    #[serde(default)]
+    fields: BTreeMap<String, PipelineConfig>,
+    #[serde(default)]
+    keyword: Vec<String>,
+    fallback: Option<PipelineConfig>,
+}
+
+// The settings of a pipeline, as written in a configuration file.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct PipelineConfig {
+    #[serde(default)]
+    stages: Vec<StageConfig>,
+    #[serde(default)]
+    strip_markup: bool,
+    #[serde(default)]
+    keep_original: bool,
+    #[serde(default)]
+    protect_phrases: Vec<String>,
+    #[serde(default)]
+    pass_through: Vec<TokenClass>,
+    #[serde(default)]
+    strip: Vec<TokenClass>,
+    shingles: Option<(usize, usize)>,
+    char_ngrams: Option<(usize, usize)>,
+}
+
+// A stage of a pipeline, as written in a configuration file, with its kind
+// in the `type` field.
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case", deny_unknown_fields)]
+enum StageConfig {
+    Lowercase,
+    StripPossessives,
+    NormalizeNumbers,
+    Stopwords {
+        // The name of a built in list, `english`.
+        builtin: Option<String>,
+        #[serde(default)]
+        words: Vec<String>,
+    },
+    Decompound {
+        vocabulary: Vec<String>,
+        min_part: Option<usize>,
+        #[serde(default)]
+        overlap: Overlap,
+    },
+    Stem {
+        // The name of the stemmer, as accepted by `by_name`.
+        algorithm: String,
+        options: Option<StemOptions>,
+        // The stems of words the algorithm gets wrong.
+        #[serde(default)]
+        exceptions: HashMap<String, String>,
+    },
+    #[cfg(feature = "regex")]
+    KeepMatching {
+        pattern: String,
+    },
+    #[cfg(feature = "regex")]
+    DropMatching {
+        pattern: String,
+    },
+    #[cfg(feature = "regex")]
+    ReplaceMatching {
+        pattern: String,
+        replacement: String,
+    },
+}
+
+// A stemmer returning the stems listed for some words, and stemming the
+// others with `stemmer`.
+struct Exceptions<D> {
+    stemmer: D,
+    stems: HashMap<String, String>,
+}
+
+impl<D: DynStemmer> DynStemmer for Exceptions<D> {
+    fn stem_word(&self, word: &str) -> String {
+        match self.stems.get(word) {
+            Some(stem) => stem.clone(),
+            None => self.stemmer.stem_word(word),
+        }
+    }
+
+    fn try_stem_word(&self, word: &str) -> Result<String, StemError> {
+        match self.stems.get(word) {
+            Some(stem) => Ok(stem.clone()),
+            None => self.stemmer.try_stem_word(word),
+        }
+    }
+}
+
+impl Pipeline {
+    /// Construct a `Pipeline` from its settings in TOML, so that the
+    /// analysis of an index can be versioned alongside it.
+    ///
+    /// The `stages` array lists the stages in order, each with its kind in
+    /// `type`: `lowercase`, `strip_possessives`, `normalize_numbers`,
+    /// `stopwords`, with an optional `builtin` list, `english` or the
+    /// longer `snowball_english`, and extra `words`, `decompound`, with a
+    /// `vocabulary`, `min_part` and `overlap`, and `stem`, with the name of
+    /// an `algorithm` or registered stemmer, as accepted by [`by_name`],
+    /// [`StemOptions`] in `options` and a table of `exceptions` from words
+    /// to their stems.
+    /// With the `regex` feature, `keep_matching` and `drop_matching` take a
+    /// `pattern`, and `replace_matching` a `pattern` and a `replacement`.
+    /// Stopwords listed after a `stem` stage are stemmed with its stemmer,
+    /// as by [`Stopwords::stemmed`].
+    ///
+    /// The other settings are `strip_markup`, `keep_original`,
+    /// `protect_phrases`, the classes of token to `pass_through` or
+    /// `strip`, and the smallest and largest n of `shingles` or
+    /// `char_ngrams`, each as by the method of the same name. Unknown
+    /// settings are an error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polystem::Pipeline;
+    ///
+    /// let pipeline = Pipeline::from_toml(
+    ///     r#"
+    ///     protect_phrases = ["new york"]
+    ///     pass_through = ["url"]
+    ///
+    ///     [[stages]]
+    ///     type = "lowercase"
+    ///
+    ///     [[stages]]
+    ///     type = "stopwords"
+    ///     builtin = "english"
+    ///
+    ///     [[stages]]
+    ///     type = "stem"
+    ///     algorithm = "porter"
+    ///     exceptions = { news = "news" }
+    ///     "#,
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!(
+    ///     vec!["new york", "news", "poni", "example.com"],
+    ///     pipeline.terms("The New York news of ponies at example.com")
+    /// );
+    /// ```
+    #[cfg(feature = "toml")]
+    pub fn from_toml(config: &str) -> Result<Pipeline, ConfigError> {
+        let config: PipelineConfig = toml::from_str(config)
+            .map_err(|e| ConfigError::Syntax(e.message().to_owned()))?;
+        config.build()
+    }
+
+    /// Construct a `Pipeline` from its settings in YAML, which are those
+    /// read by [`from_toml`](Pipeline::from_toml).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polystem::Pipeline;
+    ///
+    /// let pipeline = Pipeline::from_yaml(
+    ///     "
+    /// stages:
+    ///   - type: lowercase
+    ///   - type: stem
+    ///     algorithm: s
+    /// shingles: [1, 2]
+    /// ",
+    /// )
+    /// .unwrap();
+    ///
+    /// let terms = pipeline.terms("Ponies run");
+    /// assert_eq!(vec!["pony", "pony run", "run"], terms);
+    /// ```
+    #[cfg(feature = "yaml")]
+    pub fn from_yaml(config: &str) -> Result<Pipeline, ConfigError> {
+        let config: PipelineConfig = serde_yaml::from_str(config)
+            .map_err(|e| ConfigError::Syntax(e.to_string()))?;
+        config.build()
+    }
+}
+
+impl FieldAnalyzers {
+    /// Construct a `FieldAnalyzers` from its settings in TOML, the schema
+    /// of the records of an index.
+    ///
+    /// The `fields` table has the settings of the pipeline of each field,
+    /// as read by [`Pipeline::from_toml`], `keyword` lists the keyword
+    /// fields, and `fallback` has the settings of the pipeline of the
+    /// other fields. A field can not be both a keyword field and have a
+    /// pipeline.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polystem::FieldAnalyzers;
+    ///
+    /// let analyzers = FieldAnalyzers::from_toml(
+    ///     r#"
+    ///     keyword = ["id"]
+    ///
+    ///     [fields.title]
+    ///     stages = [
+    ///         { type = "lowercase" },
+    ///         { type = "stem", algorithm = "s" },
+    ///     ]
+    ///
+    ///     [fields.body]
+    ///     stages = [{ type = "stem", algorithm = "porter" }]
+    ///     "#,
+    /// )
+    /// .unwrap();
+    ///
+    /// let terms = |field, text| analyzers.terms(field, text);
+    /// assert_eq!(Some(vec!["pony".into()]), terms("title", "Ponies"));
+    /// assert_eq!(Some(vec!["poni".into()]), terms("body", "ponies"));
+    /// assert_eq!(Some(vec!["A-1".into()]), terms("id", "A-1"));
+    /// assert_eq!(None, terms("color", "grey"));
+    /// ```
+    #[cfg(feature = "toml")]
+    pub fn from_toml(config: &str) -> Result<FieldAnalyzers, ConfigError> {
+        let config: FieldsConfig = toml::from_str(config)
+            .map_err(|e| ConfigError::Syntax(e.message().to_owned()))?;
+        config.build()
+    }
+
+    /// Construct a `FieldAnalyzers` from its settings in YAML, which are
+    /// those read by [`from_toml`](FieldAnalyzers::from_toml).
+    #[cfg(feature = "yaml")]
+    pub fn from_yaml(config: &str) -> Result<FieldAnalyzers, ConfigError> {
+        let config: FieldsConfig = serde_yaml::from_str(config)
+            .map_err(|e| ConfigError::Syntax(e.to_string()))?;
+        config.build()
+    }
+}
+
+impl FieldsConfig {
+    // Construct the analyzers with these settings.
+    fn build(self) -> Result<FieldAnalyzers, ConfigError> {
+        let mut analyzers = FieldAnalyzers::new();
+        if let Some(fallback) = self.fallback {
+            analyzers = analyzers.fallback(fallback.build()?);
+        }
+        for field in &self.keyword {
+            if self.fields.contains_key(field) {
+                return Err(ConfigError::Invalid(format!(
+                    "field `{}` is both a keyword field and has a pipeline",
+                    field
+                )));
+            }
+            analyzers.keyword(field);
+        }
+        for (field, config) in self.fields {
+            let pipeline = config.build().map_err(|e| match e {
+                ConfigError::Invalid(message) => ConfigError::Invalid(
+                    format!("field `{}`: {}", field, message),
+                ),
+                e => e,
+            })?;
+            analyzers.insert(&field, pipeline);
+        }
+        Ok(analyzers)
+    }
+}
+
+impl PipelineConfig {
+    // Construct the pipeline with these settings.
+    fn build(self) -> Result<Pipeline, ConfigError> {
+        let mut pipeline = Pipeline::new();
+        if self.strip_markup {
+            pipeline = pipeline.strip_markup();
+        }
+        // The stemmer of the last `stem` stage so far.
+        let mut stemmer: Option<Arc<dyn DynStemmer + Send + Sync>> = None;
+        for stage in self.stages {
+            pipeline = match stage {
+                StageConfig::Lowercase => pipeline.lowercase(),
+                StageConfig::StripPossessives => pipeline.strip_possessives(),
+                StageConfig::NormalizeNumbers => pipeline.normalize_numbers(),
+                StageConfig::Stopwords { builtin, words } => {
+                    let mut stopwords = match builtin.as_deref() {
+                        Some("english") => Stopwords::english(),
+                        #[cfg(any(feature = "porter", feature = "s"))]
+                        Some("snowball_english") => {
+                            Stopwords::snowball(crate::Language::English)
+                        }
+                        Some(name) => {
+                            return Err(ConfigError::Invalid(format!(
+                                "unknown stopword list `{}`",
+                                name
+                            )));
+                        }
+                        None => Stopwords::new(),
+                    };
+                    stopwords.extend(words);
+                    if let Some(stemmer) = &stemmer {
+                        stopwords = stopwords.stemmed(&**stemmer);
+                    }
+                    pipeline.stopwords(stopwords)
+                }
+                StageConfig::Decompound {
+                    vocabulary,
+                    min_part,
+                    overlap,
+                } => {
+                    let mut decompounder =
+                        Decompounder::new(vocabulary).overlap(overlap);
+                    if let Some(chars) = min_part {
+                        decompounder = decompounder.min_part(chars);
+                    }
+                    pipeline.decompound(decompounder)
+                }
+                StageConfig::Stem {
+                    algorithm,
+                    options,
+                    exceptions,
+                } => {
+                    let algorithm = by_name(&algorithm)
+                        .map_err(|e| ConfigError::Invalid(e.to_string()))?;
+                    let stem: Arc<dyn DynStemmer + Send + Sync> = match options
+                    {
+                        Some(options) => Arc::new(Exceptions {
+                            stemmer: Configured::new(algorithm, options),
+                            stems: exceptions,
+                        }),
+                        None => Arc::new(Exceptions {
+                            stemmer: algorithm,
+                            stems: exceptions,
+                        }),
+                    };
+                    pipeline.stages.push(Stage::Stem(Arc::clone(&stem)));
+                    stemmer = Some(stem);
+                    pipeline
+                }
+                #[cfg(feature = "regex")]
+                StageConfig::KeepMatching { pattern } => {
+                    pipeline.keep_matching(regex(&pattern)?)
+                }
+                #[cfg(feature = "regex")]
+                StageConfig::DropMatching { pattern } => {
+                    pipeline.drop_matching(regex(&pattern)?)
+                }
+                #[cfg(feature = "regex")]
+                StageConfig::ReplaceMatching {
+                    pattern,
+                    replacement,
+                } => pipeline.replace_matching(regex(&pattern)?, &replacement),
+            };
+        }
+
+        if self.keep_original {
+            pipeline = pipeline.keep_original();
+        }
+        pipeline = pipeline.protect_phrases(self.protect_phrases);
+        for class in self.pass_through {
+            pipeline = pipeline.pass_through(class);
+        }
+        for class in self.strip {
+            pipeline = pipeline.strip(class);
+        }
+        match (self.shingles, self.char_ngrams) {
+            (Some(_), Some(_)) => {
+                return Err(ConfigError::Invalid(
+                    "shingles and char_ngrams can not both be set".to_owned(),
+                ));
+            }
+            (Some((min, max)), None) => {
+                ngrams(min, max)?;
+                pipeline = pipeline.shingles(min, max);
+            }
+            (None, Some((min, max))) => {
+                ngrams(min, max)?;
+                pipeline = pipeline.char_ngrams(min, max);
+            }
+            (None, None) => {}
+        }
+        Ok(pipeline)
+    }
+}
+
+// Check the smallest and largest n of the n-grams of a configuration.
+fn ngrams(min: usize, max: usize) -> Result<(), ConfigError> {
+    if min == 0 || min > max {
+        return Err(ConfigError::Invalid(format!(
+            "invalid n-gram sizes [{}, {}]",
+            min, max
+        )));
+    }
+    Ok(())
+}
+
+// Compile the regular expression `pattern` of a configuration.
+#[cfg(feature = "regex")]
+fn regex(pattern: &str) -> Result<Regex, ConfigError> {
+    Regex::new(pattern).map_err(|e| ConfigError::Invalid(e.to_string()))
+}
+
+#[cfg(all(test, feature = "english"))]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn test_from_toml() {
+        let pipeline = Pipeline::from_toml(
+            r#"
+            keep_original = true
+            strip_markup = true
+            strip = ["hashtag"]
+            char_ngrams = [3, 3]
+
+            [[stages]]
+            type = "stem"
+            algorithm = "s"
+            options = { preserve_case = true }
+
+            [[stages]]
+            type = "stopwords"
+            words = ["Flies"]
+            "#,
+        )
+        .unwrap();
+        assert_eq!(pipeline.terms("Flies"), Vec::<String>::new());
+        assert_eq!(format!("{:?}", pipeline.stages), "[Stem, Stopwords]");
+        assert!(pipeline.keep_original && pipeline.markup);
+        assert!(matches!(
+            pipeline.ngrams,
+            Some(crate::pipeline::NGrams::Chars(3, 3))
+        ));
+        let pipeline = pipeline.char_ngrams(9, 9);
+        assert_eq!(pipeline.terms("Suns #x"), ["Suns", "Sun"]);
+
+        let pipeline = Pipeline::from_toml(
+            "[[stages]]\ntype = \"stopwords\"\nbuiltin = \"snowball_english\"",
+        )
+        .unwrap();
+        assert_eq!(pipeline.terms("she would go"), ["go"]);
+
+        let errors = [
+            ("lowercase = true", "unknown field `lowercase`"),
+            ("[[stages]]\ntype = \"upper\"", "unknown variant `upper`"),
+            (
+                "[[stages]]\ntype = \"stem\"\nalgorithm = \"krovetz\"",
+                "unknown stemming algorithm `krovetz`",
+            ),
+            (
+                "[[stages]]\ntype = \"stopwords\"\nbuiltin = \"klingon\"",
+                "unknown stopword list `klingon`",
+            ),
+            ("shingles = [3, 2]", "invalid n-gram sizes [3, 2]"),
+            ("char_ngrams = [0, 2]", "invalid n-gram sizes [0, 2]"),
+        ];
+        for &(config, message) in &errors {
+            let e = Pipeline::from_toml(config).unwrap_err();
+            assert!(e.to_string().contains(message), "{}: {}", config, e);
+        }
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn test_registered_stemmer() {
+        struct Upper;
+
+        impl DynStemmer for Upper {
+            fn stem_word(&self, word: &str) -> String {
+                word.to_uppercase()
+            }
+
+            fn try_stem_word(&self, word: &str) -> Result<String, StemError> {
+                Ok(self.stem_word(word))
+            }
+        }
+
+        crate::register_stemmer("test-config-upper", || Box::new(Upper));
+        let pipeline = Pipeline::from_toml(
+            r#"
+            [[stages]]
+            type = "stem"
+            algorithm = "test-config-upper"
+            exceptions = { ponies = "pony" }
+            "#,
+        )
+        .unwrap();
+        assert_eq!(pipeline.terms("rusted ponies"), ["RUSTED", "pony"]);
+    }
+
+    #[cfg(feature = "yaml")]
+    #[test]
+    fn test_from_yaml() {
+        let pipeline = Pipeline::from_yaml(
+            "
+stages:
+  - type: decompound
+    vocabulary: [sun, flower]
+  - type: stem
+    algorithm: porter
+    exceptions: {flower: bloom}
+protect_phrases: [new york]
+",
+        )
+        .unwrap();
+        assert_eq!(
+            pipeline.terms("sunflower New York"),
+            ["sun", "bloom", "new york"]
+        );
+        assert!(matches!(
+            Pipeline::from_yaml("stages: 1"),
+            Err(ConfigError::Syntax(_))
+        ));
+    }
+
+    #[cfg(all(feature = "toml", feature = "regex"))]
+    #[test]
+    fn test_regex_stages() {
+        let pipeline = Pipeline::from_toml(
+            r#"
+            [[stages]]
+            type = "drop_matching"
+            pattern = '^\d+$'
+
+            [[stages]]
+            type = "replace_matching"
+            pattern = "^v(\\d)"
+            replacement = "version$1"
+            "#,
+        )
+        .unwrap();
+        assert_eq!(pipeline.terms("v2 of 2024"), ["version2", "of"]);
+        let e = Pipeline::from_toml(
+            "[[stages]]\ntype = \"keep_matching\"\npattern = \"(\"",
+        )
+        .unwrap_err();
+        assert!(matches!(e, ConfigError::Invalid(_)));
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn test_fields_from_toml() {
+        let analyzers = FieldAnalyzers::from_toml(
+            r#"
+            keyword = ["id"]
+
+            [fields.title]
+            stages = [{ type = "stem", algorithm = "s" }]
+
+            [fallback]
+            stages = [{ type = "lowercase" }]
+            "#,
+        )
+        .unwrap();
+        assert_eq!(analyzers.terms("title", "Suns").unwrap(), ["sun"]);
+        assert_eq!(analyzers.terms("id", "X 1").unwrap(), ["X 1"]);
+        assert_eq!(analyzers.terms("body", "Suns").unwrap(), ["suns"]);
+
+        let errors = [
+            ("keyword = 1", "invalid type"),
+            ("[field.a]", "unknown field `field`"),
+            (
+                "keyword = [\"a\"]\n[fields.a]",
+                "field `a` is both a keyword field and has a pipeline",
+            ),
+            ("[fields.a]\nshingles = [2, 1]", "field `a`: invalid n-gram"),
+        ];
+        for &(config, message) in &errors {
+            let e = FieldAnalyzers::from_toml(config).unwrap_err();
+            assert!(e.to_string().contains(message), "{}: {}", config, e);
+        }
+    }
+
+    #[cfg(feature = "yaml")]
+    #[test]
+    fn test_fields_from_yaml() {
+        let analyzers = FieldAnalyzers::from_yaml(
+            "
+fields:
+  body:
+    stages:
+      - type: stem
+        algorithm: porter
+keyword: [id]
+",
+        )
+        .unwrap();
+        assert_eq!(analyzers.terms("body", "ponies").unwrap(), ["poni"]);
+        assert_eq!(analyzers.terms("id", "ponies").unwrap(), ["ponies"]);
+        assert_eq!(analyzers.terms("title", "ponies"), None);
+    }
+}
@@ -0,0 +1,1104 @@
+// Copyright 2019 The Polystem authors.
+//
+// For the full copyright and license information, please view the LICENSE file
+// that was distributed with this source code.
+
+use std::borrow::Cow;
+use std::fmt;
+use std::str;
+
+use crate::suffix::SuffixTrie;
+use crate::{
+    copy_to_buf, is_short, load_buf, Aggressiveness, BufTooSmall, Language,
+    Stemmer, StemmerInfo, StemmerMut,
+};
+
+#[cfg(feature = "simd")]
+use crate::swar::ends_with as suffix_eq;
+
+// Return `true` if `word` ends with `suffix`.
+#[cfg(not(feature = "simd"))]
+#[inline]
+fn suffix_eq(word: &[u8], suffix: &[u8]) -> bool {
+    word.ends_with(suffix)
+}
+
+// The suffix tables `STEP2`, `STEP3` and `STEP4`, generated by `build.rs`
+// from `rules/porter.rules`.
+include!(concat!(env!("OUT_DIR"), "/porter_rules.rs"));
+
+// The last letters of the suffixes the steps remove or rewrite, as a mask of
+// their offsets from 'a': those of `step1ab` ("s", "ed" and "ing"), `step1c`
+// ("y") and `step5` ("e" and "ll"), and of the tables of the other steps. A
+// lowercase word ending in any other byte is left unchanged.
+const FINAL_LETTERS: u32 = letter_mask(b"sdgyel")
+    | last_letters(STEP2)
+    | last_letters(STEP3)
+    | last_letters(STEP4);
+
+// Return the mask of `letters` by their offsets from 'a'.
+const fn letter_mask(letters: &[u8]) -> u32 {
+    let mut mask = 0;
+    let mut i = 0;
+    while i < letters.len() {
+        mask |= 1 << (letters[i] - b'a');
+        i += 1;
+    }
+    mask
+}
+
+// Return the mask of the last letters of the suffixes of `rules`, by their
+// offsets from 'a'.
+const fn last_letters(rules: &[(&str, &str)]) -> u32 {
+    let mut mask = 0;
+    let mut i = 0;
+    while i < rules.len() {
+        let suffix = rules[i].0.as_bytes();
+        mask |= 1 << (suffix[suffix.len() - 1] - b'a');
+        i += 1;
+    }
+    mask
+}
+
+static STEP2_TRIE: SuffixTrie = SuffixTrie::new(STEP2);
+static STEP3_TRIE: SuffixTrie = SuffixTrie::new(STEP3);
+static STEP4_TRIE: SuffixTrie = SuffixTrie::new(STEP4);
+
+// The longest word `Porter::stem` stems in a buffer on the stack. Almost every
+// English word is shorter, longer words are stemmed in a buffer on the heap.
+const INLINE_LEN: usize = 24;
+
+// The number of leading bytes of a word classified by `Porter::consonants`.
+const MASK_LEN: usize = 64;
+
+// Return `true` if `b` is a consonant, given whether the byte before it is
+// one. A 'y' is a consonant at the start of a word and after a vowel.
+#[inline]
+fn consonant(b: u8, prev: bool) -> bool {
+    match b {
+        b'a' | b'e' | b'i' | b'o' | b'u' => false,
+        b'y' => !prev,
+        _ => true,
+    }
+}
+
+// Classify each byte of `word` as a consonant or not, in a single pass.
+fn consonants(word: &[u8]) -> impl Iterator<Item = bool> + '_ {
+    word.iter().scan(false, |prev, &b| {
+        *prev = consonant(b, *prev);
+        Some(*prev)
+    })
+}
+
+// The vowels a, e, i, o and u, by their offset from 'a'.
+const VOWELS: u32 = 1 | 1 << 4 | 1 << 8 | 1 << 14 | 1 << 20;
+
+// Return the mask of the consonants among the first `MASK_LEN` bytes of
+// `word`, where bit `i` is set when byte `i` is a consonant.
+fn consonant_mask(word: &[u8]) -> u64 {
+    let mut mask = 0;
+    let mut ys = 0;
+    for (i, &b) in word.iter().take(MASK_LEN).enumerate() {
+        let c = u32::from(b.wrapping_sub(b'a'));
+        let vowel = c < 32 && VOWELS >> c & 1 == 1;
+        mask |= u64::from(!vowel) << i;
+        ys |= u64::from(b == b'y') << i;
+    }
+
+    // Each 'y' depends on the byte before it, which is final by the time
+    // the lowest 'y' left is reached.
+    while ys != 0 {
+        let i = ys.trailing_zeros();
+        if i > 0 && mask >> (i - 1) & 1 == 1 {
+            mask &= !(1 << i);
+        }
+        ys &= ys - 1;
+    }
+
+    mask
+}
+
+// Return the mask of the first `n` bits.
+#[inline]
+fn low_bits(n: usize) -> u64 {
+    if n >= MASK_LEN {
+        !0
+    } else {
+        (1 << n) - 1
+    }
+}
+
+/// The Porter stemmer.
+///
+/// The type parameter is the buffer the word is stemmed in, a `Vec` reused
+/// between words by [`StemmerMut`], or the caller's buffer for
+/// [`Stemmer::stem_to_buf`].
+pub struct Porter<B = Vec<u8>> {
+    buf: B,
+    // The consonants of the word, see `consonant_mask`. Classified when
+    // first needed, and again after a step rewrites the word.
+    consonants: Option<u64>,
+    k: usize,
+    j: usize,
+    step: &'static str,
+    trace: Option<Vec<Rule>>,
+}
+
+/// A rule that fired while stemming a word, see [`Porter::stem_traced`].
+///
+/// A rule rewrites `suffix` at the end of the word to `replacement`. It is
+/// displayed in the form `step2: ization→ize`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Rule {
+    pub step: &'static str,
+    pub suffix: String,
+    pub replacement: String,
+}
+
+impl fmt::Display for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}→{}", self.step, self.suffix, self.replacement)
+    }
+}
+
+impl Porter {
+    /// The version of the rules of the Porter stemmer.
+    pub const VERSION: u32 = 3;
+
+    // Construct new `Porter`.
+    //
+    // The end index `k` starts counting from 1. The index `j` is a general
+    // index used during the stemming process.
+    fn new(word: &str) -> Porter {
+        Porter {
+            buf: word.to_ascii_lowercase().into_bytes(),
+            consonants: None,
+            k: word.len(),
+            j: 0,
+            step: "",
+            trace: None,
+        }
+    }
+}
+
+impl<B: AsRef<[u8]> + AsMut<[u8]>> Porter<B> {
+    // Check if byte at index `i` is a consonant or not.
+    #[inline]
+    fn is_consonant(&mut self, i: usize) -> bool {
+        if i < MASK_LEN {
+            return self.mask() >> i & 1 == 1;
+        }
+
+        consonants(&self.buf.as_ref()[..=i])
+            .last()
+            .expect("word is not empty")
+    }
+
+    // Return the mask of the consonants of the word, see `consonant_mask`.
+    #[inline]
+    fn mask(&mut self) -> u64 {
+        match self.consonants {
+            Some(mask) => mask,
+            None => {
+                let mask = consonant_mask(&self.buf.as_ref()[..self.k]);
+                self.consonants = Some(mask);
+                mask
+            }
+        }
+    }
+
+    // Have the consonants classified again when next needed, after a step
+    // rewrote the word.
+    #[inline]
+    fn reclassify(&mut self) {
+        self.consonants = None;
+    }
+
+    // Determines if the current stem contains a vowel between indexes
+    // `[0, j)`.
+    #[inline]
+    fn has_vowel(&mut self) -> bool {
+        if self.j <= MASK_LEN {
+            return !self.mask() & low_bits(self.j) != 0;
+        }
+
+        consonants(&self.buf.as_ref()[..self.j]).any(|c| !c)
+    }
+
+    // Returns the number of consonant sequences within `[0, j)`, that is
+    // the number of vowels followed by a consonant.
+    fn count(&mut self) -> usize {
+        if self.j <= MASK_LEN {
+            let mask = self.mask();
+            let vowels = !mask & low_bits(self.j);
+            let consonants = mask & low_bits(self.j);
+            return (vowels << 1 & consonants).count_ones() as usize;
+        }
+
+        let mut prev = true;
+        consonants(&self.buf.as_ref()[..self.j])
+            .filter(|&c| {
+                let vc = !prev && c;
+                prev = c;
+                vc
+            })
+            .count()
+    }
+
+    // Return the byte `n` places from the end of the word, or `None` when
+    // earlier steps left fewer than `n` bytes.
+    #[inline]
+    fn back(&self, n: usize) -> Option<u8> {
+        self.k.checked_sub(n).map(|i| self.buf.as_ref()[i])
+    }
+
+    // Return `true` if `[j, j-1]` contains a double consonant, `false`
+    // otherwise. Only ASCII letters are doubled consonants, so that removing
+    // one of them can not split a multi-byte character.
+    #[inline]
+    fn double_consonant(&mut self, index: usize) -> bool {
+        if index < 1 || index >= self.k {
+            return false;
+        }
+
+        if self.buf.as_ref()[index] != self.buf.as_ref()[index - 1]
+            || !self.buf.as_ref()[index].is_ascii_alphabetic()
+        {
+            return false;
+        }
+
+        self.is_consonant(index)
+    }
+
+    // Consonant - vowel - consonant. Returns `true` when the sequence `index -
+    // 2`, `index - 1`, `index` is of the form consonant, vowel, consonant. The
+    // second consonant in the sequence can not be an 'w', 'x' or 'y'.
+    #[inline]
+    fn cvc(&mut self, index: usize) -> bool {
+        if index < 2 || index >= self.k {
+            return false;
+        }
+
+        if !self.is_consonant(index)
+            || self.is_consonant(index - 1)
+            || !self.is_consonant(index - 2)
+        {
+            return false;
+        }
+
+        !matches!(self.buf.as_ref()[index], b'w' | b'x' | b'y')
+    }
+
+    // Return `true` if the current buffer `self.buf` ends with the string `s`
+    // and update the index `self.j`. Return false otherwise.
+    fn ends_with(&mut self, s: &str) -> bool {
+        let end_bytes = s.as_bytes();
+        let len = end_bytes.len();
+
+        if len > self.k {
+            return false;
+        }
+
+        let word = &self.buf.as_ref()[..self.k];
+        if !suffix_eq(word, end_bytes) {
+            return false;
+        }
+
+        self.j = self.k - len;
+        true
+    }
+
+    // Find the longest suffix of `rules` that the word ends with and that
+    // `accept` allows for the stem before it. Update the index `self.j` to
+    // the start of the suffix and return its replacement.
+    fn longest_suffix<F>(
+        &mut self,
+        trie: &SuffixTrie,
+        rules: &[(&str, &'static str)],
+        accept: F,
+    ) -> Option<&'static str>
+    where
+        F: Fn(&[u8], &str) -> bool,
+    {
+        let word = &self.buf.as_ref()[..self.k];
+        for rule in trie.matches(word) {
+            let (suffix, to) = rules[rule];
+            let stem = &word[..word.len() - suffix.len()];
+            if accept(stem, suffix) {
+                self.j = stem.len();
+                return Some(to);
+            }
+        }
+
+        None
+    }
+
+    // Record that the characters in `[start, k)` are about to be rewritten
+    // to `s`, when a trace was requested.
+    fn record(&mut self, start: usize, s: &str) {
+        if let Some(trace) = self.trace.as_mut() {
+            let suffix = &self.buf.as_ref()[start..self.k];
+            trace.push(Rule {
+                step: self.step,
+                suffix: String::from_utf8_lossy(suffix).into_owned(),
+                replacement: s.to_owned(),
+            });
+        }
+    }
+
+    // Remove the characters in `[end, k)` and update `k`.
+    #[inline]
+    fn truncate(&mut self, end: usize) {
+        self.record(end, "");
+        self.k = end;
+    }
+
+    // Replace characters in `[j, k)` to the string `s` and update `k`.
+    #[inline]
+    fn replace(&mut self, s: &str) {
+        self.record(self.j, s);
+        let len = s.len();
+
+        self.buf.as_mut()[self.j..self.j + len].copy_from_slice(s.as_bytes());
+        self.k = self.j + len;
+        self.reclassify();
+    }
+
+    #[inline]
+    fn r(&mut self, s: &str) {
+        if self.count() > 0 {
+            self.replace(s);
+        }
+    }
+
+    // Strip plurals and 'ed' or 'ing'.
+    //
+    // The following are examples of the operations performed:
+    //
+    // ```notrust
+    // caresses  ->  caress
+    // ponies    ->  poni
+    // ties      ->  ti
+    // caress    ->  caress
+    // cats      ->  cat
+    //
+    // feed      ->  feed
+    // agreed    ->  agree
+    // disabled  ->  disable
+    //
+    // matting   ->  mat
+    // mating    ->  mate
+    // meeting   ->  meet
+    // milling   ->  mill
+    // messing   ->  mess
+    //
+    // meetings  ->  meet
+    // ```
+    #[inline]
+    fn step1ab(&mut self) {
+        if Some(b's') == self.back(1) {
+            if self.ends_with("sses") {
+                self.truncate(self.k - 2);
+            } else if self.ends_with("ies") {
+                self.replace("i");
+            } else if Some(b's') != self.back(2) {
+                self.truncate(self.k - 1);
+            }
+        }
+
+        if self.ends_with("eed") {
+            if self.count() > 0 {
+                self.truncate(self.k - 1);
+            }
+        } else if (self.ends_with("ed") || self.ends_with("ing"))
+            && self.has_vowel()
+        {
+            self.truncate(self.j);
+            if self.ends_with("at") {
+                self.replace("ate");
+            } else if self.ends_with("bl") {
+                self.replace("ble");
+            } else if self.ends_with("iz") {
+                self.replace("ize");
+            } else if self.k > 0 && self.double_consonant(self.k - 1) {
+                if !matches!(self.back(1), Some(b'l' | b's' | b'z')) {
+                    self.truncate(self.k - 1);
+                }
+            } else if 1 == self.count() && self.k > 0 && self.cvc(self.k - 1) {
+                self.replace("e");
+            }
+        }
+    }
+
+    // Replace a terminal 'y' with an 'i' when there is another vowel in the
+    // stem.
+    #[inline]
+    fn step1c(&mut self) {
+        if self.ends_with("y") && self.has_vowel() {
+            self.record(self.k - 1, "i");
+            self.buf.as_mut()[self.k - 1] = b'i';
+            self.reclassify();
+        }
+    }
+
+    // Convert double suffices into their single counterparts. For example the
+    // suffix 'ization' becomes 'ize' (also 'ation' becomes 'ate', etc). Note
+    // the string before the suffix must have a consonant-vowel-consonant
+    // greater than `0`, hence the use of `self.r`.
+    #[inline]
+    fn step2(&mut self) {
+        if let Some(to) = self.longest_suffix(&STEP2_TRIE, STEP2, |_, _| true)
+        {
+            self.r(to);
+        }
+    }
+
+    // Convert double suffices into single form. Similar to `step2`, convert
+    // 'ic', 'full', 'ness', etc.
+    #[inline]
+    fn step3(&mut self) {
+        if let Some(to) = self.longest_suffix(&STEP3_TRIE, STEP3, |_, _| true)
+        {
+            self.r(to);
+        }
+    }
+
+    // Remove 'ant', 'ence', etc when in the context '<c>vcvc<v>', where 'c' is
+    // a consonant and 'v' is a vowel, and '<.>' indicates arbitrary presence.
+    // The suffix 'ion' is only removed after an 's' or a 't'.
+    #[inline]
+    fn step4(&mut self) {
+        let found = self
+            .longest_suffix(&STEP4_TRIE, STEP4, |stem, suffix| {
+                suffix != "ion" || matches!(stem.last(), Some(b's' | b't'))
+            })
+            .is_some();
+
+        if found && self.count() > 1 {
+            self.truncate(self.j);
+        }
+    }
+
+    // Remove terminal 'e' if `count() > 1`; and map 'll' to 'l' if
+    // `count() > 1`.
+    #[inline]
+    fn step5(&mut self) {
+        self.j = self.k;
+        if Some(b'e') == self.back(1) {
+            let c = self.count();
+            if c > 1 || c == 1 && (self.k < 2 || !self.cvc(self.k - 2)) {
+                self.truncate(self.k - 1);
+            }
+        }
+
+        if Some(b'l') == self.back(1)
+            && self.double_consonant(self.k - 1)
+            && self.count() > 1
+        {
+            self.truncate(self.k - 1);
+        }
+    }
+
+    // Run each step of the algorithm over the word.
+    fn run(&mut self) {
+        self.step = "step1ab";
+        self.step1ab();
+        self.step = "step1c";
+        self.step1c();
+        self.step = "step2";
+        self.step2();
+        self.step = "step3";
+        self.step3();
+        self.step = "step4";
+        self.step4();
+        self.step = "step5";
+        self.step5();
+    }
+
+    // Return the resulting stem as a `&str`.
+    //
+    // The steps only remove or rewrite ASCII suffixes, so `k` always falls
+    // on a character boundary of the word.
+    fn as_str(&self) -> &str {
+        str::from_utf8(&self.buf.as_ref()[..self.k])
+            .expect("stem ends on a character boundary")
+    }
+}
+
+impl Porter {
+    /// Stem `word` with the Porter stemming algorithm and report the rules
+    /// that fired, in the order they were applied.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polystem::Porter;
+    ///
+    /// let (stem, rules) = Porter::stem_traced("ponies");
+    /// let rules: Vec<String> = rules.iter().map(|r| r.to_string()).collect();
+    ///
+    /// assert_eq!("poni", stem);
+    /// assert_eq!(vec!["step1ab: ies→i"], rules);
+    /// ```
+    pub fn stem_traced<W: AsRef<str>>(word: W) -> (String, Vec<Rule>) {
+        let word = word.as_ref();
+        if !is_short(word) {
+            let mut porter = Porter::new(word);
+            porter.trace = Some(Vec::new());
+            porter.run();
+
+            let trace = porter.trace.take().unwrap_or_default();
+            return (porter.into_stem(), trace);
+        }
+
+        (String::from(word), Vec::new())
+    }
+
+    /// Construct a `Porter` whose buffer can hold words of `capacity` bytes
+    /// without reallocating.
+    pub fn with_capacity(capacity: usize) -> Porter {
+        let mut porter = Porter::default();
+        porter.buf.reserve(capacity);
+        porter
+    }
+
+    /// Stem `word`, reusing the buffer of this instance across calls.
+    ///
+    /// This is the same as [`StemmerMut::stem`], for callers that do not
+    /// import the trait. The buffer only grows when a word is longer than
+    /// any word stemmed before.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polystem::Porter;
+    ///
+    /// let mut porter = Porter::with_capacity(32);
+    ///
+    /// for (word, stem) in [("rusted", "rust"), ("flies", "fli")].iter() {
+    ///     assert_eq!(*stem, porter.stem_reuse(word));
+    /// }
+    /// ```
+    pub fn stem_reuse<W: AsRef<str>>(&mut self, word: W) -> &str {
+        StemmerMut::stem(self, word)
+    }
+
+    // Return the resulting stem as a `String`, reusing the buffer.
+    fn into_stem(mut self) -> String {
+        self.buf.truncate(self.k);
+        String::from_utf8(self.buf).expect("stem ends on a character boundary")
+    }
+
+    // Load `word` into the existing buffer, ready for the stemming steps.
+    // Words of two letters or less are kept as is.
+    fn reset(&mut self, word: &str) {
+        self.buf.clear();
+        self.buf.extend_from_slice(word.as_bytes());
+        if !is_short(word) {
+            self.buf.make_ascii_lowercase();
+        }
+        self.k = word.len();
+        self.j = 0;
+        self.reclassify();
+    }
+}
+
+impl Default for Porter {
+    /// Construct a `Porter` for use with [`StemmerMut`].
+    fn default() -> Porter {
+        Porter::new("")
+    }
+}
+
+impl Stemmer for Porter {
+    const INFO: StemmerInfo = StemmerInfo {
+        name: Cow::Borrowed("porter"),
+        language: Language::English,
+        aggressiveness: Aggressiveness::Moderate,
+        algorithm_version: Porter::VERSION,
+    };
+
+    /// Porter stemming algorithm.
+    ///
+    /// This version was derived from the C version published at
+    /// [tartarus.org/martin/PorterStemmer][tartarus]
+    ///
+    /// >Porter, 1980, An algorithm for suffix stripping, Program, Vol. 14,
+    /// >No. 3, pp 130-137
+    ///
+    /// [tartarus]: https://tartarus.org/martin/PorterStemmer/
+    ///
+    /// Every call allocates the returned `String`. Use
+    /// [`with_stem`](Stemmer::with_stem) to stem without allocating.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polystem::{Stemmer, Porter};
+    ///
+    /// let term = String::from("rusted");
+    /// let stem = Porter::stem(term);
+    ///
+    /// assert_eq!("rust", stem);
+    /// ```
+    fn stem<W: AsRef<str>>(word: W) -> String {
+        let word = word.as_ref();
+        if Porter::is_unchanged(word) {
+            return String::from(word);
+        }
+        if word.len() <= INLINE_LEN {
+            let mut buf = [0; INLINE_LEN];
+            if let Ok(stem) = Porter::stem_to_buf(word, &mut buf) {
+                return stem.to_owned();
+            }
+        }
+
+        // Longer words are stemmed in their lowercased copy, which becomes
+        // the returned stem without another allocation or copy.
+        let mut porter = Porter::new(word);
+        porter.run();
+        porter.into_stem()
+    }
+
+    /// Short words, and lowercase words whose last letter ends none of the
+    /// suffixes of the algorithm, are left unchanged by the Porter stemmer.
+    fn is_unchanged(word: &str) -> bool {
+        if is_short(word) {
+            return true;
+        }
+
+        let bytes = word.as_bytes();
+        let last = bytes[bytes.len() - 1];
+        let ends_suffix = last.is_ascii_lowercase()
+            && FINAL_LETTERS >> (last - b'a') & 1 == 1;
+        !ends_suffix && !bytes.iter().any(u8::is_ascii_uppercase)
+    }
+
+    /// Porter stemming algorithm, reusing one buffer for every word.
+    fn stem_all<W: AsRef<str>>(words: &[W]) -> Vec<String> {
+        let mut porter = Porter::default();
+        words
+            .iter()
+            .map(|word| porter.stem_reuse(word).to_owned())
+            .collect()
+    }
+
+    /// Porter stemming algorithm, stemming the word in `buf`.
+    fn stem_to_buf<W: AsRef<str>>(
+        word: W,
+        buf: &mut [u8],
+    ) -> Result<&str, BufTooSmall> {
+        let word = word.as_ref();
+        if Porter::is_unchanged(word) {
+            return copy_to_buf(word, buf);
+        }
+
+        let buf = load_buf(word, buf)?;
+        buf.make_ascii_lowercase();
+        let mut porter = Porter {
+            buf: &mut *buf,
+            consonants: None,
+            k: word.len(),
+            j: 0,
+            step: "",
+            trace: None,
+        };
+        porter.run();
+        let k = porter.k;
+
+        Ok(str::from_utf8(&buf[..k])
+            .expect("stem ends on a character boundary"))
+    }
+
+    /// Porter stemming algorithm, stemming the ASCII word in `out`.
+    fn stem_bytes(word: &[u8], out: &mut Vec<u8>) {
+        out.clear();
+        out.extend_from_slice(word);
+        if word.len() <= 2 {
+            return;
+        }
+
+        out.make_ascii_lowercase();
+        let mut porter = Porter {
+            buf: &mut out[..],
+            consonants: None,
+            k: word.len(),
+            j: 0,
+            step: "",
+            trace: None,
+        };
+        porter.run();
+        let k = porter.k;
+        out.truncate(k);
+    }
+}
+
+impl StemmerMut for Porter {
+    /// Porter stemming algorithm, reusing the buffer of the `Porter`
+    /// instance.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polystem::{Porter, StemmerMut};
+    ///
+    /// let mut porter = Porter::default();
+    ///
+    /// assert_eq!("rust", porter.stem("rusted"));
+    /// assert_eq!("fli", porter.stem("flies"));
+    /// ```
+    fn stem<W: AsRef<str>>(&mut self, word: W) -> &str {
+        let word = word.as_ref();
+        self.reset(word);
+        if !is_short(word) {
+            self.run();
+        }
+
+        self.as_str()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixture_test::*;
+
+    #[test]
+    fn test_is_consonant() {
+        let mut p = Porter::new("y");
+        assert!(p.is_consonant(0));
+
+        let mut p = Porter::new("ey");
+        assert!(p.is_consonant(1));
+
+        let mut p = Porter::new("ly");
+        assert!(!p.is_consonant(1));
+
+        let mut p = Porter::new("aeiou");
+        for i in 0..p.k {
+            assert!(!p.is_consonant(i));
+        }
+        let mut p = Porter::new("bcdfghjklmnpqrstvwxz");
+        for i in 0..p.k {
+            assert!(p.is_consonant(i));
+        }
+
+        for word in Vocabulary::load("porter").words() {
+            let mask = consonants(word.as_bytes())
+                .take(MASK_LEN)
+                .enumerate()
+                .fold(0, |mask, (i, c)| mask | u64::from(c) << i);
+            assert_eq!(consonant_mask(word.as_bytes()), mask, "{}", word);
+        }
+
+        let mut p = Porter::new(&"y".repeat(2 * MASK_LEN));
+        for i in 0..p.k {
+            assert_eq!(p.is_consonant(i), i % 2 == 0);
+        }
+    }
+
+    #[test]
+    fn test_has_vowel() {
+        let mut p = Porter::new("follow");
+        p.j = 2;
+        assert!(p.has_vowel());
+
+        let mut p = Porter::new("fllw");
+        assert!(!p.has_vowel());
+
+        for (word, vowel) in [("y", false), ("yy", true), ("sky", true)] {
+            let mut p = Porter::new(word);
+            p.j = word.len();
+            assert_eq!(p.has_vowel(), vowel);
+        }
+
+        let word = "bcdfghjklmnpqrstvwxz".repeat(4);
+        let mut p = Porter::new(&format!("{}y", word));
+        p.j = word.len();
+        assert!(!p.has_vowel());
+        p.j += 1;
+        assert!(p.has_vowel());
+    }
+
+    #[test]
+    fn test_count() {
+        let mut p = Porter::new("be");
+        assert_eq!(p.count(), 0);
+
+        let mut p = Porter::new("beb");
+        p.j = 3;
+        assert_eq!(p.count(), 1);
+
+        let mut p = Porter::new("bebebe");
+        p.j = 6;
+        assert_eq!(p.count(), 2);
+
+        let mut p = Porter::new("bebebebe");
+        p.j = 8;
+        assert_eq!(p.count(), 3);
+
+        let mut p = Porter::new(&"eb".repeat(MASK_LEN));
+        for j in [MASK_LEN - 1, MASK_LEN, MASK_LEN + 1, 2 * MASK_LEN] {
+            p.j = j;
+            assert_eq!(p.count(), j / 2);
+        }
+    }
+
+    #[test]
+    fn test_double_consonant() {
+        let mut p = Porter::new("be");
+        assert!(!p.double_consonant(0));
+
+        let mut p = Porter::new("bbee");
+        assert!(p.double_consonant(1));
+
+        let mut p = Porter::new("bbee");
+        assert!(!p.double_consonant(2));
+
+        let mut p = Porter::new("bbee");
+        assert!(!p.double_consonant(3));
+
+        let mut p = Porter::new("bbee");
+        assert!(!p.double_consonant(4));
+    }
+
+    #[test]
+    fn test_cvc() {
+        let mut p = Porter::new("bab");
+        assert!(!p.cvc(0));
+
+        let mut p = Porter::new("bab");
+        assert!(!p.cvc(1));
+
+        let mut p = Porter::new("bab");
+        assert!(p.cvc(2));
+
+        let mut p = Porter::new("bab");
+        assert!(!p.cvc(3));
+
+        let mut p = Porter::new("cave");
+        assert!(p.cvc(2));
+
+        let mut p = Porter::new("lov");
+        assert!(p.cvc(2));
+
+        let mut p = Porter::new("hop");
+        assert!(p.cvc(2));
+
+        let mut p = Porter::new("crim");
+        assert!(p.cvc(3));
+
+        let mut p = Porter::new("snow");
+        assert!(!p.cvc(3));
+
+        let mut p = Porter::new("box");
+        assert!(!p.cvc(2));
+
+        let mut p = Porter::new("tray");
+        assert!(!p.cvc(3));
+    }
+
+    #[test]
+    fn test_ends_with() {
+        let mut p = Porter::new("session");
+        assert!(p.ends_with("ion"));
+
+        let mut p = Porter::new("session");
+        assert!(!p.ends_with("ions"));
+
+        let mut p = Porter::new("s");
+        assert!(p.ends_with("s"));
+    }
+
+    #[test]
+    fn test_replace() {
+        let mut p = Porter::new("session");
+        p.j = 4;
+        p.replace("bar");
+        assert_eq!(p.buf, b"sessbar");
+    }
+
+    #[test]
+    fn test_stem_traced() {
+        let (stem, rules) = Porter::stem_traced("generalizations");
+        let rules: Vec<String> = rules.iter().map(|r| r.to_string()).collect();
+
+        assert_eq!(stem, <Porter as Stemmer>::stem("generalizations"));
+        assert_eq!(
+            rules,
+            vec![
+                "step1ab: s→",
+                "step2: ization→ize",
+                "step3: alize→al",
+                "step4: al→",
+            ]
+        );
+
+        let (stem, rules) = Porter::stem_traced("hopping");
+        assert_eq!(stem, "hop");
+        assert_eq!(rules.len(), 2);
+
+        let (stem, rules) = Porter::stem_traced("a");
+        assert_eq!(stem, "a");
+        assert!(rules.is_empty());
+    }
+
+    #[test]
+    fn test_porter_stem() {
+        let vocabulary = Vocabulary::load("porter");
+        vocabulary.check(|word| <Porter as Stemmer>::stem(word), |_, _| false);
+    }
+
+    #[test]
+    fn test_porter_is_unchanged() {
+        let porter = Vocabulary::load("porter");
+        let words = porter.words();
+        let mut unchanged = 0;
+        for word in &words {
+            if Porter::is_unchanged(word) {
+                assert_eq!(<Porter as Stemmer>::stem(word), *word);
+                unchanged += 1;
+            }
+        }
+        assert!(unchanged > words.len() / 10);
+
+        assert!(Porter::is_unchanged("Is"));
+        assert!(Porter::is_unchanged("caf\u{e9}"));
+        assert!(!Porter::is_unchanged("HOP"));
+        assert!(!Porter::is_unchanged("hopping"));
+        assert!(matches!(Porter::stem_cow("hop"), Cow::Borrowed("hop")));
+        assert_eq!(Porter::stem_cow("hopping"), "hop");
+    }
+
+    #[test]
+    fn test_porter_stem_mut() {
+        let mut porter = Porter::default();
+
+        for (word, expected) in Vocabulary::load("porter").pairs() {
+            assert_eq!(StemmerMut::stem(&mut porter, word), expected);
+        }
+        assert_eq!(StemmerMut::stem(&mut porter, "Is"), "Is");
+    }
+
+    #[test]
+    fn test_porter_stem_reuse() {
+        let mut porter = Porter::with_capacity(32);
+        let capacity = porter.buf.capacity();
+        assert!(capacity >= 32);
+
+        for (word, expected) in Vocabulary::load("porter").pairs() {
+            assert_eq!(porter.stem_reuse(word), expected);
+        }
+        assert_eq!(porter.buf.capacity(), capacity);
+    }
+
+    // A change to the stems must be paired with a bump of `Porter::VERSION`.
+    #[test]
+    fn test_porter_version() {
+        let porter = Vocabulary::load("porter");
+        let hash =
+            fingerprint(&porter.words(), |w| <Porter as Stemmer>::stem(w));
+        assert_eq!((Porter::VERSION, hash), (3, 0xc1a2_2ccc_afb2_51c7));
+    }
+
+    // Every word of up to four characters drawn from the ASCII letters, `é`
+    // and an apostrophe stems without panicking, as the steps shrink `k`.
+    #[test]
+    fn test_porter_short_words() {
+        let letters: Vec<String> = (b'a'..=b'z')
+            .map(|c| (c as char).to_string())
+            .chain(vec!["é".to_owned(), "'".to_owned()])
+            .collect();
+        let mut words = vec![String::new()];
+        let mut porter = Porter::default();
+
+        for _ in 0..4 {
+            words = words
+                .iter()
+                .flat_map(|w| {
+                    letters.iter().map(move |c| format!("{}{}", w, c))
+                })
+                .collect();
+            for word in words.iter() {
+                let stem = <Porter as Stemmer>::stem(word);
+                assert_eq!(StemmerMut::stem(&mut porter, word), stem);
+            }
+        }
+        assert_eq!(<Porter as Stemmer>::stem("ies"), "i");
+        assert_eq!(<Porter as Stemmer>::stem("ion"), "ion");
+    }
+
+    #[test]
+    fn test_porter_stem_long_words() {
+        let mut porter = Porter::default();
+        let word = "generalizations".repeat(3);
+
+        for end in INLINE_LEN - 2..INLINE_LEN + 3 {
+            let word = &word[..end];
+            assert_eq!(
+                <Porter as Stemmer>::stem(word),
+                porter.stem_reuse(word)
+            );
+        }
+        assert_eq!(
+            <Porter as Stemmer>::stem("HONORIFICABILITUDINITATIBUS"),
+            "honorificabilitudinitatibu"
+        );
+    }
+
+    #[test]
+    fn test_porter_stem_all() {
+        let vocabulary = Vocabulary::load("porter");
+        let stems = Porter::stem_all(&vocabulary.words());
+        assert_eq!(stems, vocabulary.stems());
+    }
+
+    #[test]
+    fn test_porter_stem_to_buf() {
+        let mut buf = [0; 32];
+
+        for (word, expected) in Vocabulary::load("porter").pairs() {
+            assert_eq!(Porter::stem_to_buf(word, &mut buf), Ok(expected));
+        }
+        assert_eq!(Porter::stem_to_buf("Is", &mut buf), Ok("Is"));
+    }
+
+    #[test]
+    fn test_porter_stem_bytes() {
+        let mut out = Vec::new();
+
+        for (word, stem) in Vocabulary::load("porter").pairs() {
+            Porter::stem_bytes(word.as_bytes(), &mut out);
+            assert_eq!(out, stem.as_bytes());
+        }
+        Porter::stem_bytes(b"Is", &mut out);
+        assert_eq!(out, b"Is");
+        Porter::stem_bytes(b"\xff\xfeing", &mut out);
+        assert_eq!(out, b"\xff\xfeing");
+    }
+
+    #[test]
+    fn test_porter_stem_utf8() {
+        let words = [
+            "café",
+            "cafés",
+            "naïveties",
+            "crème",
+            "résumés",
+            "коты",
+            "\u{1041}\u{1041}ing",
+            "a\u{1041}\u{1041}ed",
+            "zoëll",
+        ];
+        let mut porter = Porter::default();
+
+        for word in words.iter() {
+            let stem = <Porter as Stemmer>::stem(word);
+            assert_eq!(StemmerMut::stem(&mut porter, word), stem);
+        }
+        assert_eq!(
+            <Porter as Stemmer>::stem("a\u{1041}\u{1041}ed"),
+            "a\u{1041}\u{1041}"
+        );
+    }
+}
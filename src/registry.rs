@@ -0,0 +1,135 @@
+// Copyright 2019 The Polystem authors.
+//
+// For the full copyright and license information, please view the LICENSE file
+// that was distributed with this source code.
+
+use std::sync::RwLock;
+
+use crate::{Algorithm, DynStemmer, ParseAlgorithmError};
+
+/// A function that constructs a registered stemmer, see
+/// [`register_stemmer`].
+pub type StemmerFactory = fn() -> Box<dyn DynStemmer + Send + Sync>;
+
+// The stemmers added by `register_stemmer`, in the order they were added.
+static REGISTRY: RwLock<Vec<(&str, StemmerFactory)>> = RwLock::new(Vec::new());
+
+fn registered() -> Vec<(&'static str, StemmerFactory)> {
+    REGISTRY.read().unwrap_or_else(|e| e.into_inner()).clone()
+}
+
+/// Add a stemmer from outside this crate under `name`, so that
+/// [`by_name`] and the programs built on it can construct it.
+///
+/// The stemmer must be `Send` and `Sync`, so that it can be shared between
+/// threads, as by a `Pipeline` built from a configuration file naming it.
+///
+/// Names are compared without regard to ASCII case. Return `false`, and
+/// leave the registry unchanged, if `name` is already the name of an
+/// [`Algorithm`] or of a registered stemmer.
+///
+/// # Examples
+///
+/// ```
+/// use polystem::{by_name, register_stemmer, DynStemmer, StemError};
+///
+/// struct Upper;
+///
+/// impl DynStemmer for Upper {
+///     fn stem_word(&self, word: &str) -> String {
+///         word.to_uppercase()
+///     }
+///
+///     fn try_stem_word(&self, word: &str) -> Result<String, StemError> {
+///         Ok(self.stem_word(word))
+///     }
+/// }
+///
+/// assert!(register_stemmer("upper", || Box::new(Upper)));
+/// assert!(!register_stemmer("Upper", || Box::new(Upper)));
+///
+/// let stemmer = by_name("upper").unwrap();
+/// assert_eq!("RUSTED", stemmer.stem_word("rusted"));
+/// ```
+pub fn register_stemmer(name: &'static str, factory: StemmerFactory) -> bool {
+    if name.parse::<Algorithm>().is_ok() {
+        return false;
+    }
+    let mut registry = REGISTRY.write().unwrap_or_else(|e| e.into_inner());
+    if registry.iter().any(|(n, _)| n.eq_ignore_ascii_case(name)) {
+        return false;
+    }
+    registry.push((name, factory));
+    true
+}
+
+/// Return the stemmer named `name`, either an [`Algorithm`] or a stemmer
+/// added with [`register_stemmer`].
+///
+/// # Examples
+///
+/// ```
+/// use polystem::by_name;
+///
+/// assert_eq!("rust", by_name("Porter").unwrap().stem_word("rusted"));
+/// assert!(by_name("klingon").is_err());
+/// ```
+pub fn by_name(
+    name: &str,
+) -> Result<Box<dyn DynStemmer + Send + Sync>, ParseAlgorithmError> {
+    if let Ok(algorithm) = name.parse::<Algorithm>() {
+        return Ok(Box::new(algorithm));
+    }
+    registered()
+        .into_iter()
+        .find(|(n, _)| n.eq_ignore_ascii_case(name))
+        .map(|(_, factory)| factory())
+        .ok_or_else(|| ParseAlgorithmError::new(name))
+}
+
+/// Return the names accepted by [`by_name`]: the algorithms compiled into
+/// the crate, followed by the registered stemmers in the order they were
+/// added.
+pub fn stemmer_names() -> Vec<&'static str> {
+    let algorithms = Algorithm::ALL.iter().map(|a| a.name());
+    algorithms
+        .chain(registered().into_iter().map(|(name, _)| name))
+        .collect()
+}
+
+#[cfg(all(test, feature = "english"))]
+mod tests {
+    use super::*;
+    use crate::StemError;
+
+    struct Reverse;
+
+    impl DynStemmer for Reverse {
+        fn stem_word(&self, word: &str) -> String {
+            word.chars().rev().collect()
+        }
+
+        fn try_stem_word(&self, word: &str) -> Result<String, StemError> {
+            Ok(self.stem_word(word))
+        }
+    }
+
+    #[test]
+    fn test_register_stemmer() {
+        assert!(!register_stemmer("PORTER", || Box::new(Reverse)));
+        assert!(register_stemmer("test-reverse", || Box::new(Reverse)));
+        assert!(!register_stemmer("Test-Reverse", || Box::new(Reverse)));
+
+        let stemmer = by_name("TEST-REVERSE").unwrap();
+        assert_eq!(stemmer.stem_word("flies"), "seilf");
+        assert_eq!(by_name("s").unwrap().stem_word("flies"), "fly");
+        assert_eq!(
+            by_name("reverse").err().unwrap().to_string(),
+            "unknown stemming algorithm `reverse`"
+        );
+
+        let names = stemmer_names();
+        assert_eq!(&names[..2], ["s", "porter"]);
+        assert!(names.contains(&"test-reverse"));
+    }
+}
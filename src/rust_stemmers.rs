@@ -0,0 +1,80 @@
+// Copyright 2019 The Polystem authors.
+//
+// For the full copyright and license information, please view the LICENSE file
+// that was distributed with this source code.
+
+//! The API of the `rust-stemmers` crate, `Stemmer::create` and `stem`, over
+//! the stemmers of polystem.
+//!
+//! The languages of `rust-stemmers` are not available. Its `English` is the
+//! Porter2 algorithm of Snowball, which polystem does not implement, and the
+//! original Porter algorithm gives different stems for many words. Code
+//! moving from `rust-stemmers` must therefore choose one of the algorithms
+//! below, and restem what it has indexed.
+//!
+//! # Examples
+//!
+//! ```
+//! use polystem::rust_stemmers::{Algorithm, Stemmer};
+//!
+//! let stemmer = Stemmer::create(Algorithm::Porter);
+//!
+//! assert_eq!("connect", stemmer.stem("connections"));
+//! ```
+
+use std::borrow::Cow;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The algorithms that a [`Stemmer`] can be created for.
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[non_exhaustive]
+pub enum Algorithm {
+    /// The original Porter algorithm, not the Porter2 of `rust-stemmers`.
+    Porter,
+    #[cfg(feature = "s")]
+    S,
+}
+
+/// A stemmer for one language, created with [`Stemmer::create`].
+#[derive(Debug, Clone, Copy)]
+pub struct Stemmer {
+    algorithm: crate::Algorithm,
+}
+
+impl Stemmer {
+    /// Create a stemmer for `lang`.
+    pub fn create(lang: Algorithm) -> Self {
+        let algorithm = match lang {
+            Algorithm::Porter => crate::Algorithm::Porter,
+            #[cfg(feature = "s")]
+            Algorithm::S => crate::Algorithm::S,
+        };
+        Stemmer { algorithm }
+    }
+
+    /// Stem `input`, borrowing it when it is its own stem.
+    ///
+    /// Unlike `rust-stemmers`, the input need not be lowercase.
+    pub fn stem<'a>(&self, input: &'a str) -> Cow<'a, str> {
+        self.algorithm.stem_cow(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stemmer() {
+        let stemmer = Stemmer::create(Algorithm::Porter);
+        assert_eq!(stemmer.stem("generalizations"), "gener");
+        assert_eq!(stemmer.stem("Rusted"), "rust");
+        assert!(matches!(stemmer.stem("hop"), Cow::Borrowed("hop")));
+
+        #[cfg(feature = "s")]
+        assert_eq!(Stemmer::create(Algorithm::S).stem("flies"), "fly");
+    }
+}
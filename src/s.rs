@@ -0,0 +1,176 @@
+// Copyright 2019 The Polystem authors.
+//
+// For the full copyright and license information, please view the LICENSE file
+// that was distributed with this source code.
+
+use std::borrow::Cow;
+use std::str;
+
+use crate::{
+    copy_to_buf, is_short, load_buf, Aggressiveness, BufTooSmall, Language,
+    Stemmer, StemmerInfo,
+};
+
+pub struct S;
+
+impl S {
+    /// The version of the rules of the s-stemmer.
+    pub const VERSION: u32 = 3;
+}
+
+// Return the length of the part of the lowercase `word` that is kept, and the
+// string appended to it to form the stem.
+fn rule(word: &[u8]) -> (usize, &'static str) {
+    if let Some(stem) = word.strip_suffix(b"ies") {
+        return (stem.len(), "y");
+    }
+    let stem = word
+        .strip_suffix(b"es")
+        .or_else(|| word.strip_suffix(b"s"))
+        .unwrap_or(word);
+    (stem.len(), "")
+}
+
+impl Stemmer for S {
+    const INFO: StemmerInfo = StemmerInfo {
+        name: Cow::Borrowed("s"),
+        language: Language::English,
+        aggressiveness: Aggressiveness::Light,
+        algorithm_version: S::VERSION,
+    };
+
+    /// A simple stemmer that strips `ies`, `es` and `s` from terms. Derived
+    /// from the s-stemmer in the [Atire](http://atire.org) search engine.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polystem::{Stemmer, S};
+    ///
+    /// let term = String::from("flies");
+    /// let stem = S::stem(term);
+    ///
+    /// assert_eq!("fly", stem);
+    /// ```
+    fn stem<W: AsRef<str>>(word: W) -> String {
+        let word = word.as_ref();
+        if S::is_unchanged(word) {
+            return String::from(word);
+        }
+
+        let mut stem = word.to_ascii_lowercase();
+        let (len, end) = rule(stem.as_bytes());
+        // The new ending is never longer than the suffix it replaces, so the
+        // stem is built in the lowercased word without reallocating.
+        stem.truncate(len);
+        stem.push_str(end);
+
+        stem
+    }
+
+    /// Short words, and lowercase words that do not end in 's', are left
+    /// unchanged by the s-stemmer.
+    fn is_unchanged(word: &str) -> bool {
+        is_short(word)
+            || !(word.ends_with('s')
+                || word.bytes().any(|b| b.is_ascii_uppercase()))
+    }
+
+    /// The s-stemmer, stemming the word in `buf`.
+    fn stem_to_buf<W: AsRef<str>>(
+        word: W,
+        buf: &mut [u8],
+    ) -> Result<&str, BufTooSmall> {
+        let word = word.as_ref();
+        if S::is_unchanged(word) {
+            return copy_to_buf(word, buf);
+        }
+
+        let buf = load_buf(word, buf)?;
+        buf.make_ascii_lowercase();
+        let (len, end) = rule(buf);
+        buf[len..len + end.len()].copy_from_slice(end.as_bytes());
+
+        Ok(str::from_utf8(&buf[..len + end.len()])
+            .expect("stem ends on a character boundary"))
+    }
+
+    /// The s-stemmer, stemming the ASCII word in `out`.
+    fn stem_bytes(word: &[u8], out: &mut Vec<u8>) {
+        out.clear();
+        out.extend_from_slice(word);
+        if word.len() <= 2 {
+            return;
+        }
+
+        out.make_ascii_lowercase();
+        let (len, end) = rule(out);
+        out.truncate(len);
+        out.extend_from_slice(end.as_bytes());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixture_test::*;
+
+    #[test]
+    fn test_s_stem() {
+        Vocabulary::load("s").check(|word| S::stem(word), |_, _| false);
+    }
+
+    #[test]
+    fn test_s_is_unchanged() {
+        assert!(S::is_unchanged("fly"));
+        assert!(S::is_unchanged("IS"));
+        assert!(!S::is_unchanged("Fly"));
+        assert!(!S::is_unchanged("suns"));
+        assert!(matches!(S::stem_cow("fly"), Cow::Borrowed("fly")));
+        assert_eq!(S::stem_cow("Fly"), "fly");
+    }
+
+    #[test]
+    fn test_s_stem_utf8() {
+        assert_eq!(S::stem("cafés"), "café");
+        assert_eq!(S::stem("cafe\u{301}s"), "cafe\u{301}");
+        assert_eq!(S::stem("ÉTÉS"), "ÉtÉ");
+    }
+
+    #[test]
+    fn test_s_stem_to_buf() {
+        let mut buf = [0; 32];
+
+        for (word, stem) in Vocabulary::load("s").pairs() {
+            assert_eq!(S::stem_to_buf(word, &mut buf), Ok(stem));
+        }
+        assert_eq!(S::stem_to_buf("ies", &mut buf[..3]), Ok("y"));
+        assert_eq!(S::stem_to_buf("Is", &mut buf[..2]), Ok("Is"));
+        assert_eq!(
+            S::stem_to_buf("Is", &mut buf[..1]),
+            Err(BufTooSmall::new(2))
+        );
+    }
+
+    #[test]
+    fn test_s_stem_bytes() {
+        let mut out = Vec::new();
+
+        for (word, stem) in Vocabulary::load("s").pairs() {
+            S::stem_bytes(word.as_bytes(), &mut out);
+            assert_eq!(out, stem.as_bytes());
+        }
+        S::stem_bytes(b"Is", &mut out);
+        assert_eq!(out, b"Is");
+        S::stem_bytes(b"FLIES", &mut out);
+        assert_eq!(out, b"fly");
+    }
+
+    // A change to the stems must be paired with a bump of `S::VERSION`.
+    #[test]
+    fn test_s_version() {
+        let s = Vocabulary::load("s");
+        let hash = fingerprint(&s.words(), |w| S::stem(w));
+        assert_eq!((S::VERSION, hash), (3, 0xe174_f747_3947_9b39));
+    }
+}
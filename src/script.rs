@@ -0,0 +1,137 @@
+// Copyright 2019 The Polystem authors.
+//
+// For the full copyright and license information, please view the LICENSE file
+// that was distributed with this source code.
+
+/// The writing system of a letter.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Script {
+    Latin,
+    Greek,
+    Cyrillic,
+    Armenian,
+    Hebrew,
+    Arabic,
+    Devanagari,
+    Thai,
+    Hangul,
+    /// The Japanese Hiragana and Katakana syllabaries.
+    Kana,
+    /// Chinese characters, also used in Japanese and Korean text.
+    Han,
+    /// A letter of any other script.
+    Other,
+}
+
+impl Script {
+    /// Return the script of `c`, or `None` when `c` is not a letter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polystem::Script;
+    ///
+    /// assert_eq!(Some(Script::Latin), Script::of('é'));
+    /// assert_eq!(Some(Script::Cyrillic), Script::of('я'));
+    /// assert_eq!(None, Script::of('7'));
+    /// ```
+    pub fn of(c: char) -> Option<Script> {
+        if !c.is_alphabetic() {
+            return None;
+        }
+
+        let script = match c {
+            '\u{0000}'..='\u{024f}'
+            | '\u{1e00}'..='\u{1eff}'
+            | '\u{2c60}'..='\u{2c7f}'
+            | '\u{a720}'..='\u{a7ff}'
+            | '\u{ff21}'..='\u{ff3a}'
+            | '\u{ff41}'..='\u{ff5a}' => Script::Latin,
+            '\u{0370}'..='\u{03ff}' | '\u{1f00}'..='\u{1fff}' => Script::Greek,
+            '\u{0400}'..='\u{052f}' | '\u{2de0}'..='\u{2dff}' => {
+                Script::Cyrillic
+            }
+            '\u{0530}'..='\u{058f}' => Script::Armenian,
+            '\u{0590}'..='\u{05ff}' => Script::Hebrew,
+            '\u{0600}'..='\u{06ff}' | '\u{0750}'..='\u{077f}' => {
+                Script::Arabic
+            }
+            '\u{0900}'..='\u{097f}' => Script::Devanagari,
+            '\u{0e00}'..='\u{0e7f}' => Script::Thai,
+            '\u{1100}'..='\u{11ff}' | '\u{ac00}'..='\u{d7af}' => {
+                Script::Hangul
+            }
+            '\u{3040}'..='\u{30ff}' | '\u{ff66}'..='\u{ff9f}' => Script::Kana,
+            '\u{3400}'..='\u{4dbf}'
+            | '\u{4e00}'..='\u{9fff}'
+            | '\u{f900}'..='\u{faff}'
+            | '\u{20000}'..='\u{2fa1f}' => Script::Han,
+            _ => Script::Other,
+        };
+        Some(script)
+    }
+}
+
+/// Return `true` if `word` has letters from scripts that are not written
+/// together, such as a Latin word run into a Cyrillic one by a tokenizer.
+///
+/// Digits and punctuation belong to no script, and Han characters may be
+/// mixed with Kana or Hangul.
+///
+/// # Examples
+///
+/// ```
+/// use polystem::is_mixed_script;
+///
+/// assert!(is_mixed_script("Москваcity"));
+/// assert!(!is_mixed_script("naïve"));
+/// assert!(!is_mixed_script("東京タワー"));
+/// ```
+pub fn is_mixed_script(word: &str) -> bool {
+    let mut han = false;
+    let mut seen = None;
+    for script in word.chars().filter_map(Script::of) {
+        match (script, seen) {
+            (Script::Han, _) => han = true,
+            (_, None) => seen = Some(script),
+            (_, Some(s)) if s != script => return true,
+            _ => (),
+        }
+    }
+
+    match seen {
+        Some(Script::Kana) | Some(Script::Hangul) | None => false,
+        Some(_) => han,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_script_of() {
+        assert_eq!(Script::of('a'), Some(Script::Latin));
+        assert_eq!(Script::of('ω'), Some(Script::Greek));
+        assert_eq!(Script::of('ア'), Some(Script::Kana));
+        assert_eq!(Script::of('字'), Some(Script::Han));
+        assert_eq!(Script::of('한'), Some(Script::Hangul));
+        assert_eq!(Script::of('ᚠ'), Some(Script::Other));
+        assert_eq!(Script::of('、'), None);
+        assert_eq!(Script::of('\''), None);
+    }
+
+    #[test]
+    fn test_is_mixed_script() {
+        assert!(is_mixed_script("covid19、пациенты"));
+        assert!(is_mixed_script("αβγabc"));
+        assert!(is_mixed_script("漢字abc"));
+        assert!(!is_mixed_script("covid19、patients"));
+        assert!(!is_mixed_script("漢字かな"));
+        assert!(!is_mixed_script("韓國한국"));
+        assert!(!is_mixed_script("漢字"));
+        assert!(!is_mixed_script("2024"));
+        assert!(!is_mixed_script(""));
+    }
+}
@@ -0,0 +1,149 @@
+// Copyright 2019 The Polystem authors.
+//
+// For the full copyright and license information, please view the LICENSE file
+// that was distributed with this source code.
+
+//! An HTTP service that stems words, for sharing one stemmer between
+//! programs written in other languages.
+//!
+//! The service has two endpoints, which name the stemmer as accepted by
+//! [`by_name`], defaulting to the English algorithm:
+//!
+//! * `GET /stem?alg=porter&word=rusted` answers `{"stem":"rust"}`.
+//! * `POST /stem` with `{"alg":"porter","words":["ponies","rusted"]}`
+//!   answers `{"stems":["poni","rust"]}`.
+//!
+//! An unknown stemmer is answered with `400 Bad Request` and the error
+//! message as text.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! # async fn run() -> std::io::Result<()> {
+//! let listener = tokio::net::TcpListener::bind("127.0.0.1:8080").await?;
+//! polystem::serve::serve(listener).await
+//! # }
+//! ```
+
+use std::io;
+
+use axum::extract::{Json, Query};
+use axum::http::StatusCode;
+use axum::routing::get;
+use axum::Router;
+use serde::{Deserialize, Serialize};
+use tokio::net::TcpListener;
+
+use crate::{by_name, DynStemmer, Language};
+
+#[derive(Deserialize)]
+struct StemQuery {
+    alg: Option<String>,
+    word: String,
+}
+
+#[derive(Serialize)]
+struct StemResponse {
+    stem: String,
+}
+
+#[derive(Deserialize)]
+struct BatchRequest {
+    alg: Option<String>,
+    words: Vec<String>,
+}
+
+#[derive(Serialize)]
+struct BatchResponse {
+    stems: Vec<String>,
+}
+
+type Rejection = (StatusCode, String);
+
+/// Return the routes of the service, to serve on their own with [`serve`]
+/// or to nest in a larger application.
+pub fn router() -> Router {
+    Router::new().route("/stem", get(stem).post(stem_batch))
+}
+
+/// Serve the routes of [`router`] on `listener` until the process ends.
+pub async fn serve(listener: TcpListener) -> io::Result<()> {
+    axum::serve(listener, router()).await
+}
+
+// Return the stemmer named `alg`, or the English one.
+fn stemmer(
+    alg: Option<&str>,
+) -> Result<Box<dyn DynStemmer + Send + Sync>, Rejection> {
+    let name = alg.unwrap_or_else(|| Language::English.algorithm().name());
+    by_name(name).map_err(|e| (StatusCode::BAD_REQUEST, e.to_string()))
+}
+
+async fn stem(
+    Query(query): Query<StemQuery>,
+) -> Result<Json<StemResponse>, Rejection> {
+    let stemmer = stemmer(query.alg.as_deref())?;
+    let stem = stemmer.stem_word(&query.word);
+    Ok(Json(StemResponse { stem }))
+}
+
+async fn stem_batch(
+    Json(request): Json<BatchRequest>,
+) -> Result<Json<BatchResponse>, Rejection> {
+    let stemmer = stemmer(request.alg.as_deref())?;
+    let stems = request.words.iter().map(|w| stemmer.stem_word(w)).collect();
+    Ok(Json(BatchResponse { stems }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::{to_bytes, Body};
+    use axum::http::Request;
+    use tower::ServiceExt;
+
+    async fn send(request: Request<Body>) -> (StatusCode, String) {
+        let response = router().oneshot(request).await.unwrap();
+        let status = response.status();
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        (status, String::from_utf8(body.to_vec()).unwrap())
+    }
+
+    fn get(uri: &str) -> Request<Body> {
+        Request::get(uri).body(Body::empty()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_stem() {
+        assert_eq!(
+            send(get("/stem?alg=s&word=flies")).await,
+            (StatusCode::OK, r#"{"stem":"fly"}"#.to_owned())
+        );
+        assert_eq!(
+            send(get("/stem?word=connections")).await,
+            (StatusCode::OK, r#"{"stem":"connect"}"#.to_owned())
+        );
+        assert_eq!(
+            send(get("/stem?alg=porter2&word=flies")).await,
+            (
+                StatusCode::BAD_REQUEST,
+                "unknown stemming algorithm `porter2`".to_owned()
+            )
+        );
+        assert_eq!(send(get("/stem")).await.0, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_stem_batch() {
+        let request = Request::post("/stem")
+            .header("content-type", "application/json")
+            .body(Body::from(
+                r#"{"alg":"porter","words":["ponies","rusted"]}"#,
+            ))
+            .unwrap();
+        assert_eq!(
+            send(request).await,
+            (StatusCode::OK, r#"{"stems":["poni","rust"]}"#.to_owned())
+        );
+    }
+}
@@ -0,0 +1,418 @@
+// Copyright 2019 The Polystem authors.
+//
+// For the full copyright and license information, please view the LICENSE file
+// that was distributed with this source code.
+
+use std::fmt;
+
+use crate::{is_short, DynStemmer, ParseAlgorithmError, StemError};
+
+// The entry point of a translated program, which stems the word of `env`.
+type Entry = fn(&mut Env) -> bool;
+
+// The stemmers translated from `snowball/<name>.sbl` by the build script, as
+// `ALGORITHMS`, a list of names and entry points.
+include!(concat!(env!("OUT_DIR"), "/snowball.rs"));
+
+/// A stemmer translated at build time from a
+/// [Snowball](https://snowballstem.org) program in the `snowball` directory.
+///
+/// Each `snowball/<name>.sbl` file becomes the stemmer named `<name>`, so a
+/// Snowball language is added by adding its program rather than by porting
+/// it by hand. Words are lowercased before stemming, as Snowball programs
+/// expect, except for words of at most two characters, which are returned
+/// unchanged like those of the other stemmers.
+///
+/// # Examples
+///
+/// ```
+/// use polystem::{DynStemmer, Snowball};
+///
+/// let porter = Snowball::new("porter").unwrap();
+/// assert_eq!("connect", porter.stem_word("Connections"));
+/// assert!(Snowball::new("klingon").is_err());
+/// ```
+#[derive(Clone, Copy)]
+pub struct Snowball {
+    name: &'static str,
+    stem: Entry,
+}
+
+impl Snowball {
+    /// Return the stemmer translated from `snowball/<name>.sbl`.
+    pub fn new(name: &str) -> Result<Snowball, ParseAlgorithmError> {
+        ALGORITHMS
+            .iter()
+            .find(|(n, _)| n.eq_ignore_ascii_case(name))
+            .map(|&(name, stem)| Snowball { name, stem })
+            .ok_or_else(|| ParseAlgorithmError::new(name))
+    }
+
+    /// Return the names of the translated Snowball programs.
+    pub fn names() -> impl Iterator<Item = &'static str> {
+        ALGORITHMS.iter().map(|&(name, _)| name)
+    }
+
+    /// Return the name of the Snowball program.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+}
+
+impl fmt::Debug for Snowball {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("Snowball").field(&self.name).finish()
+    }
+}
+
+impl DynStemmer for Snowball {
+    fn stem_word(&self, word: &str) -> String {
+        if is_short(word) {
+            return word.to_owned();
+        }
+        let mut env = Env::new(&word.to_lowercase());
+        (self.stem)(&mut env);
+        env.into_string()
+    }
+
+    fn try_stem_word(&self, word: &str) -> Result<String, StemError> {
+        if word.is_empty() {
+            return Err(StemError::Empty);
+        }
+        Ok(self.stem_word(word))
+    }
+}
+
+// An entry of an `among` table: a string, the number of the command to run
+// when it matches, and an optional routine that must also succeed.
+struct Among<C: 'static> {
+    s: &'static [u8],
+    result: i32,
+    cond: Option<fn(&mut Env, &mut C) -> bool>,
+}
+
+// The state of a Snowball program, after the C runtime of Snowball. The
+// cursor `c` moves between the limits `lb` and `l`, and `bra` and `ket`
+// delimit the slice. Positions are byte offsets that always fall between
+// characters. Not every program uses every command of the runtime.
+#[allow(dead_code)]
+struct Env {
+    current: Vec<u8>,
+    c: usize,
+    l: usize,
+    lb: usize,
+    bra: usize,
+    ket: usize,
+}
+
+#[allow(dead_code)]
+impl Env {
+    fn new(word: &str) -> Env {
+        Env {
+            current: word.as_bytes().to_vec(),
+            c: 0,
+            l: word.len(),
+            lb: 0,
+            bra: 0,
+            ket: word.len(),
+        }
+    }
+
+    fn into_string(self) -> String {
+        match String::from_utf8(self.current) {
+            Ok(s) => s,
+            Err(e) => String::from_utf8_lossy(e.as_bytes()).into_owned(),
+        }
+    }
+
+    // Return the number of characters of the string.
+    fn len(&self) -> i32 {
+        self.current.iter().filter(|&&b| b & 0xc0 != 0x80).count() as i32
+    }
+
+    // Return the position after the character at `c`, if it ends before
+    // the limit.
+    fn next_pos(&self, c: usize) -> Option<usize> {
+        if c >= self.l {
+            return None;
+        }
+        let mut c = c + 1;
+        while c < self.l && self.current[c] & 0xc0 == 0x80 {
+            c += 1;
+        }
+        Some(c)
+    }
+
+    // Return the position of the character before `c`, if it starts after
+    // the backward limit.
+    fn prev_pos(&self, c: usize) -> Option<usize> {
+        if c <= self.lb {
+            return None;
+        }
+        let mut c = c - 1;
+        while c > self.lb && self.current[c] & 0xc0 == 0x80 {
+            c -= 1;
+        }
+        Some(c)
+    }
+
+    fn char_between(&self, start: usize, end: usize) -> Option<char> {
+        std::str::from_utf8(&self.current[start..end])
+            .ok()
+            .and_then(|s| s.chars().next())
+    }
+
+    fn next(&mut self) -> bool {
+        self.hop(1)
+    }
+
+    fn next_b(&mut self) -> bool {
+        self.hop_b(1)
+    }
+
+    fn hop(&mut self, n: i32) -> bool {
+        let mut c = self.c;
+        for _ in 0..n.max(0) {
+            match self.next_pos(c) {
+                Some(next) => c = next,
+                None => return false,
+            }
+        }
+        self.c = c;
+        n >= 0
+    }
+
+    fn hop_b(&mut self, n: i32) -> bool {
+        let mut c = self.c;
+        for _ in 0..n.max(0) {
+            match self.prev_pos(c) {
+                Some(prev) => c = prev,
+                None => return false,
+            }
+        }
+        self.c = c;
+        n >= 0
+    }
+
+    fn eq_s(&mut self, s: &[u8]) -> bool {
+        if self.l - self.c < s.len() || !self.current[self.c..].starts_with(s)
+        {
+            return false;
+        }
+        self.c += s.len();
+        true
+    }
+
+    fn eq_s_b(&mut self, s: &[u8]) -> bool {
+        if self.c - self.lb < s.len() || !self.current[..self.c].ends_with(s) {
+            return false;
+        }
+        self.c -= s.len();
+        true
+    }
+
+    // Move past the next character if its membership of `grouping` is
+    // `member`.
+    fn grouping(&mut self, grouping: &[char], member: bool) -> bool {
+        let next = match self.next_pos(self.c) {
+            Some(next) => next,
+            None => return false,
+        };
+        match self.char_between(self.c, next) {
+            Some(ch) if grouping.binary_search(&ch).is_ok() == member => {
+                self.c = next;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn grouping_b(&mut self, grouping: &[char], member: bool) -> bool {
+        let prev = match self.prev_pos(self.c) {
+            Some(prev) => prev,
+            None => return false,
+        };
+        match self.char_between(prev, self.c) {
+            Some(ch) if grouping.binary_search(&ch).is_ok() == member => {
+                self.c = prev;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn in_grouping(&mut self, grouping: &[char]) -> bool {
+        self.grouping(grouping, true)
+    }
+
+    fn out_grouping(&mut self, grouping: &[char]) -> bool {
+        self.grouping(grouping, false)
+    }
+
+    fn in_grouping_b(&mut self, grouping: &[char]) -> bool {
+        self.grouping_b(grouping, true)
+    }
+
+    fn out_grouping_b(&mut self, grouping: &[char]) -> bool {
+        self.grouping_b(grouping, false)
+    }
+
+    // Match the longest string of `amongs` at the cursor whose routine, if
+    // any, succeeds, and return its result, or 0 if there is none. The
+    // strings are sorted longest first.
+    fn find_among<C>(&mut self, amongs: &[Among<C>], ctx: &mut C) -> i32 {
+        let c = self.c;
+        for among in amongs {
+            let end = c + among.s.len();
+            if end > self.l || !self.current[c..].starts_with(among.s) {
+                continue;
+            }
+            self.c = end;
+            let found = among.cond.is_none_or(|cond| cond(self, ctx));
+            self.c = end;
+            if found {
+                return among.result;
+            }
+        }
+        self.c = c;
+        0
+    }
+
+    fn find_among_b<C>(&mut self, amongs: &[Among<C>], ctx: &mut C) -> i32 {
+        let c = self.c;
+        for among in amongs {
+            if c - self.lb < among.s.len()
+                || !self.current[..c].ends_with(among.s)
+            {
+                continue;
+            }
+            let start = c - among.s.len();
+            self.c = start;
+            let found = among.cond.is_none_or(|cond| cond(self, ctx));
+            self.c = start;
+            if found {
+                return among.result;
+            }
+        }
+        self.c = c;
+        0
+    }
+
+    // Replace `current[bra..ket]` with `s`, and move the limit and cursor
+    // to follow the text after it. Return the change in length.
+    fn replace(&mut self, bra: usize, ket: usize, s: &[u8]) -> isize {
+        let adjustment = s.len() as isize - (ket - bra) as isize;
+        self.current.splice(bra..ket, s.iter().copied());
+        self.l = (self.l as isize + adjustment) as usize;
+        if self.c >= ket {
+            self.c = (self.c as isize + adjustment) as usize;
+        } else if self.c > bra {
+            self.c = bra;
+        }
+        adjustment
+    }
+
+    fn slice_ok(&self) -> bool {
+        self.bra <= self.ket
+            && self.ket <= self.l
+            && self.l <= self.current.len()
+    }
+
+    fn slice_from(&mut self, s: &[u8]) -> bool {
+        if !self.slice_ok() {
+            return false;
+        }
+        self.replace(self.bra, self.ket, s);
+        true
+    }
+
+    // Insert `s` at the cursor, leaving the cursor after it.
+    fn attach(&mut self, s: &[u8]) -> bool {
+        let c = self.c;
+        let adjustment = self.replace(c, c, s);
+        if c <= self.bra {
+            self.bra = (self.bra as isize + adjustment) as usize;
+        }
+        if c <= self.ket {
+            self.ket = (self.ket as isize + adjustment) as usize;
+        }
+        true
+    }
+
+    // Insert `s` at the cursor, leaving the cursor before it.
+    fn insert(&mut self, s: &[u8]) -> bool {
+        let c = self.c;
+        self.attach(s);
+        self.c = c;
+        true
+    }
+
+    fn slice_to(&self) -> Option<String> {
+        if !self.slice_ok() {
+            return None;
+        }
+        let slice = &self.current[self.bra..self.ket];
+        Some(String::from_utf8_lossy(slice).into_owned())
+    }
+
+    fn assign_to(&self) -> String {
+        String::from_utf8_lossy(&self.current[..self.l]).into_owned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixture_test::*;
+
+    #[test]
+    fn test_names() {
+        assert!(Snowball::names().any(|name| name == "porter"));
+        assert_eq!(Snowball::new("Porter").unwrap().name(), "porter");
+        assert_eq!(
+            Snowball::new("porter3").unwrap_err().to_string(),
+            "unknown stemming algorithm `porter3`"
+        );
+    }
+
+    // The Snowball program follows the published algorithm, so it differs
+    // from the fixture, made with Martin Porter's C implementation, where
+    // that departs from the paper: the -bli and -logi rules.
+    #[test]
+    fn test_snowball_porter() {
+        let porter = Snowball::new("porter").unwrap();
+        for (word, expected) in Vocabulary::load("porter").pairs() {
+            let stem = porter.stem_word(word);
+            if !stem.ends_with("bli") && !stem.ends_with("logi") {
+                assert_eq!(stem, expected, "{}", word);
+            }
+        }
+        assert_eq!(porter.stem_word("apology"), "apologi");
+        assert_eq!(porter.stem_word("is"), "is");
+        assert_eq!(porter.stem_word("IS"), "IS");
+        assert_eq!(porter.try_stem_word(""), Err(StemError::Empty));
+    }
+
+    #[test]
+    fn test_env() {
+        let mut env = Env::new("café");
+        assert!(env.hop(3));
+        assert!(env.next());
+        assert_eq!(env.c, 5);
+        assert!(!env.next());
+        assert!(env.eq_s_b("é".as_bytes()));
+        assert!(env.out_grouping_b(&['a', 'e']));
+        assert!(env.in_grouping_b(&['a', 'e']));
+        assert_eq!(env.c, 1);
+
+        env.bra = 1;
+        env.ket = 2;
+        assert!(env.slice_from(b"ou"));
+        assert_eq!(env.l, 6);
+        assert!(env.insert(b"s"));
+        assert_eq!(env.c, 1);
+        assert_eq!(env.len(), 6);
+        assert_eq!(env.slice_to().unwrap(), "o");
+        assert_eq!(env.into_string(), "csoufé");
+    }
+}
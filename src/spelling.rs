@@ -0,0 +1,242 @@
+// Copyright 2019 The Polystem authors.
+//
+// For the full copyright and license information, please view the LICENSE file
+// that was distributed with this source code.
+
+use std::borrow::Cow;
+
+// British spellings and their American counterparts, replaced wherever a word
+// starts with them so that inflections such as "colours" and "colourful" are
+// covered too.
+const PREFIXES: &[(&str, &str)] = &[
+    ("aluminium", "aluminum"),
+    ("arbour", "arbor"),
+    ("ardour", "ardor"),
+    ("armour", "armor"),
+    ("behaviour", "behavior"),
+    ("clamour", "clamor"),
+    ("colour", "color"),
+    ("defence", "defense"),
+    ("demeanour", "demeanor"),
+    ("endeavour", "endeavor"),
+    ("favour", "favor"),
+    ("fervour", "fervor"),
+    ("flavour", "flavor"),
+    ("harbour", "harbor"),
+    ("honour", "honor"),
+    ("humour", "humor"),
+    ("labour", "labor"),
+    ("licence", "license"),
+    ("neighbour", "neighbor"),
+    ("odour", "odor"),
+    ("offence", "offense"),
+    ("parlour", "parlor"),
+    ("plough", "plow"),
+    ("pretence", "pretense"),
+    ("rancour", "rancor"),
+    ("rigour", "rigor"),
+    ("rumour", "rumor"),
+    ("saviour", "savior"),
+    ("savour", "savor"),
+    ("splendour", "splendor"),
+    ("tumour", "tumor"),
+    ("valour", "valor"),
+    ("vapour", "vapor"),
+    ("vigour", "vigor"),
+];
+
+// Words whose British spelling only differs in some of their forms.
+const WORDS: &[(&str, &str)] = &[
+    ("cheque", "check"),
+    ("cheques", "checks"),
+    ("grey", "gray"),
+    ("greyer", "grayer"),
+    ("greyest", "grayest"),
+    ("greyish", "grayish"),
+    ("greys", "grays"),
+    ("jewellery", "jewelry"),
+    ("programme", "program"),
+    ("programmes", "programs"),
+    ("tyre", "tire"),
+    ("tyres", "tires"),
+];
+
+// Stems spelled with "-re" in British English and "-er" in American English,
+// as in "centre" and "center".
+const RE_STEMS: &[&str] = &[
+    "calib", "cent", "fib", "lit", "lust", "meag", "met", "sab", "somb",
+    "spect", "theat",
+];
+
+// The endings of the forms of the "-re" stems and their American spelling.
+const RE_ENDINGS: &[(&str, &str)] = &[
+    ("re", "er"),
+    ("res", "ers"),
+    ("red", "ered"),
+    ("ring", "ering"),
+];
+
+// Stems spelled with "-ogue" in British English and "-og" in American
+// English, as in "catalogue" and "catalog".
+const OGUE_STEMS: &[&str] =
+    &["anal", "catal", "dial", "epil", "monol", "prol"];
+
+// The endings of the forms of the "-ogue" stems and their American spelling.
+const OGUE_ENDINGS: &[(&str, &str)] = &[
+    ("ogue", "og"),
+    ("ogues", "ogs"),
+    ("ogued", "oged"),
+    ("oguing", "oging"),
+];
+
+// The endings of verbs in "-ise" and their derived nouns.
+const ISE_ENDINGS: &[&str] = &[
+    "isation", "isations", "ise", "ised", "iser", "isers", "ises", "ising",
+];
+
+// Words ending in "-ise" that are spelled the same in American English, such
+// as "otherwise", "surprise" and "exercise".
+const ISE_EXCEPTIONS: &[&str] = &[
+    "adise", "aise", "andise", "chemise", "chise", "cise", "demise",
+    "moonrise", "oise", "premise", "prise", "promise", "rtise", "spise",
+    "stise", "sunrise", "surmise", "treatise", "uise", "uprise", "valise",
+    "vise", "wise",
+];
+
+/// Rewrite the British spelling of the lowercase `word` to the American one,
+/// so that both spellings are conflated by the stemmers.
+///
+/// Common "-our", "-re", "-ogue" and "-ence" spellings are rewritten, as is
+/// the "-ise" ending of verbs and their "-isation" nouns.
+///
+/// # Examples
+///
+/// ```
+/// use polystem::americanize;
+///
+/// assert_eq!("colors", americanize("colours"));
+/// assert_eq!("normalize", americanize("normalise"));
+/// assert_eq!("globalization", americanize("globalisation"));
+/// assert_eq!("surprise", americanize("surprise"));
+/// ```
+pub fn americanize(word: &str) -> Cow<'_, str> {
+    if let Some((_, us)) = WORDS.iter().find(|(uk, _)| *uk == word) {
+        return Cow::Borrowed(us);
+    }
+    for (uk, us) in PREFIXES {
+        if let Some(rest) = word.strip_prefix(uk) {
+            return Cow::Owned(format!("{}{}", us, rest));
+        }
+    }
+    if let Some(word) = replace_ending(word, RE_STEMS, RE_ENDINGS) {
+        return Cow::Owned(word);
+    }
+    if let Some(word) = replace_ending(word, OGUE_STEMS, OGUE_ENDINGS) {
+        return Cow::Owned(word);
+    }
+
+    for ending in ISE_ENDINGS {
+        let stem = match word.strip_suffix(ending) {
+            Some(stem) => stem,
+            None => continue,
+        };
+        // Short words such as "rise" and "crises" are not verbs in "-ise".
+        let base = format!("{}ise", stem);
+        if base.len() < 6 || ISE_EXCEPTIONS.iter().any(|e| base.ends_with(e)) {
+            return Cow::Borrowed(word);
+        }
+        return Cow::Owned(format!("{}iz{}", stem, &ending[2..]));
+    }
+
+    for ending in &["yse", "ysed", "yser", "ysers", "yses", "ysing"] {
+        if let Some(stem) = word.strip_suffix(ending) {
+            if stem.ends_with('l') {
+                return Cow::Owned(format!("{}yz{}", stem, &ending[2..]));
+            }
+        }
+    }
+
+    Cow::Borrowed(word)
+}
+
+// Return `word` with the British ending rewritten when `word` is one of
+// `stems` followed by one of `endings`.
+fn replace_ending(
+    word: &str,
+    stems: &[&str],
+    endings: &[(&str, &str)],
+) -> Option<String> {
+    endings.iter().find_map(|(uk, us)| {
+        let stem = word.strip_suffix(uk)?;
+        if !stems.contains(&stem) {
+            return None;
+        }
+        Some(format!("{}{}", stem, us))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_americanize() {
+        let words = [
+            ("colour", "color"),
+            ("honourable", "honorable"),
+            ("defences", "defenses"),
+            ("greys", "grays"),
+            ("centre", "center"),
+            ("theatres", "theaters"),
+            ("centred", "centered"),
+            ("catalogues", "catalogs"),
+            ("organisation", "organization"),
+            ("realised", "realized"),
+            ("minimises", "minimizes"),
+            ("standardise", "standardize"),
+            ("theorising", "theorizing"),
+            ("summariser", "summarizer"),
+            ("characterising", "characterizing"),
+            ("analyse", "analyze"),
+            ("paralysed", "paralyzed"),
+        ];
+        for (uk, us) in words.iter() {
+            assert_eq!(americanize(uk), *us);
+        }
+    }
+
+    #[test]
+    fn test_americanize_unchanged() {
+        let words = [
+            "color",
+            "your",
+            "four",
+            "greyhound",
+            "programmer",
+            "meter",
+            "rise",
+            "arising",
+            "crises",
+            "otherwise",
+            "exercised",
+            "advertising",
+            "compromises",
+            "surprised",
+            "merchandise",
+            "despised",
+            "uprising",
+            "promised",
+            "franchises",
+            "noises",
+            "cruise",
+            "paradise",
+            "treatise",
+            "",
+        ];
+        for word in words.iter() {
+            assert!(
+                matches!(americanize(word), Cow::Borrowed(w) if w == *word)
+            );
+        }
+    }
+}
@@ -0,0 +1,213 @@
+// Copyright 2019 The Polystem authors.
+//
+// For the full copyright and license information, please view the LICENSE file
+// that was distributed with this source code.
+
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::fmt;
+use std::marker::PhantomData;
+use std::ops::Deref;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::Stemmer;
+
+/// A word that has been normalized by a stemmer.
+///
+/// `Stem` makes it explicit in a type signature that a string is the output
+/// of a stemmer, for example as the key of a map of conflated terms. Since it
+/// implements `Borrow<str>`, such maps can be queried with a `&str`.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashMap;
+///
+/// use polystem::{Porter, Stem};
+///
+/// let mut postings: HashMap<Stem, Vec<usize>> = HashMap::new();
+/// postings.entry(Stem::of::<Porter>("connected")).or_default().push(1);
+/// postings.entry(Stem::of::<Porter>("connecting")).or_default().push(2);
+///
+/// assert_eq!(Some(&vec![1, 2]), postings.get("connect"));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+pub struct Stem(String);
+
+impl Stem {
+    /// Stem `word` with the stemmer `T`.
+    pub fn of<T: Stemmer>(word: impl AsRef<str>) -> Stem {
+        Stem(T::stem(word))
+    }
+
+    /// Return the stem as a string slice.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Convert the stem into a `String`.
+    pub fn into_string(self) -> String {
+        self.0
+    }
+}
+
+impl Deref for Stem {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<str> for Stem {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Borrow<str> for Stem {
+    fn borrow(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for Stem {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<Stem> for String {
+    fn from(stem: Stem) -> String {
+        stem.0
+    }
+}
+
+/// Return `true` if `a` and `b` have the same stem under the stemmer `T`.
+///
+/// # Examples
+///
+/// ```
+/// use polystem::{same_stem, Porter};
+///
+/// assert!(same_stem::<Porter>("connected", "connecting"));
+/// assert!(!same_stem::<Porter>("connected", "conned"));
+/// ```
+pub fn same_stem<T: Stemmer>(a: impl AsRef<str>, b: impl AsRef<str>) -> bool {
+    let (a, b) = (a.as_ref(), b.as_ref());
+    a == b || T::stem(a) == T::stem(b)
+}
+
+/// A memoized [`same_stem`], which stems each distinct word only once.
+///
+/// # Examples
+///
+/// ```
+/// use polystem::{Porter, SameStem};
+///
+/// let mut same = SameStem::<Porter>::new();
+///
+/// assert!(same.test("connected", "connecting"));
+/// assert!(same.test("connected", "connection"));
+/// assert_eq!(3, same.len());
+/// ```
+#[derive(Clone, Debug)]
+pub struct SameStem<T> {
+    stems: HashMap<String, Stem>,
+    stemmer: PhantomData<T>,
+}
+
+impl<T: Stemmer> SameStem<T> {
+    /// Construct a `SameStem` with an empty cache.
+    pub fn new() -> SameStem<T> {
+        SameStem {
+            stems: HashMap::new(),
+            stemmer: PhantomData,
+        }
+    }
+
+    /// Return `true` if `a` and `b` have the same stem under the stemmer
+    /// `T`.
+    pub fn test(&mut self, a: impl AsRef<str>, b: impl AsRef<str>) -> bool {
+        let (a, b) = (a.as_ref(), b.as_ref());
+        a == b || self.stem(a) == self.stem(b)
+    }
+
+    /// Return the number of words whose stem is cached.
+    pub fn len(&self) -> usize {
+        self.stems.len()
+    }
+
+    /// Return `true` if no stem is cached.
+    pub fn is_empty(&self) -> bool {
+        self.stems.is_empty()
+    }
+
+    /// Remove every cached stem.
+    pub fn clear(&mut self) {
+        self.stems.clear();
+    }
+
+    // Return the cached stem of `word`, stemming it on first use.
+    fn stem(&mut self, word: &str) -> Stem {
+        if let Some(stem) = self.stems.get(word) {
+            return stem.clone();
+        }
+
+        let stem = Stem::of::<T>(word);
+        self.stems.insert(word.to_owned(), stem.clone());
+        stem
+    }
+}
+
+impl<T: Stemmer> Default for SameStem<T> {
+    fn default() -> SameStem<T> {
+        SameStem::new()
+    }
+}
+
+#[cfg(all(test, feature = "s"))]
+mod tests {
+    use super::*;
+    use crate::S;
+    use std::collections::BTreeSet;
+
+    #[test]
+    fn test_stem() {
+        let stem = Stem::of::<S>("Flies");
+        assert_eq!(stem.as_str(), "fly");
+        assert_eq!(stem.len(), 3);
+        assert_eq!(stem.to_string(), "fly");
+        assert_eq!(stem, Stem::of::<S>(String::from("fly")));
+
+        let stems: BTreeSet<Stem> = vec!["suns", "flies", "sun"]
+            .into_iter()
+            .map(Stem::of::<S>)
+            .collect();
+        assert_eq!(stems.len(), 2);
+        assert!(stems.contains("fly"));
+        assert_eq!(stems.iter().next().map(Stem::as_str), Some("fly"));
+    }
+
+    #[test]
+    fn test_same_stem() {
+        assert!(same_stem::<S>("flies", "Fly"));
+        assert!(same_stem::<S>("", ""));
+        assert!(!same_stem::<S>("suns", "sum"));
+
+        let mut same = SameStem::<S>::new();
+        assert!(same.is_empty());
+        assert!(same.test("flies", String::from("fly")));
+        assert!(same.test("fly", "flies"));
+        assert!(!same.test("suns", "flies"));
+        assert!(same.test("suns", "suns"));
+        assert_eq!(same.len(), 3);
+
+        same.clear();
+        assert!(same.is_empty());
+    }
+}
@@ -0,0 +1,415 @@
+// Copyright 2019 The Polystem authors.
+//
+// For the full copyright and license information, please view the LICENSE file
+// that was distributed with this source code.
+
+use std::collections::HashSet;
+use std::io::{self, BufRead};
+use std::iter::FromIterator;
+
+use crate::{Case, DynStemmer, Language};
+
+// The lists are sorted, to be searched without building a set.
+
+// The English stopwords of Lucene's `EnglishAnalyzer`.
+const LUCENE_ENGLISH: &[&str] = &[
+    "a", "an", "and", "are", "as", "at", "be", "but", "by", "for", "if", "in",
+    "into", "is", "it", "no", "not", "of", "on", "or", "such", "that", "the",
+    "their", "then", "there", "these", "they", "this", "to", "was", "will",
+    "with",
+];
+
+// The English stopwords of the Snowball project, the words of the
+// `english/stop.txt` of its stemmers.
+#[cfg(any(feature = "porter", feature = "s"))]
+const SNOWBALL_ENGLISH: &[&str] = &[
+    "a",
+    "about",
+    "above",
+    "after",
+    "again",
+    "against",
+    "all",
+    "am",
+    "an",
+    "and",
+    "any",
+    "are",
+    "aren't",
+    "as",
+    "at",
+    "be",
+    "because",
+    "been",
+    "before",
+    "being",
+    "below",
+    "between",
+    "both",
+    "but",
+    "by",
+    "can't",
+    "cannot",
+    "could",
+    "couldn't",
+    "did",
+    "didn't",
+    "do",
+    "does",
+    "doesn't",
+    "doing",
+    "don't",
+    "down",
+    "during",
+    "each",
+    "few",
+    "for",
+    "from",
+    "further",
+    "had",
+    "hadn't",
+    "has",
+    "hasn't",
+    "have",
+    "haven't",
+    "having",
+    "he",
+    "he'd",
+    "he'll",
+    "he's",
+    "her",
+    "here",
+    "here's",
+    "hers",
+    "herself",
+    "him",
+    "himself",
+    "his",
+    "how",
+    "how's",
+    "i",
+    "i'd",
+    "i'll",
+    "i'm",
+    "i've",
+    "if",
+    "in",
+    "into",
+    "is",
+    "isn't",
+    "it",
+    "it's",
+    "its",
+    "itself",
+    "let's",
+    "me",
+    "more",
+    "most",
+    "mustn't",
+    "my",
+    "myself",
+    "no",
+    "nor",
+    "not",
+    "of",
+    "off",
+    "on",
+    "once",
+    "only",
+    "or",
+    "other",
+    "ought",
+    "our",
+    "ours",
+    "ourselves",
+    "out",
+    "over",
+    "own",
+    "same",
+    "shan't",
+    "she",
+    "she'd",
+    "she'll",
+    "she's",
+    "should",
+    "shouldn't",
+    "so",
+    "some",
+    "such",
+    "than",
+    "that",
+    "that's",
+    "the",
+    "their",
+    "theirs",
+    "them",
+    "themselves",
+    "then",
+    "there",
+    "there's",
+    "these",
+    "they",
+    "they'd",
+    "they'll",
+    "they're",
+    "they've",
+    "this",
+    "those",
+    "through",
+    "to",
+    "too",
+    "under",
+    "until",
+    "up",
+    "very",
+    "was",
+    "wasn't",
+    "we",
+    "we'd",
+    "we'll",
+    "we're",
+    "we've",
+    "were",
+    "weren't",
+    "what",
+    "what's",
+    "when",
+    "when's",
+    "where",
+    "where's",
+    "which",
+    "while",
+    "who",
+    "who's",
+    "whom",
+    "why",
+    "why's",
+    "with",
+    "won't",
+    "would",
+    "wouldn't",
+    "you",
+    "you'd",
+    "you'll",
+    "you're",
+    "you've",
+    "your",
+    "yours",
+    "yourself",
+    "yourselves",
+];
+
+// Return the Lucene list of `language`, or its Snowball list if `snowball`.
+#[cfg_attr(
+    not(any(feature = "porter", feature = "s")),
+    allow(unused_variables)
+)]
+fn list(language: Language, snowball: bool) -> &'static [&'static str] {
+    match language {
+        #[cfg(any(feature = "porter", feature = "s"))]
+        Language::English if snowball => SNOWBALL_ENGLISH,
+        #[cfg(any(feature = "porter", feature = "s"))]
+        Language::English => LUCENE_ENGLISH,
+    }
+}
+
+/// Return `true` if `word` is in the stopwords of `language` given by
+/// [`Stopwords::lucene`], without regard to case.
+///
+/// # Examples
+///
+/// ```
+/// use polystem::{is_stopword, Language};
+///
+/// assert!(is_stopword(Language::English, "The"));
+/// assert!(!is_stopword(Language::English, "pony"));
+/// ```
+pub fn is_stopword(language: Language, word: &str) -> bool {
+    let word = Case::Lowercase.fold(word);
+    list(language, false).binary_search(&&*word).is_ok()
+}
+
+/// A set of stopwords, the words too common to be worth indexing.
+///
+/// Words are compared without regard to case, so that `"The"` is a stopword
+/// when `"the"` is.
+///
+/// # Examples
+///
+/// ```
+/// use polystem::Stopwords;
+///
+/// let stopwords = Stopwords::english();
+/// assert!(stopwords.contains("The"));
+/// assert!(!stopwords.contains("pony"));
+///
+/// let stopwords: Stopwords = vec!["le", "la", "les"].into_iter().collect();
+/// assert!(stopwords.contains("Les"));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Stopwords {
+    words: HashSet<String>,
+}
+
+impl Stopwords {
+    /// Construct an empty set of stopwords.
+    pub fn new() -> Stopwords {
+        Stopwords::default()
+    }
+
+    /// Return the English stopwords of Lucene's `EnglishAnalyzer`, such as
+    /// `"the"`, `"and"` and `"of"`.
+    pub fn english() -> Stopwords {
+        LUCENE_ENGLISH.iter().collect()
+    }
+
+    /// Return the stopwords of `language` of Lucene's analyzer for it, a
+    /// short list of articles, conjunctions and prepositions, as for
+    /// English [`english`](Stopwords::english).
+    pub fn lucene(language: Language) -> Stopwords {
+        list(language, false).iter().collect()
+    }
+
+    /// Return the stopwords of `language` of the Snowball project, a longer
+    /// list that also has pronouns and auxiliary verbs, such as `"she"` and
+    /// `"would"`, and in English their contractions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polystem::{Language, Stopwords};
+    ///
+    /// let stopwords = Stopwords::snowball(Language::English);
+    /// assert!(stopwords.contains("Wouldn't"));
+    /// assert!(!Stopwords::lucene(Language::English).contains("would"));
+    /// ```
+    pub fn snowball(language: Language) -> Stopwords {
+        list(language, true).iter().collect()
+    }
+
+    /// Read a list of stopwords with one word on each line. Blank lines and
+    /// lines starting with `#` are skipped.
+    pub fn read<R: BufRead>(reader: R) -> io::Result<Stopwords> {
+        let mut stopwords = Stopwords::new();
+        for line in reader.lines() {
+            let line = line?;
+            let word = line.trim();
+            if !word.is_empty() && !word.starts_with('#') {
+                stopwords.insert(word);
+            }
+        }
+        Ok(stopwords)
+    }
+
+    /// Add `word` to the set.
+    pub fn insert(&mut self, word: &str) {
+        self.words.insert(Case::Lowercase.fold(word).into_owned());
+    }
+
+    /// Return `true` if `word` is a stopword.
+    pub fn contains(&self, word: &str) -> bool {
+        self.words.contains(&*Case::Lowercase.fold(word))
+    }
+
+    /// Return the number of stopwords.
+    pub fn len(&self) -> usize {
+        self.words.len()
+    }
+
+    /// Return `true` if there are no stopwords.
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+
+    /// Return the stems of the stopwords, for removing stopwords after
+    /// stemming, where `"was"` has become `"wa"` with Porter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polystem::{Algorithm, Stopwords};
+    ///
+    /// let stems = Stopwords::english().stemmed(&Algorithm::Porter);
+    /// assert!(stems.contains("wa"));
+    /// ```
+    pub fn stemmed<D: DynStemmer + ?Sized>(&self, stemmer: &D) -> Stopwords {
+        self.words.iter().map(|w| stemmer.stem_word(w)).collect()
+    }
+}
+
+impl<W: AsRef<str>> FromIterator<W> for Stopwords {
+    fn from_iter<I: IntoIterator<Item = W>>(words: I) -> Stopwords {
+        let mut stopwords = Stopwords::new();
+        stopwords.extend(words);
+        stopwords
+    }
+}
+
+impl<W: AsRef<str>> Extend<W> for Stopwords {
+    fn extend<I: IntoIterator<Item = W>>(&mut self, words: I) {
+        for word in words {
+            self.insert(word.as_ref());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stopwords() {
+        let stopwords = Stopwords::english();
+        assert_eq!(stopwords.len(), 33);
+        assert!(stopwords.contains("THE"));
+        assert!(stopwords.contains("into"));
+        assert!(!stopwords.contains("them"));
+        assert!(Stopwords::new().is_empty());
+
+        let mut stopwords: Stopwords =
+            vec!["Der", "DIE"].into_iter().collect();
+        stopwords.insert("das");
+        assert!(stopwords.contains("der"));
+        assert!(stopwords.contains("Die"));
+        assert!(stopwords.contains("DAS"));
+    }
+
+    #[cfg(any(feature = "porter", feature = "s"))]
+    #[test]
+    fn test_builtin_lists() {
+        for &snowball in &[false, true] {
+            let words = list(Language::English, snowball);
+            assert!(words.windows(2).all(|w| w[0] < w[1]));
+            assert!(words.iter().all(|w| Case::Lowercase.fold(w) == *w));
+        }
+        assert_eq!(Stopwords::lucene(Language::English), Stopwords::english());
+        let snowball = Stopwords::snowball(Language::English);
+        assert_eq!(snowball.len(), 174);
+        assert!(snowball.contains("THEMSELVES"));
+        assert!(!snowball.contains("will"));
+
+        assert!(is_stopword(Language::English, "Such"));
+        assert!(is_stopword(Language::English, "a"));
+        assert!(is_stopword(Language::English, "with"));
+        assert!(!is_stopword(Language::English, "them"));
+        assert!(!is_stopword(Language::English, ""));
+    }
+
+    #[test]
+    fn test_read() {
+        let text = "# French\nle\n\n  la  \nles\n";
+        let stopwords = Stopwords::read(text.as_bytes()).unwrap();
+        let expected: Stopwords =
+            vec!["le", "la", "les"].into_iter().collect();
+        assert_eq!(stopwords, expected);
+    }
+
+    #[cfg(feature = "porter")]
+    #[test]
+    fn test_stemmed() {
+        let stems = Stopwords::english().stemmed(&crate::Algorithm::Porter);
+        assert!(stems.contains("wa"));
+        assert!(stems.contains("thei"));
+        assert!(!stems.contains("was"));
+    }
+}
@@ -0,0 +1,296 @@
+// Copyright 2019 The Polystem authors.
+//
+// For the full copyright and license information, please view the LICENSE file
+// that was distributed with this source code.
+
+use std::io::{self, ErrorKind, Read, Write};
+use std::str;
+
+use crate::DynStemmer;
+
+#[cfg(feature = "tokio")]
+mod async_io;
+
+#[cfg(feature = "tokio")]
+pub use async_io::StemChunks;
+
+/// The number of bytes and tokens of a stream handled by
+/// [`Streaming::stem_stream`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct StreamStats {
+    /// The number of bytes read.
+    pub bytes: u64,
+    /// The number of tokens stemmed.
+    pub tokens: u64,
+    /// The number of tokens copied unchanged, because they are longer than
+    /// the buffer or are not valid UTF-8.
+    pub passed_through: u64,
+}
+
+/// A stemmer wrapper that stems a stream of text of any size in a buffer of
+/// fixed size.
+///
+/// The text is read in chunks, and each token, a run of bytes between ASCII
+/// whitespace, is replaced with its stem. The whitespace is copied as is. A
+/// token cut by the end of a chunk is moved to the start of the buffer and
+/// completed by the next read, so memory use stays at about twice the
+/// capacity however large the stream.
+///
+/// # Examples
+///
+/// ```
+/// use polystem::{Porter, Streaming};
+///
+/// let input = "ponies rusted\n  connections\n".as_bytes();
+/// let mut output = Vec::new();
+/// let stream = Streaming::new(Porter::default(), 4096);
+/// let stats = stream.stem_stream(input, &mut output).unwrap();
+///
+/// assert_eq!(b"poni rust\n  connect\n", &output[..]);
+/// assert_eq!(3, stats.tokens);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Streaming<D: DynStemmer> {
+    stemmer: D,
+    capacity: usize,
+}
+
+impl<D: DynStemmer> Streaming<D> {
+    /// Construct a `Streaming` stemmer that reads `capacity` bytes at a time.
+    /// Tokens of `capacity` bytes or more are copied unchanged.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is zero.
+    pub fn new(stemmer: D, capacity: usize) -> Streaming<D> {
+        assert!(capacity > 0, "capacity must be at least one byte");
+        Streaming { stemmer, capacity }
+    }
+
+    /// Return the number of bytes read at a time.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Read the text of `input` to the end and write it to `output` with
+    /// every token stemmed.
+    ///
+    /// Writes are made a token at a time, so `output` should be buffered,
+    /// for example with a [`BufWriter`](std::io::BufWriter).
+    pub fn stem_stream<R: Read, W: Write>(
+        &self,
+        mut input: R,
+        mut output: W,
+    ) -> io::Result<StreamStats> {
+        let mut state = State::new(self.capacity);
+        let mut buf = vec![0; self.capacity];
+        let mut len = 0;
+
+        loop {
+            let n = match input.read(&mut buf[len..]) {
+                Ok(n) => n,
+                Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+                Err(e) => return Err(e),
+            };
+            if n == 0 {
+                state.write(&self.stemmer, &buf[..len], &mut output)?;
+                break;
+            }
+            state.stats.bytes += n as u64;
+            len = state.feed(&self.stemmer, &mut buf, len + n, &mut output)?;
+        }
+
+        output.flush()?;
+        Ok(state.stats)
+    }
+}
+
+// The state of a stream stemmed by `Streaming`, carried from one chunk to
+// the next.
+struct State {
+    scratch: Vec<u8>,
+    stats: StreamStats,
+    // Whether the first token of the next chunk is the rest of a token
+    // longer than the buffer, which is copied unchanged.
+    overlong: bool,
+}
+
+impl State {
+    fn new(capacity: usize) -> State {
+        State {
+            scratch: vec![0; capacity],
+            stats: StreamStats::default(),
+            overlong: false,
+        }
+    }
+
+    // Write the complete tokens of the first `len` bytes of `buf`, and move
+    // the last one to the start of `buf` for the next read unless it is
+    // followed by whitespace, returning its length.
+    fn feed<D: DynStemmer, W: Write>(
+        &mut self,
+        stemmer: &D,
+        buf: &mut [u8],
+        len: usize,
+        output: &mut W,
+    ) -> io::Result<usize> {
+        match buf[..len].iter().rposition(u8::is_ascii_whitespace) {
+            Some(end) => {
+                self.write(stemmer, &buf[..=end], output)?;
+                buf.copy_within(end + 1..len, 0);
+                Ok(len - end - 1)
+            }
+            None if len == buf.len() => {
+                self.write_overlong(buf, output)?;
+                Ok(0)
+            }
+            None => Ok(len),
+        }
+    }
+
+    // Write the text of `chunk` to `output` with every token stemmed.
+    fn write<D: DynStemmer, W: Write>(
+        &mut self,
+        stemmer: &D,
+        chunk: &[u8],
+        output: &mut W,
+    ) -> io::Result<()> {
+        let mut rest = chunk;
+        while !rest.is_empty() {
+            let end = rest
+                .iter()
+                .position(|b| {
+                    b.is_ascii_whitespace() != rest[0].is_ascii_whitespace()
+                })
+                .unwrap_or(rest.len());
+            let (run, tail) = rest.split_at(end);
+            rest = tail;
+
+            if run[0].is_ascii_whitespace() {
+                self.overlong = false;
+                output.write_all(run)?;
+            } else if self.overlong {
+                output.write_all(run)?;
+            } else {
+                self.write_token(stemmer, run, output)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    // Write a part of a token that does not fit the buffer, unchanged.
+    fn write_overlong<W: Write>(
+        &mut self,
+        part: &[u8],
+        output: &mut W,
+    ) -> io::Result<()> {
+        if !self.overlong {
+            self.stats.passed_through += 1;
+            self.overlong = true;
+        }
+        output.write_all(part)
+    }
+
+    // Write the stem of `token`, or `token` itself when it is not UTF-8.
+    fn write_token<D: DynStemmer, W: Write>(
+        &mut self,
+        stemmer: &D,
+        token: &[u8],
+        output: &mut W,
+    ) -> io::Result<()> {
+        let word = match str::from_utf8(token) {
+            Ok(word) => word,
+            Err(_) => {
+                self.stats.passed_through += 1;
+                return output.write_all(token);
+            }
+        };
+
+        self.stats.tokens += 1;
+        loop {
+            match stemmer.stem_to_buf_word(word, &mut self.scratch) {
+                Ok(stem) => return output.write_all(stem.as_bytes()),
+                Err(e) => self.scratch.resize(e.required(), 0),
+            }
+        }
+    }
+}
+
+#[cfg(all(test, feature = "english"))]
+mod tests {
+    use super::*;
+    use crate::{Algorithm, S};
+
+    // A reader that returns at most `n` bytes per read.
+    struct Trickle<'a> {
+        bytes: &'a [u8],
+        n: usize,
+    }
+
+    impl Read for Trickle<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            let n = self.n.min(buf.len()).min(self.bytes.len());
+            buf[..n].copy_from_slice(&self.bytes[..n]);
+            self.bytes = &self.bytes[n..];
+            Ok(n)
+        }
+    }
+
+    fn stem(
+        input: &[u8],
+        capacity: usize,
+        n: usize,
+    ) -> (Vec<u8>, StreamStats) {
+        let mut output = Vec::new();
+        let stats = Streaming::new(S, capacity)
+            .stem_stream(Trickle { bytes: input, n }, &mut output)
+            .unwrap();
+        (output, stats)
+    }
+
+    #[test]
+    fn test_stem_stream() {
+        let input = "flies  suns\ncats\tétés\n\nblesses";
+        let expected = "fly  sun\ncat\tété\n\nbless";
+        for capacity in 8..16 {
+            for n in 1..capacity {
+                let (output, stats) = stem(input.as_bytes(), capacity, n);
+                assert_eq!(String::from_utf8(output).unwrap(), expected);
+                assert_eq!(stats.bytes, input.len() as u64);
+                assert_eq!(stats.tokens, 5);
+                assert_eq!(stats.passed_through, 0);
+            }
+        }
+
+        let (output, stats) = stem(b"", 8, 8);
+        assert!(output.is_empty());
+        assert_eq!(stats, StreamStats::default());
+    }
+
+    #[test]
+    fn test_stem_stream_passthrough() {
+        let input = b"suns unconditionallies cats \xffs suns";
+        for n in 1..8 {
+            let (output, stats) = stem(input, 8, n);
+            assert_eq!(&output[..], b"sun unconditionallies cat \xffs sun");
+            assert_eq!(stats.tokens, 3);
+            assert_eq!(stats.passed_through, 2);
+        }
+    }
+
+    #[test]
+    fn test_stem_stream_dyn() {
+        let stream = Streaming::new(Algorithm::Porter, 64);
+        let mut output = Vec::new();
+        stream.stem_stream(&b"rusted\n"[..], &mut output).unwrap();
+        assert_eq!(&output[..], b"rust\n");
+        assert_eq!(stream.capacity(), 64);
+    }
+
+    #[test]
+    #[should_panic(expected = "capacity must be at least one byte")]
+    fn test_streaming_zero_capacity() {
+        Streaming::new(S, 0);
+    }
+}
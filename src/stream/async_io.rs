@@ -0,0 +1,327 @@
+// Copyright 2019 The Polystem authors.
+//
+// For the full copyright and license information, please view the LICENSE file
+// that was distributed with this source code.
+
+// Stemming of asynchronous streams, with the `tokio` feature.
+
+use std::io::{self, ErrorKind};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures_core::Stream;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+
+use super::{State, StreamStats, Streaming};
+use crate::DynStemmer;
+
+impl<D: DynStemmer> Streaming<D> {
+    /// Read the text of `input` to the end and write it to `output` with
+    /// every token stemmed, as by [`stem_stream`](Streaming::stem_stream),
+    /// without blocking the thread while waiting for either.
+    ///
+    /// The text is stemmed a buffer at a time between reads, so a large
+    /// document holds the executor thread no longer than a small one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polystem::{Porter, Streaming};
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() -> std::io::Result<()> {
+    /// let input = "ponies rusted\n".as_bytes();
+    /// let mut output = Vec::new();
+    /// let stream = Streaming::new(Porter::default(), 4096);
+    /// let stats = stream.stem_async(input, &mut output).await?;
+    ///
+    /// assert_eq!(b"poni rust\n", &output[..]);
+    /// assert_eq!(2, stats.tokens);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn stem_async<R, W>(
+        &self,
+        mut input: R,
+        mut output: W,
+    ) -> io::Result<StreamStats>
+    where
+        R: AsyncRead + Unpin,
+        W: AsyncWrite + Unpin,
+    {
+        let mut state = State::new(self.capacity);
+        let mut buf = vec![0; self.capacity];
+        let mut len = 0;
+        // The stemmed text of a chunk, written to `output` at once.
+        let mut stemmed = Vec::new();
+
+        loop {
+            let n = match input.read(&mut buf[len..]).await {
+                Ok(n) => n,
+                Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+                Err(e) => return Err(e),
+            };
+            if n == 0 {
+                state.write(&self.stemmer, &buf[..len], &mut stemmed)?;
+                output.write_all(&stemmed).await?;
+                break;
+            }
+            state.stats.bytes += n as u64;
+            len =
+                state.feed(&self.stemmer, &mut buf, len + n, &mut stemmed)?;
+            output.write_all(&stemmed).await?;
+            stemmed.clear();
+        }
+
+        output.flush().await?;
+        Ok(state.stats)
+    }
+
+    /// Stem a stream of byte chunks, such as the body of a request or a
+    /// file read with `tokio_util::io::ReaderStream`, into a stream of the
+    /// chunks of the stemmed text.
+    ///
+    /// Tokens cut between chunks are stemmed whole, and chunks larger than
+    /// the buffer are stemmed a buffer at a time, one output chunk each.
+    /// The stream ends at the first error of `chunks`, after returning it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::future::poll_fn;
+    /// use std::pin::Pin;
+    ///
+    /// use futures_core::Stream;
+    /// use polystem::{Porter, Streaming};
+    ///
+    /// # struct Iter<I>(I);
+    /// # impl<I: Iterator + Unpin> Stream for Iter<I> {
+    /// #     type Item = I::Item;
+    /// #     fn poll_next(
+    /// #         mut self: Pin<&mut Self>,
+    /// #         _: &mut std::task::Context,
+    /// #     ) -> std::task::Poll<Option<I::Item>> {
+    /// #         std::task::Poll::Ready(self.0.next())
+    /// #     }
+    /// # }
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() -> std::io::Result<()> {
+    /// let chunks = vec![Ok("ponies ru"), Ok("sted\n")];
+    /// let mut stemmed = Streaming::new(Porter::default(), 4096)
+    ///     .stem_chunks(Iter(chunks.into_iter()));
+    ///
+    /// let mut output = Vec::new();
+    /// while let Some(chunk) =
+    ///     poll_fn(|cx| Pin::new(&mut stemmed).poll_next(cx)).await
+    /// {
+    ///     output.extend(chunk?);
+    /// }
+    /// assert_eq!(b"poni rust\n", &output[..]);
+    /// assert_eq!(2, stemmed.stats().tokens);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn stem_chunks<S, B>(self, chunks: S) -> StemChunks<S, D>
+    where
+        S: Stream<Item = io::Result<B>> + Unpin,
+        B: AsRef<[u8]>,
+    {
+        StemChunks {
+            chunks,
+            state: State::new(self.capacity),
+            buf: vec![0; self.capacity],
+            len: 0,
+            chunk: Vec::new(),
+            pos: 0,
+            done: false,
+            streaming: self,
+        }
+    }
+}
+
+/// A stream of the chunks of stemmed text, returned by
+/// [`Streaming::stem_chunks`].
+pub struct StemChunks<S, D: DynStemmer> {
+    chunks: S,
+    streaming: Streaming<D>,
+    state: State,
+    buf: Vec<u8>,
+    len: usize,
+    // The last chunk of `chunks`, and the length of it moved to `buf`.
+    chunk: Vec<u8>,
+    pos: usize,
+    done: bool,
+}
+
+impl<S, D: DynStemmer> StemChunks<S, D> {
+    /// Return the number of bytes and tokens handled so far.
+    pub fn stats(&self) -> StreamStats {
+        self.state.stats
+    }
+}
+
+// The fields are never pinned, only `chunks`, which is `Unpin`.
+impl<S: Unpin, D: DynStemmer> Unpin for StemChunks<S, D> {}
+
+impl<S, B, D> Stream for StemChunks<S, D>
+where
+    S: Stream<Item = io::Result<B>> + Unpin,
+    B: AsRef<[u8]>,
+    D: DynStemmer,
+{
+    type Item = io::Result<Vec<u8>>;
+
+    fn poll_next(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        let stemmer = &this.streaming.stemmer;
+        loop {
+            if this.pos == this.chunk.len() {
+                if this.done {
+                    return Poll::Ready(None);
+                }
+                match Pin::new(&mut this.chunks).poll_next(cx) {
+                    Poll::Pending => return Poll::Pending,
+                    Poll::Ready(Some(Ok(chunk))) => {
+                        let chunk = chunk.as_ref();
+                        this.state.stats.bytes += chunk.len() as u64;
+                        this.chunk.clear();
+                        this.chunk.extend_from_slice(chunk);
+                        this.pos = 0;
+                        continue;
+                    }
+                    Poll::Ready(Some(Err(e))) => {
+                        this.done = true;
+                        return Poll::Ready(Some(Err(e)));
+                    }
+                    Poll::Ready(None) => {
+                        this.done = true;
+                        let mut stemmed = Vec::new();
+                        let rest = &this.buf[..this.len];
+                        this.state.write(stemmer, rest, &mut stemmed)?;
+                        this.len = 0;
+                        if stemmed.is_empty() {
+                            return Poll::Ready(None);
+                        }
+                        return Poll::Ready(Some(Ok(stemmed)));
+                    }
+                }
+            }
+
+            let n =
+                (this.buf.len() - this.len).min(this.chunk.len() - this.pos);
+            let part = &this.chunk[this.pos..this.pos + n];
+            this.buf[this.len..this.len + n].copy_from_slice(part);
+            this.pos += n;
+            let mut stemmed = Vec::new();
+            let len = this.len + n;
+            this.len =
+                this.state.feed(stemmer, &mut this.buf, len, &mut stemmed)?;
+            if !stemmed.is_empty() {
+                return Poll::Ready(Some(Ok(stemmed)));
+            }
+        }
+    }
+}
+
+#[cfg(all(test, feature = "english"))]
+mod tests {
+    use std::collections::VecDeque;
+    use std::future::poll_fn;
+
+    use super::*;
+    use crate::S;
+
+    // A stream of the chunks of a text, pending before each.
+    struct Chunks {
+        chunks: VecDeque<io::Result<Vec<u8>>>,
+        ready: bool,
+    }
+
+    impl Stream for Chunks {
+        type Item = io::Result<Vec<u8>>;
+
+        fn poll_next(
+            mut self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+        ) -> Poll<Option<Self::Item>> {
+            self.ready = !self.ready;
+            if !self.ready {
+                cx.waker().wake_by_ref();
+                return Poll::Pending;
+            }
+            Poll::Ready(self.chunks.pop_front())
+        }
+    }
+
+    async fn collect(
+        chunks: Vec<io::Result<Vec<u8>>>,
+        capacity: usize,
+    ) -> (Vec<io::Result<Vec<u8>>>, StreamStats) {
+        let chunks = Chunks {
+            chunks: chunks.into(),
+            ready: false,
+        };
+        let mut stemmed = Streaming::new(S, capacity).stem_chunks(chunks);
+        let mut output = Vec::new();
+        while let Some(chunk) =
+            poll_fn(|cx| Pin::new(&mut stemmed).poll_next(cx)).await
+        {
+            output.push(chunk);
+        }
+        (output, stemmed.stats())
+    }
+
+    #[tokio::test]
+    async fn test_stem_async() {
+        let input = "flies  suns\ncats\tétés\n\nblesses";
+        for capacity in 8..16 {
+            let mut output = Vec::new();
+            let stats = Streaming::new(S, capacity)
+                .stem_async(input.as_bytes(), &mut output)
+                .await
+                .unwrap();
+            assert_eq!(output, "fly  sun\ncat\tété\n\nbless".as_bytes());
+            assert_eq!(stats.bytes, input.len() as u64);
+            assert_eq!(stats.tokens, 5);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_stem_chunks() {
+        let input = b"flies suns unconditionallies cats \xffs blesses";
+        let expected = b"fly sun unconditionallies cat \xffs bless";
+        for capacity in 8..12 {
+            for size in 1..20 {
+                let chunks =
+                    input.chunks(size).map(|c| Ok(c.to_vec())).collect();
+                let (output, stats) = collect(chunks, capacity).await;
+                assert!(output
+                    .iter()
+                    .all(|c| !c.as_ref().unwrap().is_empty()));
+                let output: Vec<u8> =
+                    output.into_iter().flat_map(Result::unwrap).collect();
+                assert_eq!(output, &expected[..], "{} {}", capacity, size);
+                assert_eq!(stats.bytes, input.len() as u64);
+                assert_eq!(stats.tokens, 4);
+                assert_eq!(stats.passed_through, 2);
+            }
+        }
+
+        // Chunks larger than the buffer are stemmed a buffer at a time.
+        let (output, _) = collect(vec![Ok(b"suns ".repeat(4))], 10).await;
+        assert_eq!(output.len(), 2);
+
+        let chunks = vec![
+            Ok(b"suns ca".to_vec()),
+            Err(io::Error::other("broken")),
+            Ok(b"ts".to_vec()),
+        ];
+        let (output, _) = collect(chunks, 16).await;
+        assert_eq!(output.len(), 2);
+        assert_eq!(output[0].as_ref().unwrap(), b"sun ");
+        assert!(output[1].is_err());
+    }
+}
@@ -0,0 +1,130 @@
+// Copyright 2019 The Polystem authors.
+//
+// For the full copyright and license information, please view the LICENSE file
+// that was distributed with this source code.
+
+// The maximum number of nodes of a `SuffixTrie`.
+const MAX_NODES: usize = 128;
+
+// The longest suffix a `SuffixTrie` can hold.
+const MAX_SUFFIX_LEN: usize = 8;
+
+// A trie of reversed suffixes over the lowercase ASCII letters, built at
+// compile time from a table of rules.
+//
+// Matching walks the end of a word backwards, once, and finds every suffix
+// of the table the word ends with.
+pub(crate) struct SuffixTrie {
+    // The children of each node by letter. Zero means no child, as the root
+    // is never a child.
+    children: [[u8; 26]; MAX_NODES],
+    // The rule ending at each node, plus one. Zero means no rule.
+    rules: [u8; MAX_NODES],
+}
+
+impl SuffixTrie {
+    // Build the trie of the suffixes of `rules`.
+    pub(crate) const fn new(rules: &[(&str, &str)]) -> SuffixTrie {
+        let mut trie = SuffixTrie {
+            children: [[0; 26]; MAX_NODES],
+            rules: [0; MAX_NODES],
+        };
+        let mut len = 1;
+
+        let mut r = 0;
+        while r < rules.len() {
+            let suffix = rules[r].0.as_bytes();
+            assert!(suffix.len() <= MAX_SUFFIX_LEN, "suffix too long");
+
+            let mut node = 0;
+            let mut i = suffix.len();
+            while i > 0 {
+                i -= 1;
+                assert!(suffix[i].is_ascii_lowercase(), "suffix not a-z");
+                let c = (suffix[i] - b'a') as usize;
+                if trie.children[node][c] == 0 {
+                    assert!(len < MAX_NODES, "too many suffixes");
+                    trie.children[node][c] = len as u8;
+                    len += 1;
+                }
+                node = trie.children[node][c] as usize;
+            }
+            assert!(trie.rules[node] == 0, "duplicate suffix");
+            trie.rules[node] = r as u8 + 1;
+            r += 1;
+        }
+
+        trie
+    }
+
+    // Return the indexes of the rules whose suffix `word` ends with, longest
+    // suffix first.
+    pub(crate) fn matches(&self, word: &[u8]) -> Matches {
+        let mut matches = Matches {
+            rules: [0; MAX_SUFFIX_LEN],
+            len: 0,
+        };
+
+        let mut node = 0;
+        for &b in word.iter().rev() {
+            if !b.is_ascii_lowercase() {
+                break;
+            }
+            node = self.children[node][(b - b'a') as usize] as usize;
+            if node == 0 {
+                break;
+            }
+            if self.rules[node] != 0 {
+                matches.rules[matches.len] = self.rules[node] - 1;
+                matches.len += 1;
+            }
+        }
+
+        matches
+    }
+}
+
+// The rules matched by `SuffixTrie::matches`, iterated longest suffix first.
+pub(crate) struct Matches {
+    rules: [u8; MAX_SUFFIX_LEN],
+    len: usize,
+}
+
+impl Iterator for Matches {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        Some(self.rules[self.len] as usize)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RULES: &[(&str, &str)] = &[
+        ("ational", "ate"),
+        ("tional", "tion"),
+        ("al", ""),
+        ("s", ""),
+    ];
+
+    static TRIE: SuffixTrie = SuffixTrie::new(RULES);
+
+    #[test]
+    fn test_suffix_trie() {
+        let matches =
+            |w: &str| TRIE.matches(w.as_bytes()).collect::<Vec<usize>>();
+
+        assert_eq!(matches("relational"), vec![0, 1, 2]);
+        assert_eq!(matches("conditional"), vec![1, 2]);
+        assert_eq!(matches("cats"), vec![3]);
+        assert_eq!(matches("cat"), Vec::<usize>::new());
+        assert_eq!(matches("caf\u{e9}"), Vec::<usize>::new());
+        assert_eq!(matches(""), Vec::<usize>::new());
+    }
+}
@@ -0,0 +1,73 @@
+// Copyright 2019 The Polystem authors.
+//
+// For the full copyright and license information, please view the LICENSE file
+// that was distributed with this source code.
+
+// Word at a time byte comparisons, used by the Porter stemmer when the `simd`
+// feature is enabled. The bytes of a word are loaded eight at a time into a
+// `u64` and compared with a few integer operations, instead of one byte at a
+// time.
+
+use std::convert::TryInto;
+
+// Load up to eight bytes of `bytes` into a `u64`, padded with zero bytes.
+#[inline]
+fn load(bytes: &[u8]) -> u64 {
+    match bytes.try_into() {
+        Ok(chunk) => u64::from_le_bytes(chunk),
+        Err(_) => {
+            let mut chunk = [0; 8];
+            chunk[..bytes.len()].copy_from_slice(bytes);
+            u64::from_le_bytes(chunk)
+        }
+    }
+}
+
+// Return `true` if `word` ends with `suffix`.
+#[inline]
+pub(crate) fn ends_with(word: &[u8], suffix: &[u8]) -> bool {
+    let len = suffix.len();
+    if len > word.len() {
+        return false;
+    }
+    if len > 8 || word.len() < 8 {
+        return word.ends_with(suffix);
+    }
+
+    // Compare the last eight bytes of the word, with the bytes before the
+    // suffix shifted out.
+    let tail = load(&word[word.len() - 8..]);
+    let shift = 8 * (8 - len) as u32;
+    tail.checked_shr(shift).unwrap_or(0) == load(suffix)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ends_with() {
+        let words: &[&[u8]] = &[
+            b"",
+            b"s",
+            b"ational",
+            b"relational",
+            b"conditional",
+            b"ness",
+        ];
+        let suffixes: &[&[u8]] =
+            &[b"", b"s", b"al", b"ational", b"tional", b"ousness", b"ness"];
+        for word in words {
+            for suffix in suffixes {
+                assert_eq!(
+                    ends_with(word, suffix),
+                    word.ends_with(suffix),
+                    "{:?} {:?}",
+                    word,
+                    suffix
+                );
+            }
+        }
+        assert!(ends_with(b"internationalization", b"nalization"));
+    }
+}
@@ -0,0 +1,179 @@
+// Copyright 2019 The Polystem authors.
+//
+// For the full copyright and license information, please view the LICENSE file
+// that was distributed with this source code.
+
+use std::collections::{HashMap, HashSet};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The counts of a term in the documents added to a [`TermStats`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TermCounts {
+    /// The number of times the term occurs, in all documents.
+    pub frequency: usize,
+    /// The number of documents the term occurs in.
+    pub documents: usize,
+}
+
+/// The frequencies and document frequencies of the terms of a collection
+/// of documents, such as the stems of a corpus.
+///
+/// # Examples
+///
+/// ```
+/// use polystem::{Porter, Stemmer, TermStats};
+///
+/// let mut stats = TermStats::new();
+/// stats.add_document(Porter::stem_all(&["ponies", "pony", "rusted"]));
+/// stats.add_document(Porter::stem_all(&["ponies"]));
+///
+/// let counts = stats.get("poni").unwrap();
+/// assert_eq!(3, counts.frequency);
+/// assert_eq!(2, counts.documents);
+/// assert_eq!(vec![("poni", 3), ("rust", 1)], stats.most_frequent(2));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TermStats {
+    terms: HashMap<String, TermCounts>,
+    documents: usize,
+    tokens: usize,
+}
+
+impl TermStats {
+    /// Construct an empty `TermStats`.
+    pub fn new() -> TermStats {
+        TermStats::default()
+    }
+
+    /// Count the terms of a document.
+    pub fn add_document<I>(&mut self, terms: I)
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let mut seen = HashSet::new();
+        for term in terms {
+            let term = term.as_ref();
+            self.tokens += 1;
+            if !self.terms.contains_key(term) {
+                self.terms.insert(term.to_owned(), TermCounts::default());
+            }
+            let counts = self.terms.get_mut(term).unwrap();
+            counts.frequency += 1;
+            if seen.insert(term.to_owned()) {
+                counts.documents += 1;
+            }
+        }
+        self.documents += 1;
+    }
+
+    /// Add the counts of `other`, collected from other documents, for
+    /// counting the parts of a collection separately.
+    pub fn merge(&mut self, other: TermStats) {
+        for (term, counts) in other.terms {
+            let total = self.terms.entry(term).or_default();
+            total.frequency += counts.frequency;
+            total.documents += counts.documents;
+        }
+        self.documents += other.documents;
+        self.tokens += other.tokens;
+    }
+
+    /// Return the counts of `term`, if it occurs.
+    pub fn get(&self, term: &str) -> Option<TermCounts> {
+        self.terms.get(term).copied()
+    }
+
+    /// Return the number of documents added.
+    pub fn documents(&self) -> usize {
+        self.documents
+    }
+
+    /// Return the number of terms added, counting each occurrence.
+    pub fn tokens(&self) -> usize {
+        self.tokens
+    }
+
+    /// Return the number of distinct terms.
+    pub fn len(&self) -> usize {
+        self.terms.len()
+    }
+
+    /// Return `true` if no terms were added.
+    pub fn is_empty(&self) -> bool {
+        self.terms.is_empty()
+    }
+
+    /// Return an iterator over the terms and their counts, in no particular
+    /// order.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, TermCounts)> {
+        self.terms
+            .iter()
+            .map(|(term, &counts)| (term.as_str(), counts))
+    }
+
+    /// Return the `n` most frequent terms with their frequencies, most
+    /// frequent first and in alphabetical order among equals.
+    pub fn most_frequent(&self, n: usize) -> Vec<(&str, usize)> {
+        let mut terms: Vec<_> = self
+            .iter()
+            .map(|(term, counts)| (term, counts.frequency))
+            .collect();
+        terms.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(b.0)));
+        terms.truncate(n);
+        terms
+    }
+}
+
+impl<I> Extend<I> for TermStats
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    /// Add each of `documents`, a list of terms, as by
+    /// [`add_document`](TermStats::add_document).
+    fn extend<D: IntoIterator<Item = I>>(&mut self, documents: D) {
+        for document in documents {
+            self.add_document(document);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_term_stats() {
+        let mut stats = TermStats::new();
+        assert!(stats.is_empty());
+        stats.extend(vec![vec!["a", "b", "a"], vec!["b", "c"], vec![]]);
+        assert_eq!(stats.documents(), 3);
+        assert_eq!(stats.tokens(), 5);
+        assert_eq!(stats.len(), 3);
+        assert_eq!(
+            stats.get("a"),
+            Some(TermCounts {
+                frequency: 2,
+                documents: 1
+            })
+        );
+        assert_eq!(stats.get("b").unwrap().documents, 2);
+        assert_eq!(stats.get("d"), None);
+        assert_eq!(stats.most_frequent(5), vec![("a", 2), ("b", 2), ("c", 1)]);
+
+        let mut other = TermStats::new();
+        other.add_document(vec![String::from("c")]);
+        stats.merge(other);
+        assert_eq!(stats.documents(), 4);
+        assert_eq!(stats.tokens(), 6);
+        assert_eq!(stats.get("c").unwrap().documents, 2);
+        let mut terms: Vec<_> = stats.iter().map(|(term, _)| term).collect();
+        terms.sort_unstable();
+        assert_eq!(terms, vec!["a", "b", "c"]);
+    }
+}
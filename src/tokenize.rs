@@ -0,0 +1,477 @@
+// Copyright 2019 The Polystem authors.
+//
+// For the full copyright and license information, please view the LICENSE file
+// that was distributed with this source code.
+
+//! Splitting text into the words to stem.
+//!
+//! Words are found with the word boundaries of [Unicode Standard Annex #29],
+//! so that punctuation is not left attached to words as it is by splitting
+//! on whitespace, while contractions and possessives such as `"don't"` and
+//! `"dog's"` and numbers such as `"3.14"` stay whole. Runs of whitespace,
+//! punctuation and symbols are not words.
+//!
+//! [Unicode Standard Annex #29]: https://www.unicode.org/reports/tr29/
+//!
+//! # Examples
+//!
+//! ```
+//! use polystem::tokenize::words;
+//! use polystem::{Porter, Stemmer};
+//!
+//! let text = "The ponies' manes, rusted?";
+//! let stems: Vec<_> = words(text).map(Porter::stem).collect();
+//!
+//! assert_eq!(vec!["the", "poni", "mane", "rust"], stems);
+//! ```
+
+use std::ops::Range;
+
+use unicode_segmentation::{
+    UnicodeSegmentation, UnicodeWordIndices, UnicodeWords,
+};
+
+use crate::DynStemmer;
+
+mod sentences;
+
+pub use sentences::{sentences, SentenceSplitter};
+
+/// An iterator over the words of a text, see [`words`].
+#[derive(Debug)]
+pub struct Words<'a> {
+    inner: UnicodeWords<'a>,
+}
+
+/// Return an iterator over the words of `text`, in order.
+///
+/// # Examples
+///
+/// ```
+/// use polystem::tokenize::words;
+///
+/// let words: Vec<_> = words("Self-organizing maps—don't panic!").collect();
+///
+/// assert_eq!(vec!["Self", "organizing", "maps", "don't", "panic"], words);
+/// ```
+pub fn words(text: &str) -> Words<'_> {
+    Words {
+        inner: text.unicode_words(),
+    }
+}
+
+impl<'a> Iterator for Words<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        self.inner.next()
+    }
+}
+
+impl<'a> DoubleEndedIterator for Words<'a> {
+    fn next_back(&mut self) -> Option<&'a str> {
+        self.inner.next_back()
+    }
+}
+
+/// Return `text` with every word, as found by [`words`], replaced by its
+/// stem, and the whitespace, punctuation and line breaks between the words
+/// kept as they are.
+///
+/// # Examples
+///
+/// ```
+/// use polystem::tokenize::stem_text;
+/// use polystem::Algorithm;
+///
+/// let text = "Ponies rusted,\n  (connections!)";
+/// let stemmed = stem_text(&Algorithm::Porter, text);
+///
+/// assert_eq!("poni rust,\n  (connect!)", stemmed);
+/// ```
+pub fn stem_text<D: DynStemmer + ?Sized>(stemmer: &D, text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for segment in text.split_word_bounds() {
+        if is_word(segment) {
+            out.push_str(&stemmer.stem_word(segment));
+        } else {
+            out.push_str(segment);
+        }
+    }
+    out
+}
+
+/// Which form of a word a [`Token`] holds.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum TokenKind {
+    /// The word as analyzed, usually its stem.
+    #[default]
+    Stem,
+    /// The word before stemming, emitted alongside its stem by a
+    /// [`Pipeline`](crate::Pipeline) that keeps originals.
+    Original,
+}
+
+/// A word of a text with its stem and its position in the text, so that
+/// the stem can be mapped back to the source, for example to highlight it.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Token {
+    /// The stem of the word, or the word before stemming for a token of
+    /// the [`Original`](TokenKind::Original) kind.
+    pub term: String,
+    /// Which form of the word `term` is.
+    pub kind: TokenKind,
+    /// The byte offset of the start of the word in the text.
+    pub start: usize,
+    /// The byte offset of the end of the word in the text.
+    pub end: usize,
+}
+
+impl Token {
+    /// Return the byte range of the word in the text.
+    pub fn range(&self) -> Range<usize> {
+        self.start..self.end
+    }
+}
+
+impl AsRef<str> for Token {
+    fn as_ref(&self) -> &str {
+        &self.term
+    }
+}
+
+/// An iterator over the stemmed words of a text, see [`tokens`].
+pub struct Tokens<'s, 't, D: ?Sized> {
+    stemmer: &'s D,
+    inner: UnicodeWordIndices<'t>,
+}
+
+/// Return an iterator over the words of `text`, as found by [`words`],
+/// stemmed by `stemmer` and with their byte offsets in `text`.
+///
+/// # Examples
+///
+/// ```
+/// use polystem::tokenize::tokens;
+/// use polystem::Algorithm;
+///
+/// let text = "Ponies, rusted.";
+/// let tokens: Vec<_> = tokens(&Algorithm::Porter, text).collect();
+///
+/// assert_eq!("poni", tokens[0].term);
+/// assert_eq!("rusted", &text[tokens[1].range()]);
+/// assert_eq!((8, 14), (tokens[1].start, tokens[1].end));
+/// ```
+pub fn tokens<'s, 't, D: DynStemmer + ?Sized>(
+    stemmer: &'s D,
+    text: &'t str,
+) -> Tokens<'s, 't, D> {
+    Tokens {
+        stemmer,
+        inner: text.unicode_word_indices(),
+    }
+}
+
+impl<'s, 't, D: DynStemmer + ?Sized> Tokens<'s, 't, D> {
+    fn token(&self, (start, word): (usize, &str)) -> Token {
+        Token {
+            term: self.stemmer.stem_word(word),
+            kind: TokenKind::Stem,
+            start,
+            end: start + word.len(),
+        }
+    }
+}
+
+impl<'s, 't, D: DynStemmer + ?Sized> Iterator for Tokens<'s, 't, D> {
+    type Item = Token;
+
+    fn next(&mut self) -> Option<Token> {
+        let word = self.inner.next()?;
+        Some(self.token(word))
+    }
+}
+
+impl<'s, 't, D: DynStemmer + ?Sized> DoubleEndedIterator
+    for Tokens<'s, 't, D>
+{
+    fn next_back(&mut self) -> Option<Token> {
+        let word = self.inner.next_back()?;
+        Some(self.token(word))
+    }
+}
+
+impl<'s, 't, D: ?Sized> std::fmt::Debug for Tokens<'s, 't, D> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("Tokens")
+            .field("inner", &self.inner)
+            .finish()
+    }
+}
+
+/// The kinds of token that are not words and are mangled by splitting them
+/// into words and stemming them, see [`classify`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum TokenClass {
+    /// A web address, such as `"https://example.com/a"`,
+    /// `"www.example.com"` or `"github.com/users"`.
+    Url,
+    /// An email address, such as `"someone@example.com"`.
+    Email,
+    /// A social media handle, such as `"@someone"`.
+    Handle,
+    /// A hashtag, such as `"#rustlang"`.
+    Hashtag,
+}
+
+// The punctuation trimmed from the ends of a token before it is classified.
+const OPENING: &[char] = &['(', '[', '{', '<', '"', '\'', '“', '‘', '«'];
+const CLOSING: &[char] = &[
+    ')', ']', '}', '>', '"', '\'', '”', '’', '»', '.', ',', ';', ':', '!',
+    '?', '…',
+];
+
+// Return `true` if `domain` is a domain name such as `"example.com"`: labels
+// of letters, digits and hyphens, separated by periods, the last of at least
+// two letters.
+fn is_domain(domain: &str) -> bool {
+    let mut labels = domain.split('.');
+    let tld = labels.next_back().unwrap_or("");
+    let mut count = 0;
+    for label in labels {
+        count += 1;
+        if label.is_empty()
+            || !label.chars().all(|c| c.is_alphanumeric() || c == '-')
+        {
+            return false;
+        }
+    }
+    count > 0
+        && tld.chars().count() >= 2
+        && tld.chars().all(char::is_alphabetic)
+}
+
+fn is_url(token: &str) -> bool {
+    let lower = token.to_ascii_lowercase();
+    for scheme in &["http://", "https://", "ftp://"] {
+        if let Some(rest) = lower.strip_prefix(scheme) {
+            return !rest.is_empty();
+        }
+    }
+    let (host, path) = match token.find('/') {
+        Some(i) => token.split_at(i),
+        None => (token, ""),
+    };
+    let host = host.split(':').next().unwrap_or(host);
+    is_domain(host) && (lower.starts_with("www.") || !path.is_empty())
+}
+
+fn is_email(token: &str) -> bool {
+    let (local, domain) = match token.split_once('@') {
+        Some(parts) => parts,
+        None => return false,
+    };
+    !local.is_empty()
+        && local
+            .chars()
+            .all(|c| c.is_alphanumeric() || "._%+-".contains(c))
+        && is_domain(domain)
+}
+
+// Return `true` if `name` is the name of a handle or a hashtag: letters,
+// digits and underscores.
+fn is_name(name: &str) -> bool {
+    !name.is_empty() && name.chars().all(|c| c.is_alphanumeric() || c == '_')
+}
+
+/// Return the class of `token`, a run of text without whitespace, if it is
+/// not a word. Punctuation around the token, such as the period ending a
+/// sentence, should be removed first, as [`special_tokens`] does.
+///
+/// # Examples
+///
+/// ```
+/// use polystem::tokenize::{classify, TokenClass};
+///
+/// assert_eq!(Some(TokenClass::Url), classify("github.com/users"));
+/// assert_eq!(Some(TokenClass::Email), classify("me@example.org"));
+/// assert_eq!(Some(TokenClass::Handle), classify("@rustlang"));
+/// assert_eq!(Some(TokenClass::Hashtag), classify("#Rust2024"));
+/// assert_eq!(None, classify("example.com"));
+/// assert_eq!(None, classify("#1"));
+/// ```
+pub fn classify(token: &str) -> Option<TokenClass> {
+    if let Some(name) = token.strip_prefix('@') {
+        return Some(TokenClass::Handle).filter(|_| is_name(name));
+    }
+    if let Some(name) = token.strip_prefix('#') {
+        let tag = is_name(name) && name.chars().any(char::is_alphabetic);
+        return Some(TokenClass::Hashtag).filter(|_| tag);
+    }
+    if is_email(token) {
+        return Some(TokenClass::Email);
+    }
+    if is_url(token) {
+        return Some(TokenClass::Url);
+    }
+    None
+}
+
+/// Return the tokens of `text` that are not words, with their byte offsets
+/// in `text` and their class, see [`classify`].
+///
+/// Tokens are the runs of text between whitespace, without the punctuation
+/// around them.
+///
+/// # Examples
+///
+/// ```
+/// use polystem::tokenize::{special_tokens, TokenClass};
+///
+/// let text = "Mail me@example.org (or see www.example.org).";
+/// let tokens = special_tokens(text);
+///
+/// assert_eq!(
+///     vec![
+///         (5, "me@example.org", TokenClass::Email),
+///         (28, "www.example.org", TokenClass::Url),
+///     ],
+///     tokens
+/// );
+/// ```
+pub fn special_tokens(text: &str) -> Vec<(usize, &str, TokenClass)> {
+    let mut out = Vec::new();
+    for chunk in text.split_whitespace() {
+        let start = chunk.as_ptr() as usize - text.as_ptr() as usize;
+        let token = chunk.trim_start_matches(OPENING);
+        let start = start + chunk.len() - token.len();
+        let token = token.trim_end_matches(CLOSING);
+        if let Some(class) = classify(token) {
+            out.push((start, token, class));
+        }
+    }
+    out
+}
+
+// Return `true` if the segment between two word boundaries is a word, by
+// the test of `UnicodeWords`.
+fn is_word(segment: &str) -> bool {
+    segment.chars().any(char::is_alphanumeric)
+}
+
+#[cfg(all(test, feature = "english"))]
+mod tests {
+    use super::*;
+    use crate::Algorithm;
+
+    fn collect(text: &str) -> Vec<&str> {
+        words(text).collect()
+    }
+
+    #[test]
+    fn test_words() {
+        assert_eq!(collect(""), Vec::<&str>::new());
+        assert_eq!(collect("  ...  "), Vec::<&str>::new());
+        assert_eq!(
+            collect("\"Quoted,\" she said (twice)."),
+            vec!["Quoted", "she", "said", "twice"]
+        );
+        assert_eq!(collect("the dog's bone"), vec!["the", "dog's", "bone"]);
+        assert_eq!(collect("π is 3.14"), vec!["π", "is", "3.14"]);
+        assert_eq!(collect("naïve café"), vec!["naïve", "café"]);
+        assert_eq!(
+            words("a b c").rev().collect::<Vec<_>>(),
+            vec!["c", "b", "a"]
+        );
+    }
+
+    #[test]
+    fn test_classify() {
+        let cases = [
+            ("https://example.com/a?b=c", Some(TokenClass::Url)),
+            ("HTTP://x", Some(TokenClass::Url)),
+            ("http://", None),
+            ("www.example.co.uk", Some(TokenClass::Url)),
+            ("localhost:8080/a", None),
+            ("example.com:8080/a", Some(TokenClass::Url)),
+            ("e.g", None),
+            ("3.14/2", None),
+            ("a.b/c", None),
+            ("first.last+tag@mail.example.com", Some(TokenClass::Email)),
+            ("me@localhost", None),
+            ("@", None),
+            ("@me@example.com", None),
+            ("@some_one", Some(TokenClass::Handle)),
+            ("#", None),
+            ("#2024", None),
+            ("#fête", Some(TokenClass::Hashtag)),
+            ("#tag!", None),
+            ("word", None),
+            ("", None),
+        ];
+        for &(token, class) in &cases {
+            assert_eq!(classify(token), class, "{}", token);
+        }
+    }
+
+    #[test]
+    fn test_special_tokens() {
+        let text = "«@me», see <https://a.org/b>! #Tags… and x@y.io.";
+        let tokens: Vec<_> = special_tokens(text)
+            .into_iter()
+            .map(|(start, token, _)| (start, token))
+            .collect();
+        assert_eq!(
+            tokens,
+            vec![
+                (2, "@me"),
+                (14, "https://a.org/b"),
+                (32, "#Tags"),
+                (45, "x@y.io")
+            ]
+        );
+        assert_eq!(&text[14..29], "https://a.org/b");
+        assert!(special_tokens("").is_empty());
+    }
+
+    #[test]
+    fn test_stem_text() {
+        let stem = |text| stem_text(&Algorithm::Porter, text);
+        assert_eq!(stem(""), "");
+        assert_eq!(stem(" \r\n\t"), " \r\n\t");
+        assert_eq!(stem("The dog's bones."), "the dog' bone.");
+        assert_eq!(stem("self-organizing"), "self-organ");
+        assert_eq!(stem("«Ponies»—rusted…"), "«poni»—rust…");
+
+        let stemmer: Box<dyn DynStemmer> = Box::new(Algorithm::S);
+        assert_eq!(stem_text(&*stemmer, "cats, dogs"), "cat, dog");
+    }
+
+    #[test]
+    fn test_tokens() {
+        let text = "«Ponies» and café\ncats";
+        let tokens: Vec<_> = tokens(&Algorithm::S, text).collect();
+        let words: Vec<_> = tokens.iter().map(|t| &text[t.range()]).collect();
+        assert_eq!(words, vec!["Ponies", "and", "café", "cats"]);
+        assert_eq!(
+            tokens[2],
+            Token {
+                term: "café".to_owned(),
+                kind: TokenKind::Stem,
+                start: 15,
+                end: 20,
+            }
+        );
+        assert_eq!(tokens[0].term, "pony");
+        assert_eq!(tokens[3].range(), 21..25);
+
+        let last = super::tokens(&Algorithm::S, text).next_back().unwrap();
+        assert_eq!(last.term, "cat");
+        assert_eq!(super::tokens(&Algorithm::S, " ; ").next(), None);
+    }
+}
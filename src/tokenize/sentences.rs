@@ -0,0 +1,276 @@
+// Copyright 2019 The Polystem authors.
+//
+// For the full copyright and license information, please view the LICENSE file
+// that was distributed with this source code.
+
+use std::collections::HashSet;
+
+use super::OPENING;
+use crate::Case;
+
+// The abbreviations, without their last period, that are usually followed
+// by a name rather than ending a sentence.
+const ABBREVIATIONS: &[&str] = &[
+    "capt", "cf", "col", "dr", "e.g", "gen", "gov", "hon", "i.e", "jr", "lt",
+    "messrs", "mlle", "mme", "mr", "mrs", "ms", "mt", "prof", "rep", "rev",
+    "sen", "sgt", "sr", "st", "u.k", "u.s", "vs",
+];
+
+// The abbreviations that do not end a sentence when followed by a number,
+// but are also words or names that do.
+const NUMBER_ABBREVIATIONS: &[&str] = &[
+    "apr", "art", "aug", "ch", "dec", "eq", "feb", "fig", "figs", "jan",
+    "jul", "jun", "mar", "no", "nos", "nov", "oct", "pp", "sec", "sep",
+    "sept", "vol",
+];
+
+// The characters ending a sentence, and those that do so without a space
+// after them, as in Chinese and Japanese.
+const TERMINATORS: &[char] = &['.', '!', '?', '…', '。', '！', '？'];
+const WIDE_TERMINATORS: &[char] = &['。', '！', '？'];
+
+// The characters closing a sentence after its terminator.
+const CLOSING: &[char] =
+    &[')', ']', '"', '\'', '\u{201d}', '\u{2019}', '»', '」', '』'];
+
+/// A rule based splitter of text into sentences, for analyzing each
+/// sentence on its own, as for snippets and summaries.
+///
+/// A sentence ends at a `.`, `!`, `?` or `…`, with any closing quotes and
+/// brackets after it, followed by whitespace and a word that does not start
+/// with a lowercase letter, and at a blank line. A period does not end a
+/// sentence after an abbreviation such as `"Dr."` or `"e.g."`, one such
+/// as `"No."` or `"Jan."` followed by a number, or an initial such as the
+/// `"J."` of `"J. Smith"`. The abbreviations are common English ones, to
+/// which others can be added.
+///
+/// # Examples
+///
+/// ```
+/// use polystem::tokenize::SentenceSplitter;
+///
+/// let splitter = SentenceSplitter::new().abbreviations(vec!["approx"]);
+/// let text = concat!(
+///     "Dr. Smith left at approx. 5 p.m. on Friday. ",
+///     "Why? \"Rain!\" he said.",
+/// );
+///
+/// assert_eq!(
+///     vec![
+///         (0, "Dr. Smith left at approx. 5 p.m. on Friday."),
+///         (44, "Why?"),
+///         (49, "\"Rain!\" he said."),
+///     ],
+///     splitter.split(text)
+/// );
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct SentenceSplitter {
+    // The abbreviations added to the English ones, case folded and without
+    // their last period.
+    abbreviations: HashSet<String>,
+}
+
+impl SentenceSplitter {
+    /// Construct a `SentenceSplitter` knowing common English
+    /// abbreviations.
+    pub fn new() -> SentenceSplitter {
+        SentenceSplitter::default()
+    }
+
+    /// Add `abbreviations`, with or without their last period, after which
+    /// a period does not end a sentence. Case is ignored.
+    pub fn abbreviations<I>(mut self, abbreviations: I) -> SentenceSplitter
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        for abbreviation in abbreviations {
+            let abbreviation = abbreviation.as_ref();
+            let abbreviation =
+                abbreviation.strip_suffix('.').unwrap_or(abbreviation);
+            let abbreviation = Case::Lowercase.fold(abbreviation);
+            self.abbreviations.insert(abbreviation.into_owned());
+        }
+        self
+    }
+
+    /// Split `text` into sentences, returning each with its byte offset in
+    /// `text`, without the whitespace around it.
+    pub fn split<'a>(&self, text: &'a str) -> Vec<(usize, &'a str)> {
+        let mut sentences = Vec::new();
+        let mut start = 0;
+        let mut chars = text.char_indices().peekable();
+        while let Some((i, c)) = chars.next() {
+            let end = if TERMINATORS.contains(&c) {
+                let mut end = i + c.len_utf8();
+                while let Some(&(j, c)) = chars.peek() {
+                    if !TERMINATORS.contains(&c) && !CLOSING.contains(&c) {
+                        break;
+                    }
+                    end = j + c.len_utf8();
+                    chars.next();
+                }
+                if !self.ends_sentence(text, i, end) {
+                    continue;
+                }
+                end
+            } else if c == '\n' && is_blank_line(&text[i + 1..]) {
+                i
+            } else {
+                continue;
+            };
+            push(&mut sentences, text, start, end);
+            start = end;
+        }
+        push(&mut sentences, text, start, text.len());
+        sentences
+    }
+
+    // Return `true` if the terminator at `at`, with the terminators and
+    // closing punctuation after it up to `end`, ends a sentence of `text`.
+    fn ends_sentence(&self, text: &str, at: usize, end: usize) -> bool {
+        let rest = &text[end..];
+        if text[at..end].starts_with(WIDE_TERMINATORS) {
+            return true;
+        }
+        let next = rest.trim_start();
+        if next.len() == rest.len() {
+            // A terminator inside a token, as in `"3.14"` or `"a.m."`.
+            return next.is_empty();
+        }
+        if next.starts_with(char::is_lowercase) {
+            return false;
+        }
+        if &text[at..end] != "." {
+            return true;
+        }
+
+        let before = &text[..at];
+        let word = match before.rfind(char::is_whitespace) {
+            Some(i) => &before[i + 1..],
+            None => before,
+        };
+        let word = word.trim_start_matches(OPENING);
+        let mut chars = word.chars();
+        if let (Some(c), None) = (chars.next(), chars.next()) {
+            // An initial.
+            return !c.is_alphabetic();
+        }
+        let word = Case::Lowercase.fold(word);
+        let number = next.starts_with(|c: char| c.is_ascii_digit());
+        let abbreviation = ABBREVIATIONS.contains(&&*word)
+            || number && NUMBER_ABBREVIATIONS.contains(&&*word)
+            || self.abbreviations.contains(&*word);
+        !abbreviation
+    }
+}
+
+/// Split `text` into sentences with a [`SentenceSplitter`] knowing common
+/// English abbreviations, returning each with its byte offset in `text`.
+///
+/// # Examples
+///
+/// ```
+/// use polystem::tokenize::sentences;
+///
+/// let text = "Ponies ran, e.g. Bob. The end!\n\nNo title";
+/// let expected = vec![(0, "Ponies ran, e.g. Bob."), (22, "The end!")];
+/// assert_eq!(expected, &sentences(text)[..2]);
+/// assert_eq!((32, "No title"), sentences(text)[2]);
+/// ```
+pub fn sentences(text: &str) -> Vec<(usize, &str)> {
+    SentenceSplitter::new().split(text)
+}
+
+// Return `true` if `text`, which follows a line break, starts with a blank
+// line.
+fn is_blank_line(text: &str) -> bool {
+    let line = text.split('\n').next().unwrap_or("");
+    text.contains('\n') && line.trim().is_empty()
+}
+
+// Append the sentence of `text` between `start` and `end` to `sentences`,
+// without the whitespace around it, unless it is blank.
+fn push<'a>(
+    sentences: &mut Vec<(usize, &'a str)>,
+    text: &'a str,
+    start: usize,
+    end: usize,
+) {
+    let sentence = &text[start..end];
+    let trimmed = sentence.trim_start();
+    let offset = start + sentence.len() - trimmed.len();
+    let trimmed = trimmed.trim_end();
+    if !trimmed.is_empty() {
+        sentences.push((offset, trimmed));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn split(text: &str) -> Vec<&str> {
+        sentences(text).into_iter().map(|(_, s)| s).collect()
+    }
+
+    #[test]
+    fn test_sentences() {
+        let cases: &[(&str, &[&str])] = &[
+            ("", &[]),
+            ("  \n ", &[]),
+            ("One. Two.", &["One.", "Two."]),
+            ("One.Two. three", &["One.Two. three"]),
+            (
+                "Is it? Yes!! Go...  Now",
+                &["Is it?", "Yes!!", "Go...", "Now"],
+            ),
+            (
+                "He said \"No.\" Then left.",
+                &["He said \"No.\"", "Then left."],
+            ),
+            ("(See above.) Then", &["(See above.)", "Then"]),
+            ("Mr. and MRS. Smith", &["Mr. and MRS. Smith"]),
+            ("J. R. R. Tolkien wrote.", &["J. R. R. Tolkien wrote."]),
+            ("In the U.S. Army", &["In the U.S. Army"]),
+            ("Pi is 3.14. It is", &["Pi is 3.14.", "It is"]),
+            ("In 1999. 2000 was", &["In 1999.", "2000 was"]),
+            ("Say no. No. 5 is", &["Say no.", "No. 5 is"]),
+            ("On Jan. 5 in Jan. We", &["On Jan. 5 in Jan.", "We"]),
+            (
+                "See fig. 3 and (cf. Smith)",
+                &["See fig. 3 and (cf. Smith)"],
+            ),
+            ("Title\n\nBody text", &["Title", "Body text"]),
+            ("Line one\nline two", &["Line one\nline two"]),
+            ("Line\r\n  \r\nNext", &["Line", "Next"]),
+            ("天気です。雨です。", &["天気です。", "雨です。"]),
+            ("Ça va? Élan.", &["Ça va?", "Élan."]),
+        ];
+        for &(text, expected) in cases {
+            assert_eq!(split(text), expected, "{:?}", text);
+        }
+    }
+
+    #[test]
+    fn test_offsets() {
+        let text = " One.  Two! ";
+        assert_eq!(sentences(text), vec![(1, "One."), (7, "Two!")]);
+        let text = "Å. Ba. Ça? Da";
+        for (offset, sentence) in sentences(text) {
+            assert!(sentence.starts_with(char::is_uppercase));
+            assert_eq!(&text[offset..offset + sentence.len()], sentence);
+        }
+    }
+
+    #[test]
+    fn test_abbreviations() {
+        let text = "It weighs approx. Two tons. Etc. Done";
+        assert_eq!(sentences(text).len(), 4);
+        let splitter = SentenceSplitter::new().abbreviations(["APPROX."]);
+        let sentences: Vec<_> =
+            splitter.split(text).into_iter().map(|(_, s)| s).collect();
+        assert_eq!(sentences, ["It weighs approx. Two tons.", "Etc.", "Done"]);
+    }
+}